@@ -120,6 +120,7 @@ mod libmruby {
             "mruby-sprintf",      // Kernel#sprintf, Kernel#format, String#%
             "mruby-class-ext",    // NOTE(GH-32): Pending removal.
             "mruby-proc-ext",     // NOTE(GH-32): This gem is required by `mruby-method`.
+            "mruby-objectspace",  // `ObjectSpace.each_object`, `ObjectSpace.count_objects`
         ]
     }
 
@@ -253,11 +254,18 @@ mod libmruby {
             .include(mruby_include_dir())
             .include(buildpath::source::mruby_sys_ext_include_dir())
             .define("MRB_DISABLE_STDIO", None)
-            .define("MRB_UTF8_STRING", None)
             .define(mrb_int, None)
             .define("DISABLE_GEMS", None)
             .define("ARTICHOKE", None);
 
+        if utf8_string() {
+            build.define("MRB_UTF8_STRING", None);
+        }
+
+        if let Some(boxing) = value_boxing() {
+            build.define(boxing, None);
+        }
+
         for gem in gems() {
             let dir = if gem == "mruby-compiler" {
                 "core"
@@ -285,6 +293,13 @@ mod libmruby {
         let bindings_out_path = PathBuf::from(env::var("OUT_DIR").unwrap()).join("ffi.rs");
         let mut bindgen = bindgen::Builder::default()
             .header(bindgen_source_header().to_str().unwrap())
+            // Without an explicit `--target`, libclang parses the mruby headers for bindgen's
+            // *host* target rather than the target this crate is being compiled for. Struct
+            // size, alignment, and padding -- all of which bindgen bakes into the generated
+            // bindings as literal layouts -- can differ across targets (e.g. musl vs. glibc,
+            // wasm32 vs. x86_64, or a big-endian target), so cross-compiling without this would
+            // silently generate bindings with the wrong layout for the actual target.
+            .clang_arg(format!("--target={}", target))
             .clang_arg(format!("-I{}", mruby_include_dir().to_str().unwrap()))
             .clang_arg(format!(
                 "-I{}",
@@ -293,8 +308,14 @@ mod libmruby {
                     .unwrap()
             ))
             .clang_arg("-DMRB_DISABLE_STDIO")
-            .clang_arg("-DMRB_UTF8_STRING")
-            .clang_arg(format!("-D{}", mrb_int))
+            .clang_arg(format!("-D{}", mrb_int));
+        if utf8_string() {
+            bindgen = bindgen.clang_arg("-DMRB_UTF8_STRING");
+        }
+        if let Some(boxing) = value_boxing() {
+            bindgen = bindgen.clang_arg(format!("-D{}", boxing));
+        }
+        let mut bindgen = bindgen
             .whitelist_function("^mrb.*")
             .whitelist_type("^mrb.*")
             .whitelist_var("^mrb.*")
@@ -326,7 +347,105 @@ mod libmruby {
             .unwrap();
     }
 
+    /// Select the `mrb_value` representation mruby is compiled with.
+    ///
+    /// mruby's default representation stores the type tag alongside the
+    /// value payload in a tagged union, which is larger than a machine word
+    /// on most targets. Defining `MRB_NAN_BOXING` packs non-`Float` values
+    /// into the unused bit patterns of a NaN `double`, and `MRB_WORD_BOXING`
+    /// packs the tag into the low bits of a pointer-sized word. Both reduce
+    /// `sizeof(mrb_value)` at the cost of a narrower `Float`/`Integer`
+    /// range; at most one may be enabled.
+    ///
+    /// See `include/mruby/boxing_nan.h` and `include/mruby/boxing_word.h` in
+    /// the vendored mruby sources.
+    fn value_boxing() -> Option<&'static str> {
+        let nan_boxing = env::var_os("CARGO_FEATURE_MRB_VALUE_BOXING_NAN").is_some();
+        let word_boxing = env::var_os("CARGO_FEATURE_MRB_VALUE_BOXING_WORD").is_some();
+        match (nan_boxing, word_boxing) {
+            (true, true) => {
+                panic!("the `mrb-value-boxing-nan` and `mrb-value-boxing-word` features are mutually exclusive")
+            }
+            (true, false) => Some("MRB_NAN_BOXING"),
+            (false, true) => Some("MRB_WORD_BOXING"),
+            (false, false) => None,
+        }
+    }
+
+    /// Whether mruby's strings are treated as UTF-8 (`String#each_char`,
+    /// `String#[]`, etc. operate on codepoints rather than bytes).
+    ///
+    /// Gated behind the `mrb-utf8-string` feature, which is on by default, so
+    /// embedders that want byte-oriented `String` semantics instead can opt
+    /// out with `default-features = false`.
+    fn utf8_string() -> bool {
+        env::var_os("CARGO_FEATURE_MRB_UTF8_STRING").is_some()
+    }
+
+    /// The mruby release this crate vendors and is built/tested against, as
+    /// `(major, minor, teeny)`.
+    ///
+    /// Keep in sync with `MRUBY_RELEASE_MAJOR`/`MRUBY_RELEASE_MINOR`/
+    /// `MRUBY_RELEASE_TEENY` in `vendor/mruby/include/mruby/version.h`. This
+    /// is checked at build time by [`check_vendored_mruby_version`] so an
+    /// in-place upgrade of the vendored sources that forgets to update this
+    /// constant -- or a vendor tree that drifts from what this crate's Rust
+    /// bindings were written against -- fails the build with a clear
+    /// diagnostic instead of compiling against mismatched ABI/semantics.
+    const PINNED_MRUBY_VERSION: (&str, &str, &str) = ("2", "0", "1");
+
+    /// Parse `#define NAME <value>` out of a C header's contents.
+    fn parse_c_define<'a>(header: &'a str, name: &str) -> Option<&'a str> {
+        for line in header.lines() {
+            let line = line.trim();
+            if let Some(rest) = line.strip_prefix("#define") {
+                let mut parts = rest.trim().splitn(2, char::is_whitespace);
+                if parts.next() == Some(name) {
+                    return parts.next().map(str::trim);
+                }
+            }
+        }
+        None
+    }
+
+    /// Verify the vendored mruby sources under `vendor/mruby` are the
+    /// release this crate is pinned to.
+    ///
+    /// This guards against a vendoring upgrade that copies in new mruby
+    /// sources without also auditing and updating the hand-written `mruby-sys`
+    /// bindings and the [`PINNED_MRUBY_VERSION`] constant that records what
+    /// they were last checked against.
+    fn check_vendored_mruby_version() {
+        let version_header = buildpath::source::mruby_vendored_include_dir()
+            .join("mruby")
+            .join("version.h");
+        let contents = fs::read_to_string(&version_header).unwrap_or_else(|err| {
+            panic!(
+                "failed to read vendored mruby version header at {}: {}",
+                version_header.display(),
+                err
+            )
+        });
+        let (major, minor, teeny) = PINNED_MRUBY_VERSION;
+        let actual = (
+            parse_c_define(&contents, "MRUBY_RELEASE_MAJOR"),
+            parse_c_define(&contents, "MRUBY_RELEASE_MINOR"),
+            parse_c_define(&contents, "MRUBY_RELEASE_TEENY"),
+        );
+        if actual != (Some(major), Some(minor), Some(teeny)) {
+            panic!(
+                "vendored mruby version drift detected: artichoke-backend is pinned to \
+                 mruby {}.{}.{}, but vendor/mruby/include/mruby/version.h reports \
+                 MRUBY_RELEASE_MAJOR={:?} MRUBY_RELEASE_MINOR={:?} MRUBY_RELEASE_TEENY={:?}. \
+                 Update `PINNED_MRUBY_VERSION` in build.rs after auditing the mruby-sys \
+                 bindings and FFI layout for this upgrade.",
+                major, minor, teeny, actual.0, actual.1, actual.2
+            );
+        }
+    }
+
     pub fn build(target: &Triple) {
+        check_vendored_mruby_version();
         fs::create_dir_all(mruby_build_dir()).unwrap();
         let mrb_int = "MRB_INT64";
         staticlib(target, mrb_int);
@@ -2,9 +2,13 @@ use std::ffi::c_void;
 use std::ops::{Deref, DerefMut};
 use std::ptr::NonNull;
 
+use crate::exception::Exception;
+use crate::exception_handler;
 use crate::ffi::{self, InterpreterExtractError};
+use crate::gc::MrbGarbageCollection;
 use crate::state::State;
 use crate::sys;
+use crate::value::Value;
 
 /// Interpreter instance.
 ///
@@ -34,6 +38,17 @@ impl Artichoke {
         Self { mrb, state }
     }
 
+    /// Create a [`Builder`](crate::Builder) for configuring and initializing
+    /// an `Artichoke` interpreter.
+    ///
+    /// This is the discoverable entry point for interpreter construction; see
+    /// [`Builder`](crate::Builder) for the configuration it currently
+    /// exposes.
+    #[must_use]
+    pub fn builder() -> crate::Builder {
+        crate::Builder::default()
+    }
+
     /// Execute a a closure by moving the [`State`] into the `mrb` instance.
     ///
     /// This method prepares this interpreter to cross an FFI boundary. When the
@@ -93,6 +108,195 @@ impl Artichoke {
         }
     }
 
+    /// Run a native operation that may raise a Ruby exception behind
+    /// `mrb_protect`, converting a raised exception into an [`Exception`].
+    ///
+    /// `func` is given the raw `*mut sys::mrb_state` so it can make other FFI
+    /// calls and must return the resulting [`sys::mrb_value`] (for example,
+    /// [`sys::mrb_sys_nil_value`] if the operation has no meaningful return
+    /// value). This is the safe building block for one-off native operations
+    /// that can raise, such as [`Value::funcall`](crate::core::Value::funcall)
+    /// and [`Value::is_range`](crate::value::Value::is_range), and replaces
+    /// hand-writing a [`sys::protect`](crate::sys::protect)-style data struct
+    /// and `mrb_protect` call for each one.
+    ///
+    /// # Safety
+    ///
+    /// `func` must only call FFI functions that use the `*mut sys::mrb_state`
+    /// it is given, per the safety requirements of
+    /// [`with_ffi_boundary`](Self::with_ffi_boundary).
+    ///
+    /// # Errors
+    ///
+    /// If the interpreter's state is inaccessible, an error is returned.
+    ///
+    /// If `func` raises, the raised exception is returned.
+    pub unsafe fn protect<F>(&mut self, func: F) -> Result<sys::mrb_value, Exception>
+    where
+        F: FnMut(*mut sys::mrb_state) -> sys::mrb_value + Copy,
+    {
+        let mut arena = self.create_arena_savepoint()?;
+        let result = arena
+            .interp()
+            .with_ffi_boundary(|mrb| crate::sys::protect::closure(mrb, func))?;
+        match result {
+            Ok(value) => Ok(value),
+            Err(exception) => {
+                let exception = Value::from(exception);
+                Err(exception_handler::last_error(&mut arena, exception)?)
+            }
+        }
+    }
+
+    /// Return the process-wide unique id of this interpreter.
+    ///
+    /// This id is assigned when the interpreter is created and is registered
+    /// in the [process-global interpreter registry](crate::registry) for the
+    /// lifetime of the interpreter.
+    ///
+    /// This method is only available when the `interpreter-registry` feature
+    /// is enabled.
+    #[cfg(feature = "interpreter-registry")]
+    #[must_use]
+    pub fn id(&self) -> u64 {
+        self.state.as_ref().map_or(0, |state| state.id)
+    }
+
+    /// Install a hook invoked when an exception raised on the interpreter is
+    /// about to be returned to the host as uncaught.
+    ///
+    /// The default handler prints a MRI-style `file:line: message (Class)`
+    /// line to the interpreter's stderr stream. See
+    /// [`exception_handler::UncaughtExceptionHandler`](crate::exception_handler::UncaughtExceptionHandler).
+    ///
+    /// # Errors
+    ///
+    /// If the interpreter's state is inaccessible, an error is returned.
+    pub fn set_uncaught_exception_handler(
+        &mut self,
+        handler: crate::exception_handler::UncaughtExceptionHandler,
+    ) -> Result<(), Exception> {
+        let state = self.state.as_mut().ok_or(InterpreterExtractError)?;
+        state.uncaught_exception_handler = handler;
+        Ok(())
+    }
+
+    /// Install hooks for validating writes to, redacting reads from, and
+    /// auditing access to `ENV`.
+    ///
+    /// The default hooks allow all writes, redact nothing, and audit-log
+    /// nothing. See
+    /// [`env_security::EnvSecurityHooks`](crate::env_security::EnvSecurityHooks).
+    ///
+    /// Hooks are read when an `Artichoke::Environ` is constructed, which
+    /// happens lazily the first time a script reads or writes `ENV`. Install
+    /// hooks before running untrusted scripts to ensure they are in effect
+    /// for the lifetime of the `ENV` global.
+    ///
+    /// # Errors
+    ///
+    /// If the interpreter's state is inaccessible, an error is returned.
+    pub fn set_env_security_hooks(
+        &mut self,
+        hooks: crate::env_security::EnvSecurityHooks,
+    ) -> Result<(), Exception> {
+        let state = self.state.as_mut().ok_or(InterpreterExtractError)?;
+        state.env_security_hooks = hooks;
+        Ok(())
+    }
+
+    /// Install hooks for bounding the capacity of, and observing evictions
+    /// from, `Artichoke::Cache`.
+    ///
+    /// The default hooks cap the cache at 128 entries and do not report
+    /// evictions. See
+    /// [`cache_hooks::CacheHooks`](crate::cache_hooks::CacheHooks).
+    ///
+    /// Hooks are read by `Artichoke::Cache` on every write, so a host may
+    /// install new hooks at runtime to change the capacity bound.
+    ///
+    /// # Errors
+    ///
+    /// If the interpreter's state is inaccessible, an error is returned.
+    pub fn set_cache_hooks(
+        &mut self,
+        hooks: crate::cache_hooks::CacheHooks,
+    ) -> Result<(), Exception> {
+        let state = self.state.as_mut().ok_or(InterpreterExtractError)?;
+        state.cache_hooks = hooks;
+        Ok(())
+    }
+
+    /// Install hooks for restricting which top-level constants a
+    /// `require`/`require_relative`/`load` is allowed to leave visible.
+    ///
+    /// The default hooks keep every constant a required file defines. See
+    /// [`require_visibility::RequireVisibilityHooks`](crate::require_visibility::RequireVisibilityHooks).
+    ///
+    /// Install hooks before running untrusted scripts to ensure they are in
+    /// effect for every `require` those scripts make.
+    ///
+    /// # Errors
+    ///
+    /// If the interpreter's state is inaccessible, an error is returned.
+    pub fn set_require_visibility_hooks(
+        &mut self,
+        hooks: crate::require_visibility::RequireVisibilityHooks,
+    ) -> Result<(), Exception> {
+        let state = self.state.as_mut().ok_or(InterpreterExtractError)?;
+        state.require_visibility_hooks = hooks;
+        Ok(())
+    }
+
+    /// Install hooks letting an embedder override the width, in columns,
+    /// that `IO#winsize` and the pretty-printer (`Kernel#pp`) wrap at.
+    ///
+    /// The default hooks always defer to the real file descriptor. See
+    /// [`terminal_hooks::TerminalHooks`](crate::terminal_hooks::TerminalHooks).
+    ///
+    /// # Errors
+    ///
+    /// If the interpreter's state is inaccessible, an error is returned.
+    pub fn set_terminal_hooks(
+        &mut self,
+        hooks: crate::terminal_hooks::TerminalHooks,
+    ) -> Result<(), Exception> {
+        let state = self.state.as_mut().ok_or(InterpreterExtractError)?;
+        state.terminal_hooks = hooks;
+        Ok(())
+    }
+
+    /// Configure the ordered list of extensions `require`,
+    /// `require_relative`, and `load` probe for when given an
+    /// extension-less path.
+    ///
+    /// Defaults to `[".rb"]`. Pass, for example, `&[".rb", ".mrb"]` to also
+    /// resolve precompiled sources registered under a `.mrb` extension. See
+    /// [`require::ExtensionStrategy`](crate::state::require::ExtensionStrategy).
+    ///
+    /// # Errors
+    ///
+    /// If the interpreter's state is inaccessible, an error is returned.
+    pub fn set_require_extensions(&mut self, extensions: &[&str]) -> Result<(), Exception> {
+        let state = self.state.as_mut().ok_or(InterpreterExtractError)?;
+        state.require_extensions.set_extensions(extensions.iter().copied());
+        Ok(())
+    }
+
+    /// Report an exception that has reached the host as uncaught by invoking
+    /// the installed [`UncaughtExceptionHandler`](crate::exception_handler::UncaughtExceptionHandler).
+    ///
+    /// Returns `Some(exception)` if the handler declines to suppress the
+    /// exception, or `None` if the handler has fully handled it.
+    pub fn report_uncaught_exception(&mut self, exception: Exception) -> Option<Exception> {
+        let default = crate::exception_handler::default_uncaught_exception_handler;
+        let handler = self
+            .state
+            .as_ref()
+            .map_or(default, |state| state.uncaught_exception_handler);
+        handler(self, exception)
+    }
+
     /// Consume an interpreter and return the pointer to the underlying
     /// [`sys::mrb_state`].
     ///
@@ -121,13 +325,22 @@ impl Artichoke {
             if let Some(state) = self.state.take() {
                 // Do not free class and module specs before running the final
                 // garbage collection on `mrb_close`.
+                #[cfg(feature = "interpreter-registry")]
+                crate::registry::deregister(state.id);
+
                 let State {
                     parser,
+                    mut output,
                     classes,
                     modules,
                     ..
                 } = *state;
 
+                // Flush any output buffered by the output strategy before
+                // tearing down the interpreter.
+                let _ = crate::state::output::Output::flush(&mut output);
+                drop(output);
+
                 if let Some(parser) = parser {
                     parser.close(mrb);
                 }
@@ -165,7 +378,19 @@ impl<'a> Guard<'a> {
     ///
     /// This function is most effective when the interpreter is temporarily
     /// reified and stored on the stack.
+    ///
+    /// # Panics
+    ///
+    /// In debug builds, panics if `interp`'s [`State`](crate::state::State)
+    /// has already been moved out. This indicates that two `Guard`s or an
+    /// `Artichoke` and a `Guard` are attempting to hold the `State` at the
+    /// same time, which would allow a re-entrant eval to observe a `State`
+    /// that is concurrently borrowed elsewhere on the call stack.
     pub fn new(interp: &'a mut Artichoke) -> Self {
+        debug_assert!(
+            interp.state.is_some(),
+            "Guard::new called with an Artichoke whose State has already been moved out"
+        );
         Self(interp)
     }
 
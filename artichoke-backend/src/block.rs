@@ -2,6 +2,7 @@ use std::borrow::Cow;
 use std::convert::TryFrom;
 use std::error;
 use std::fmt;
+use std::ptr::NonNull;
 
 use crate::class_registry::ClassRegistry;
 use crate::core::ConvertMut;
@@ -162,7 +163,8 @@ impl Block {
     }
 
     pub fn yield_arg(&self, interp: &mut Artichoke, arg: &Value) -> Result<Value, Exception> {
-        let mut arena = interp.create_arena_savepoint()?;
+        let mut guard = interp.enter_recursive_call()?;
+        let mut arena = guard.create_arena_savepoint()?;
 
         let result = unsafe {
             arena
@@ -189,4 +191,78 @@ impl Block {
             }
         }
     }
+
+    /// Yield this block with multiple arguments.
+    ///
+    /// Unlike [`yield_with_class`](Self::yield_with_class), this does not
+    /// rebind `self` or the default method-definition class, so it is
+    /// suitable for ordinary multi-argument blocks, for example comparator
+    /// blocks passed to `Array#sort`.
+    pub fn yield_args(&self, interp: &mut Artichoke, args: &[Value]) -> Result<Value, Exception> {
+        let mut guard = interp.enter_recursive_call()?;
+        let mut arena = guard.create_arena_savepoint()?;
+
+        let args = args.iter().map(Value::inner).collect::<Vec<_>>();
+        let result = unsafe {
+            arena
+                .interp()
+                .with_ffi_boundary(|mrb| protect::block_yield_argv(mrb, self.inner(), &args))?
+        };
+        match result {
+            Ok(value) => {
+                let value = Value::from(value);
+                if value.is_unreachable() {
+                    // See the comment on the analogous check in `yield_arg`.
+                    Err(Fatal::from("Unreachable Ruby value").into())
+                } else {
+                    Ok(value)
+                }
+            }
+            Err(exception) => {
+                let exception = Value::from(exception);
+                Err(exception_handler::last_error(&mut arena, exception)?)
+            }
+        }
+    }
+
+    /// Yield this block with `self` and the default method-definition class
+    /// rebound to `slf`/`target_class`, as used by `instance_exec` and
+    /// `class_eval`/`module_eval`.
+    ///
+    /// `target_class` is `None` for receivers that have no real singleton
+    /// class (e.g. `Symbol`, `Integer`, `Float`), in which case the proc's
+    /// own target class is left unchanged.
+    pub fn yield_with_class(
+        &self,
+        interp: &mut Artichoke,
+        slf: Value,
+        args: &[Value],
+        target_class: Option<NonNull<sys::RClass>>,
+    ) -> Result<Value, Exception> {
+        let mut guard = interp.enter_recursive_call()?;
+        let mut arena = guard.create_arena_savepoint()?;
+
+        let args = args.iter().map(Value::inner).collect::<Vec<_>>();
+        let target_class = target_class.map_or_else(std::ptr::null_mut, NonNull::as_ptr);
+        let result = unsafe {
+            arena.interp().with_ffi_boundary(|mrb| {
+                protect::block_yield_with_class(mrb, self.inner(), &args, slf.inner(), target_class)
+            })?
+        };
+        match result {
+            Ok(value) => {
+                let value = Value::from(value);
+                if value.is_unreachable() {
+                    // See the comment on the analogous check in `yield_arg`.
+                    Err(Fatal::from("Unreachable Ruby value").into())
+                } else {
+                    Ok(value)
+                }
+            }
+            Err(exception) => {
+                let exception = Value::from(exception);
+                Err(exception_handler::last_error(&mut arena, exception)?)
+            }
+        }
+    }
 }
@@ -0,0 +1,126 @@
+use crate::core::ReleaseMetadata;
+use crate::exception::Exception;
+use crate::interpreter::interpreter_with_config;
+use crate::profile::Profile;
+use crate::release_metadata::ReleaseMetadata as DefaultReleaseMetadata;
+use crate::Artichoke;
+
+/// Fluent builder for constructing an [`Artichoke`] interpreter.
+///
+/// `Builder` is the discoverable entry point for interpreter construction.
+/// Construct one with [`Artichoke::builder`], configure it, and call
+/// [`build`](Self::build) to get an initialized interpreter:
+///
+/// ```
+/// # use artichoke_backend::Artichoke;
+/// let interp = Artichoke::builder().build().unwrap();
+/// interp.close();
+/// ```
+///
+/// `Builder` wraps [`ReleaseMetadata`] and a resource limit/security
+/// [`Profile`]; it is the place to add future runtime-selectable
+/// configuration (for example, an `Output` stream or a `Regexp` backend) as
+/// Artichoke grows support for choosing them outside of Cargo features.
+#[derive(Debug, Clone, Copy)]
+pub struct Builder<T = DefaultReleaseMetadata<'static>> {
+    release_metadata: T,
+    profile: Profile,
+}
+
+impl Builder<DefaultReleaseMetadata<'static>> {
+    /// Construct a new `Builder` with Artichoke's default release metadata.
+    #[must_use]
+    pub fn new() -> Self {
+        Self {
+            release_metadata: DefaultReleaseMetadata::default(),
+            profile: Profile::default(),
+        }
+    }
+}
+
+impl Default for Builder<DefaultReleaseMetadata<'static>> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T> Builder<T>
+where
+    T: ReleaseMetadata,
+{
+    /// Set the build metadata embedded in the interpreter's
+    /// `RUBY_*`/`ARTICHOKE_*` constants.
+    #[must_use]
+    pub fn with_release_metadata<U>(self, release_metadata: U) -> Builder<U>
+    where
+        U: ReleaseMetadata,
+    {
+        Builder {
+            release_metadata,
+            profile: self.profile,
+        }
+    }
+
+    /// Select a preset [`Profile`] of recursion depth limit and security
+    /// hooks for the built interpreter.
+    ///
+    /// Defaults to [`Profile::Full`].
+    #[must_use]
+    pub fn with_profile(self, profile: Profile) -> Self {
+        Self { profile, ..self }
+    }
+
+    /// Consume the builder and initialize an [`Artichoke`] interpreter.
+    ///
+    /// # Errors
+    ///
+    /// If the underlying Artichoke VM backend cannot be initialized, an error
+    /// is returned.
+    ///
+    /// If Artichoke Ruby Core or Standard Library cannot be initialized, an
+    /// error is returned.
+    pub fn build(self) -> Result<Artichoke, Exception> {
+        let mut interp = interpreter_with_config(self.release_metadata)?;
+        self.profile.apply(&mut interp)?;
+        if let Some(state) = interp.state.as_mut() {
+            state.profile = self.profile;
+        }
+        Ok(interp)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn default_build_open_close() {
+        let interp = super::Builder::new().build().unwrap();
+        interp.close();
+    }
+
+    #[test]
+    fn with_release_metadata_build_open_close() {
+        let release_metadata = crate::release_metadata::ReleaseMetadata::new()
+            .with_ruby_engine("artichoke-mruby-test");
+        let interp = super::Builder::new()
+            .with_release_metadata(release_metadata)
+            .build()
+            .unwrap();
+        interp.close();
+    }
+
+    #[test]
+    fn with_profile_sets_interpreter_state() {
+        use crate::core::Eval;
+        use crate::profile::Profile;
+        use crate::types::Ruby;
+
+        let mut interp = super::Builder::new()
+            .with_profile(Profile::Sandbox)
+            .build()
+            .unwrap();
+        assert_eq!(interp.state.as_ref().unwrap().profile, Profile::Sandbox);
+        let result = interp.eval(b"Artichoke::VM.profile").unwrap();
+        assert_eq!(result.ruby_type(), Ruby::Symbol);
+        interp.close();
+    }
+}
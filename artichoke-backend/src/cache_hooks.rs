@@ -0,0 +1,56 @@
+use std::fmt;
+
+/// Hooks an embedder can install to bound and observe `Artichoke::Cache`.
+///
+/// Hosts that run pooled interpreters can use these hooks to cap how much
+/// memory a script's memoized data can hold and to learn when an entry falls
+/// out of the cache, without having to implement `Artichoke::Cache` itself.
+///
+/// Install a set of hooks with
+/// [`Artichoke::set_cache_hooks`](crate::Artichoke::set_cache_hooks). Hooks
+/// are consulted by `Artichoke::Cache`, which is implemented in Ruby on top
+/// of them (see `extn/core/artichoke/cache.rb`): the capacity hook bounds a
+/// least-recently-used eviction policy, and the eviction hook is called as
+/// entries fall off the end of it.
+#[derive(Clone, Copy)]
+pub struct CacheHooks {
+    /// Called to determine the maximum number of entries
+    /// `Artichoke::Cache` retains before evicting the least recently used
+    /// one. Consulted on every write, so a host can change the bound at
+    /// runtime by swapping hooks.
+    pub capacity: fn() -> usize,
+
+    /// Called with the key of an entry as it is evicted, whether by
+    /// exceeding `capacity`, by TTL expiry, by an explicit
+    /// `Artichoke::Cache.delete`, or by `Artichoke::Cache.clear`. Does not
+    /// affect the eviction; intended for
+    /// host-side metrics and cache-warming decisions.
+    pub on_evict: fn(key: &[u8]),
+}
+
+impl fmt::Debug for CacheHooks {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("CacheHooks")
+            .field("capacity", &"fn() -> usize")
+            .field("on_evict", &"fn(&[u8])")
+            .finish()
+    }
+}
+
+/// Default [`CacheHooks`].
+///
+/// Bounds the cache to 128 entries and does not report evictions.
+impl Default for CacheHooks {
+    fn default() -> Self {
+        fn default_capacity() -> usize {
+            128
+        }
+
+        fn no_evict(_key: &[u8]) {}
+
+        Self {
+            capacity: default_capacity,
+            on_evict: no_evict,
+        }
+    }
+}
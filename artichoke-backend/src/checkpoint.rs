@@ -0,0 +1,162 @@
+use std::path::PathBuf;
+
+use crate::core::{Convert, ConvertMut, Globals, Value as _};
+use crate::exception::Exception;
+use crate::ffi::InterpreterExtractError;
+use crate::fs::Filesystem;
+use crate::types::{Fp, Int, Ruby};
+use crate::value::Value;
+use crate::Artichoke;
+
+/// A global variable value captured by [`Artichoke::checkpoint`].
+///
+/// Only the primitive Ruby types listed here round-trip through a
+/// `Checkpoint`. [`Artichoke::checkpoint`] silently skips a requested global
+/// whose value is of some other type (for example an `Array`, `Hash`,
+/// `Proc`, or an instance of a user-defined class) rather than erroring,
+/// since there is no general way to serialize an arbitrary Ruby object's
+/// internal state from outside the VM.
+#[derive(Debug, Clone, PartialEq)]
+#[non_exhaustive]
+pub enum CheckpointValue {
+    /// Ruby `nil`.
+    Nil,
+    /// Ruby `true` or `false`.
+    Bool(bool),
+    /// Ruby `Fixnum`.
+    Fixnum(Int),
+    /// Ruby `Float`.
+    Float(Fp),
+    /// Ruby `String`, as raw bytes.
+    String(Vec<u8>),
+}
+
+impl CheckpointValue {
+    /// Attempt to capture `value` as a [`CheckpointValue`], returning
+    /// `None` if it is not one of the primitive types this enum covers.
+    ///
+    /// Shared with [`crate::extn::core::artichoke::key_value`], which faces
+    /// the same "no general Ruby object serializer" constraint as
+    /// `Checkpoint` does.
+    pub(crate) fn capture(interp: &mut Artichoke, value: Value) -> Option<Self> {
+        match value.ruby_type() {
+            Ruby::Nil => Some(Self::Nil),
+            Ruby::Bool => value.try_into::<bool>(&*interp).ok().map(Self::Bool),
+            Ruby::Fixnum => value.try_into::<Int>(&*interp).ok().map(Self::Fixnum),
+            Ruby::Float => value.try_into::<Fp>(&*interp).ok().map(Self::Float),
+            Ruby::String => value.try_into_mut::<Vec<u8>>(interp).ok().map(Self::String),
+            _ => None,
+        }
+    }
+
+    /// Restore this [`CheckpointValue`] into a live `Value` on `interp`.
+    pub(crate) fn restore(self, interp: &mut Artichoke) -> Value {
+        match self {
+            Self::Nil => Value::nil(),
+            Self::Bool(value) => interp.convert(value),
+            Self::Fixnum(value) => interp.convert(value),
+            Self::Float(value) => interp.convert_mut(value),
+            Self::String(value) => interp.convert_mut(value),
+        }
+    }
+}
+
+/// A best-effort, point-in-time snapshot of a subset of an interpreter's
+/// global state, for migrating a long-lived script session across process
+/// restarts.
+///
+/// Build one with [`Artichoke::checkpoint`] and restore it into a -- usually
+/// different -- interpreter with [`Artichoke::resume`].
+///
+/// # Limitations
+///
+/// A `Checkpoint` captures only:
+///
+/// - The subset of global variables named in the call to
+///   [`Artichoke::checkpoint`] whose values are one of the primitive types
+///   in [`CheckpointValue`]. mruby's C API does not expose a way to
+///   enumerate the global variable table, so `Checkpoint` cannot discover
+///   global variable names on its own; the caller must know which ones
+///   matter for its session.
+/// - Which sources are marked required on the virtual filesystem (the
+///   `Kernel#require` equivalent of `$LOADED_FEATURES`), limited to paths
+///   that still exist in the resuming interpreter's virtual filesystem --
+///   `Checkpoint` does not capture or replay source contents.
+///
+/// A `Checkpoint` does **not** capture top-level constants, `Proc`s,
+/// `Fiber`s, or any other heap-allocated object graph: there is no general
+/// way to serialize those from outside the VM.
+#[derive(Debug, Clone, Default)]
+pub struct Checkpoint {
+    globals: Vec<(Vec<u8>, CheckpointValue)>,
+    loaded_features: Vec<PathBuf>,
+}
+
+impl Artichoke {
+    /// Capture a best-effort snapshot of this interpreter's global state.
+    ///
+    /// `global_names` lists the global variables (including the leading
+    /// `$`) to capture; see the [`Checkpoint`] docs for why this cannot be
+    /// discovered automatically. A name with no value set, or whose value is
+    /// not one of the primitive types in [`CheckpointValue`], is omitted
+    /// from the checkpoint rather than erroring.
+    ///
+    /// # Errors
+    ///
+    /// If the interpreter's state is inaccessible, an error is returned.
+    pub fn checkpoint<T>(&mut self, global_names: &[T]) -> Result<Checkpoint, Exception>
+    where
+        T: AsRef<[u8]>,
+    {
+        let mut globals = Vec::with_capacity(global_names.len());
+        for name in global_names {
+            let name = name.as_ref();
+            if let Some(value) = self.get_global_variable(name.to_vec())? {
+                if let Some(captured) = CheckpointValue::capture(self, value) {
+                    globals.push((name.to_vec(), captured));
+                }
+            }
+        }
+        let state = self.state.as_ref().ok_or(InterpreterExtractError)?;
+        let loaded_features = state
+            .vfs
+            .paths()
+            .into_iter()
+            .filter(|path| state.vfs.is_required(path))
+            .collect();
+        Ok(Checkpoint {
+            globals,
+            loaded_features,
+        })
+    }
+
+    /// Restore a [`Checkpoint`] captured by [`Artichoke::checkpoint`] into
+    /// this interpreter.
+    ///
+    /// Global variables captured in `checkpoint` are set on this
+    /// interpreter. Sources in `checkpoint`'s loaded-features list are
+    /// marked required if they already exist on this interpreter's virtual
+    /// filesystem (for example, because the embedder registered the same
+    /// [`File`](crate::core::File)s and Ruby sources before calling
+    /// `resume`); sources that don't exist here are skipped, since
+    /// `Checkpoint` does not carry source contents to re-register them with.
+    ///
+    /// # Errors
+    ///
+    /// If the interpreter's state is inaccessible, an error is returned.
+    ///
+    /// If a captured global variable name is invalid, an error is returned.
+    pub fn resume(&mut self, checkpoint: &Checkpoint) -> Result<(), Exception> {
+        for (name, value) in &checkpoint.globals {
+            let value = value.clone().restore(self);
+            self.set_global_variable(name.clone(), &value)?;
+        }
+        for path in &checkpoint.loaded_features {
+            let state = self.state.as_mut().ok_or(InterpreterExtractError)?;
+            if state.vfs.is_file(path) {
+                state.vfs.mark_required(path)?;
+            }
+        }
+        Ok(())
+    }
+}
@@ -152,6 +152,71 @@ impl<'a> Builder<'a> {
     }
 }
 
+/// Builder for configuring methods on an anonymous `Class`.
+///
+/// Unlike [`Builder`], which defines a named class reachable by constant
+/// lookup via a [`Spec`], `AnonymousBuilder` wraps a [`sys::RClass`] that was
+/// already allocated with `mrb_class_new` and has no name and no constant
+/// pointing at it. Anonymous classes are not registered in the
+/// [`class::Registry`](registry::Registry) and have no [`Spec`]: the
+/// returned [`Value`](crate::value::Value) is the only handle to them, just
+/// like the return value of `Class.new` in Ruby.
+#[derive(Debug)]
+pub struct AnonymousBuilder<'a> {
+    interp: &'a mut Artichoke,
+    rclass: NonNull<sys::RClass>,
+    methods: HashSet<method::Spec>,
+}
+
+impl<'a> AnonymousBuilder<'a> {
+    #[must_use]
+    pub fn new(interp: &'a mut Artichoke, rclass: NonNull<sys::RClass>) -> Self {
+        Self {
+            interp,
+            rclass,
+            methods: HashSet::default(),
+        }
+    }
+
+    pub fn add_method<T>(
+        mut self,
+        name: T,
+        method: Method,
+        args: sys::mrb_aspec,
+    ) -> Result<Self, ConstantNameError>
+    where
+        T: Into<Cow<'static, str>>,
+    {
+        let spec = method::Spec::new(method::Type::Instance, name.into(), method, args)?;
+        self.methods.insert(spec);
+        Ok(self)
+    }
+
+    pub fn add_self_method<T>(
+        mut self,
+        name: T,
+        method: Method,
+        args: sys::mrb_aspec,
+    ) -> Result<Self, ConstantNameError>
+    where
+        T: Into<Cow<'static, str>>,
+    {
+        let spec = method::Spec::new(method::Type::Class, name.into(), method, args)?;
+        self.methods.insert(spec);
+        Ok(self)
+    }
+
+    pub fn define(mut self) -> Result<sys::mrb_value, NotDefinedError> {
+        for method in &self.methods {
+            unsafe {
+                method.define(self.interp, self.rclass.as_mut())?;
+            }
+        }
+        let value = unsafe { sys::mrb_sys_class_value(self.rclass.as_mut()) };
+        Ok(value)
+    }
+}
+
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct Rclass {
     name: CString,
@@ -296,6 +361,8 @@ impl PartialEq for Spec {
 
 #[cfg(test)]
 mod tests {
+    use std::ptr::NonNull;
+
     use crate::extn::core::exception::StandardError;
     use crate::extn::core::kernel::Kernel;
     use crate::test::prelude::*;
@@ -358,4 +425,99 @@ mod tests {
         let rclass = unsafe { interp.with_ffi_boundary(|mrb| spec.rclass().resolve(mrb)) }.unwrap();
         assert!(rclass.is_some());
     }
+
+    #[test]
+    fn new_class_is_anonymous_and_subclasses_object_by_default() {
+        let mut interp = crate::interpreter().unwrap();
+        let anonymous = interp.new_class(None, Ok).unwrap();
+
+        let object_class = interp.eval(b"Object").unwrap();
+        let is_object_subclass = anonymous
+            .funcall(&mut interp, "<", &[object_class], None)
+            .unwrap();
+        assert!(is_object_subclass.try_into::<bool>(&interp).unwrap());
+
+        let name = anonymous.funcall(&mut interp, "name", &[], None).unwrap();
+        assert!(name.is_nil());
+    }
+
+    unsafe extern "C" fn greeting(
+        _mrb: *mut sys::mrb_state,
+        _slf: sys::mrb_value,
+    ) -> sys::mrb_value {
+        sys::mrb_sys_fixnum_value(7)
+    }
+
+    #[test]
+    fn new_class_with_explicit_superclass_and_methods() {
+        let mut interp = crate::interpreter().unwrap();
+        let superclass = interp.eval(b"StandardError").unwrap();
+        let anonymous = interp
+            .new_class(Some(superclass), |builder| {
+                Ok(builder.add_method("greeting", greeting, sys::mrb_args_none())?)
+            })
+            .unwrap();
+
+        let standard_error_class = interp.eval(b"StandardError").unwrap();
+        let is_standard_error_subclass = anonymous
+            .funcall(&mut interp, "<", &[standard_error_class], None)
+            .unwrap();
+        assert!(is_standard_error_subclass
+            .try_into::<bool>(&interp)
+            .unwrap());
+
+        let object = anonymous.funcall(&mut interp, "new", &[], None).unwrap();
+        let greeting = object.funcall(&mut interp, "greeting", &[], None).unwrap();
+        assert_eq!(greeting.try_into::<i64>(&interp).unwrap(), 7);
+    }
+
+    struct RegisteredByRclass;
+
+    #[test]
+    fn class_spec_for_class_finds_spec_by_rclass_pointer() {
+        let mut interp = crate::interpreter().unwrap();
+        let spec = class::Spec::new("RegisteredByRclass", None, None).unwrap();
+        class::Builder::for_spec(&mut interp, &spec).define().unwrap();
+        interp.def_class::<RegisteredByRclass>(spec).unwrap();
+
+        let value = interp.class_of::<RegisteredByRclass>().unwrap().unwrap();
+        let rclass = NonNull::new(unsafe { sys::mrb_sys_class_ptr(value.inner()) }).unwrap();
+        let found = interp.class_spec_for_class(rclass).unwrap().unwrap();
+        assert_eq!(found.name().as_ref(), "RegisteredByRclass");
+    }
+
+    #[test]
+    fn class_spec_for_class_is_none_for_anonymous_classes() {
+        let mut interp = crate::interpreter().unwrap();
+        let anonymous = interp.new_class(None, Ok).unwrap();
+        let rclass = NonNull::new(unsafe { sys::mrb_sys_class_ptr(anonymous.inner()) }).unwrap();
+        assert!(interp.class_spec_for_class(rclass).unwrap().is_none());
+    }
+
+    #[test]
+    fn method_defined_finds_rust_registered_methods() {
+        let mut interp = crate::interpreter().unwrap();
+        let spec = class::Spec::new("RegisteredByRclass", None, None).unwrap();
+        class::Builder::for_spec(&mut interp, &spec)
+            .add_method("greeting", greeting, sys::mrb_args_none())
+            .unwrap()
+            .define()
+            .unwrap();
+        interp.def_class::<RegisteredByRclass>(spec).unwrap();
+
+        assert!(interp
+            .method_defined::<RegisteredByRclass>("greeting")
+            .unwrap());
+        assert!(!interp
+            .method_defined::<RegisteredByRclass>("no_such_method")
+            .unwrap());
+    }
+
+    #[test]
+    fn method_defined_is_false_for_unregistered_type() {
+        struct Unregistered;
+
+        let mut interp = crate::interpreter().unwrap();
+        assert!(!interp.method_defined::<Unregistered>("greeting").unwrap());
+    }
 }
@@ -21,7 +21,7 @@
 //! name, are not invalidated as the underlying storage reallocates.
 
 use std::any::{self, Any, TypeId};
-use std::collections::hash_map::{RandomState, Values};
+use std::collections::hash_map::{Iter, RandomState, Values};
 use std::collections::HashMap;
 use std::hash::BuildHasher;
 use std::iter::FusedIterator;
@@ -53,6 +53,31 @@ impl<'a> Iterator for ClassSpecs<'a> {
     }
 }
 
+/// An iterator of all `(TypeId, Spec)` pairs stored in the [`Registry`].
+#[derive(Debug, Clone)]
+pub struct ClassSpecsByTypeId<'a>(Iter<'a, TypeId, Box<Spec>>);
+
+impl<'a> ExactSizeIterator for ClassSpecsByTypeId<'a> {}
+
+impl<'a> FusedIterator for ClassSpecsByTypeId<'a> {}
+
+impl<'a> Iterator for ClassSpecsByTypeId<'a> {
+    type Item = (TypeId, &'a Spec);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let (id, value) = self.0.next()?;
+        Some((*id, value.as_ref()))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.0.size_hint()
+    }
+
+    fn count(self) -> usize {
+        self.0.count()
+    }
+}
+
 /// A registry for [Class specs](crate::class::Spec) that uses types as keys.
 ///
 /// This data structure is used for associating data type metadata with a Rust
@@ -141,6 +166,18 @@ impl<S> Registry<S> {
         ClassSpecs(self.0.values())
     }
 
+    /// An iterator of all `(TypeId, Spec)` pairs stored in the [`Registry`]
+    /// in arbitrary order.
+    ///
+    /// This is used to look up a [class spec](Spec) by a property of the
+    /// `Spec` itself, like its resolved [`sys::RClass`](crate::sys::RClass)
+    /// pointer, when the caller does not have the Rust type the `Spec` is
+    /// keyed by.
+    #[must_use]
+    pub fn class_specs_by_type_id(&self) -> ClassSpecsByTypeId<'_> {
+        ClassSpecsByTypeId(self.0.iter())
+    }
+
     /// Returns the number of [class specs](Spec) in the registry.
     #[must_use]
     pub fn len(&self) -> usize {
@@ -212,6 +249,18 @@ where
         Some(value.as_ref())
     }
 
+    /// Returns a reference to the [class spec](Spec) corresponding to the
+    /// given [`TypeId`], if one has been registered.
+    ///
+    /// This is the counterpart to [`Registry::get`] for callers that have a
+    /// `TypeId` discovered at runtime, e.g. from
+    /// [`Registry::class_specs_by_type_id`], rather than a static type `K`.
+    #[must_use]
+    pub fn get_by_type_id(&self, id: TypeId) -> Option<&Spec> {
+        let value = self.0.get(&id)?;
+        Some(value.as_ref())
+    }
+
     /// Reserves `capacity` for at least additional more elements to be inserted
     /// in the `Registry`. The collection may reserve more space to avoid
     /// frequent reallocations.
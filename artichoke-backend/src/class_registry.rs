@@ -1,7 +1,10 @@
 use std::any::Any;
 use std::convert::TryFrom;
+use std::ptr::NonNull;
 
 use crate::class;
+use crate::core::Intern;
+use crate::def::NotDefinedError;
 use crate::exception::Exception;
 use crate::ffi::InterpreterExtractError;
 use crate::sys;
@@ -33,9 +36,54 @@ pub trait ClassRegistry {
     where
         T: Any;
 
+    /// Check whether the class registered for Rust type `T` defines a
+    /// method with the given name.
+    ///
+    /// This consults mruby's method table via [`sys::mrb_obj_respond_to`]
+    /// rather than funcalling `respond_to?`, so it is safe to call from
+    /// registration code (e.g. to decide whether a core class already
+    /// defines a method before patching one in) without risking re-entering
+    /// the VM through a user-overridable Ruby method.
+    ///
+    /// Returns `false` if `T` has not been registered with
+    /// [`ClassRegistry::def_class`].
+    fn method_defined<T>(&mut self, method: &str) -> Result<bool, Exception>
+    where
+        T: Any;
+
     fn new_instance<T>(&mut self, args: &[Value]) -> Result<Option<Value>, Exception>
     where
         T: Any;
+
+    /// Create a new anonymous `Class`, optionally subclassing `superclass`,
+    /// and configure it with a [`class::AnonymousBuilder`].
+    ///
+    /// Anonymous classes have no name and nothing in the class registry keys
+    /// them to a Rust type, so, unlike [`ClassRegistry::def_class`], this
+    /// does not require `T: Any` and the resulting class cannot later be
+    /// retrieved with [`ClassRegistry::class_spec`]. The returned
+    /// [`Value`] is the only handle to the class, mirroring `Class.new` in
+    /// Ruby.
+    fn new_class<F>(&mut self, superclass: Option<Value>, build: F) -> Result<Value, Exception>
+    where
+        F: FnOnce(class::AnonymousBuilder<'_>) -> Result<class::AnonymousBuilder<'_>, Exception>;
+
+    /// Look up a registered class definition by its resolved `RClass`
+    /// pointer rather than by Rust type.
+    ///
+    /// Every other lookup on this trait requires the caller to already know
+    /// the Rust type a class was registered with, which is unavailable when
+    /// all that's on hand is a Ruby [`Value`] of unknown, possibly runtime
+    /// defined (e.g. `Struct.new`, `Class.new`) class. This resolves each
+    /// registered [`class::Spec`] and compares it against `rclass`, so it
+    /// can find the Rust-backed `Spec` for a class, if any, starting only
+    /// from a class object. Returns `None` for classes that have no `Spec`,
+    /// which includes every class created with
+    /// [`ClassRegistry::new_class`].
+    fn class_spec_for_class(
+        &mut self,
+        rclass: NonNull<sys::RClass>,
+    ) -> Result<Option<&class::Spec>, Exception>;
 }
 
 impl ClassRegistry for Artichoke {
@@ -90,6 +138,30 @@ impl ClassRegistry for Artichoke {
         Ok(value_class)
     }
 
+    fn method_defined<T>(&mut self, method: &str) -> Result<bool, Exception>
+    where
+        T: Any,
+    {
+        let state = self.state.as_ref().ok_or(InterpreterExtractError)?;
+        let spec = state.classes.get::<T>();
+        let rclass = if let Some(spec) = spec {
+            spec.rclass()
+        } else {
+            return Ok(false);
+        };
+        let method = self.intern_string(method.to_string())?;
+        let defined = unsafe {
+            self.with_ffi_boundary(|mrb| {
+                if let Some(mut rclass) = rclass.resolve(mrb) {
+                    sys::mrb_obj_respond_to(mrb, rclass.as_mut(), method.into())
+                } else {
+                    0
+                }
+            })?
+        };
+        Ok(defined != 0)
+    }
+
     fn new_instance<T>(&mut self, args: &[Value]) -> Result<Option<Value>, Exception>
     where
         T: Any,
@@ -120,4 +192,49 @@ impl ClassRegistry for Artichoke {
 
         Ok(instance)
     }
+
+    fn new_class<F>(&mut self, superclass: Option<Value>, build: F) -> Result<Value, Exception>
+    where
+        F: FnOnce(class::AnonymousBuilder<'_>) -> Result<class::AnonymousBuilder<'_>, Exception>,
+    {
+        let mut super_class = if let Some(superclass) = superclass {
+            let rclass = unsafe { sys::mrb_sys_class_ptr(superclass.inner()) };
+            NonNull::new(rclass).ok_or_else(|| NotDefinedError::super_class("(anonymous)"))?
+        } else {
+            let rclass = unsafe { self.mrb.as_mut().object_class };
+            NonNull::new(rclass).ok_or_else(|| NotDefinedError::super_class("Object"))?
+        };
+        let rclass =
+            unsafe { self.with_ffi_boundary(|mrb| sys::mrb_class_new(mrb, super_class.as_mut()))? };
+        let rclass = NonNull::new(rclass).ok_or_else(|| NotDefinedError::class("(anonymous)"))?;
+
+        let builder = class::AnonymousBuilder::new(self, rclass);
+        let builder = build(builder)?;
+        let value = builder.define()?;
+        Ok(Value::from(value))
+    }
+
+    fn class_spec_for_class(
+        &mut self,
+        rclass: NonNull<sys::RClass>,
+    ) -> Result<Option<&class::Spec>, Exception> {
+        let candidates = {
+            let state = self.state.as_ref().ok_or(InterpreterExtractError)?;
+            state
+                .classes
+                .class_specs_by_type_id()
+                .map(|(id, spec)| (id, spec.rclass()))
+                .collect::<Vec<_>>()
+        };
+        let found = unsafe {
+            self.with_ffi_boundary(|mrb| {
+                candidates
+                    .into_iter()
+                    .find(|(_, candidate)| candidate.resolve(mrb) == Some(rclass))
+                    .map(|(id, _)| id)
+            })?
+        };
+        let state = self.state.as_ref().ok_or(InterpreterExtractError)?;
+        Ok(found.and_then(|id| state.classes.get_by_type_id(id)))
+    }
 }
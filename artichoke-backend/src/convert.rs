@@ -21,7 +21,12 @@ mod hash;
 mod nilable;
 mod string;
 
-pub use boxing::{BoxUnboxVmValue, HeapAllocatedData, Immediate, UnboxedValueGuard};
+pub use array::StreamingArray;
+pub use boxing::{
+    init_copy, BoxUnboxVmValue, CloneBehavior, HeapAllocatedData, Immediate, UnboxedValueGuard,
+};
+pub use bytes::StaticBytes;
+pub use hash::StreamingHash;
 
 /// Provide a fallible converter for types that implement an infallible
 /// conversion.
@@ -87,7 +92,7 @@ impl error::Error for UnboxRubyError {}
 
 impl RubyException for UnboxRubyError {
     fn message(&self) -> Cow<'_, [u8]> {
-        Cow::Borrowed(b"Failed to convert from Ruby value to Rust type")
+        Cow::Owned(self.to_string().into_bytes())
     }
 
     fn name(&self) -> Cow<'_, str> {
@@ -158,7 +163,7 @@ impl error::Error for BoxIntoRubyError {}
 
 impl RubyException for BoxIntoRubyError {
     fn message(&self) -> Cow<'_, [u8]> {
-        Cow::Borrowed(b"Failed to convert from Rust type to Ruby value")
+        Cow::Owned(self.to_string().into_bytes())
     }
 
     fn name(&self) -> Cow<'_, str> {
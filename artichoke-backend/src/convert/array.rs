@@ -8,6 +8,29 @@ use crate::types::{Int, Ruby, Rust};
 use crate::value::Value;
 use crate::Artichoke;
 
+/// Wrap a Rust [`IntoIterator`] of [`Value`]s to convert it to a Ruby
+/// `Array` by streaming elements directly into the `Array` backing store.
+///
+/// Unlike the [`TryConvertMut<Vec<Value>, Value>`] converter, which requires
+/// the caller to have already materialized a `Vec`, this converter accepts
+/// any iterator, so a large or lazily generated sequence (e.g. a `Range`
+/// adapter or a generator reading rows from an external source) can be
+/// converted without first collecting into an intermediate `Vec<Value>`.
+#[derive(Debug, Clone, Copy)]
+pub struct StreamingArray<I>(pub I);
+
+impl<I> TryConvertMut<StreamingArray<I>, Value> for Artichoke
+where
+    I: IntoIterator<Item = Value>,
+{
+    type Error = Exception;
+
+    fn try_convert_mut(&mut self, value: StreamingArray<I>) -> Result<Value, Self::Error> {
+        let ary = Array::from_iter(value.0);
+        Array::alloc_value(ary, self)
+    }
+}
+
 impl TryConvertMut<&[Value], Value> for Artichoke {
     type Error = Exception;
 
@@ -514,6 +537,31 @@ mod tests {
         true
     }
 
+    #[quickcheck]
+    fn arr_nested_bstr(arr: Vec<Vec<u8>>) -> bool {
+        let mut interp = fixture();
+        // Borrowed converter
+        let value = interp.try_convert_mut(arr.as_slice()).unwrap();
+        let len = value.funcall(&mut interp, "length", &[], None).unwrap();
+        let len = len.try_into::<usize>(&interp).unwrap();
+        if len != arr.len() {
+            return false;
+        }
+        let recovered: Vec<Vec<u8>> = interp.try_convert_mut(value).unwrap();
+        if recovered != arr {
+            return false;
+        }
+        // Owned converter
+        let value = interp.try_convert_mut(arr.to_vec()).unwrap();
+        let len = value.funcall(&mut interp, "length", &[], None).unwrap();
+        let len = len.try_into::<usize>(&interp).unwrap();
+        if len != arr.len() {
+            return false;
+        }
+        let recovered: Vec<Vec<u8>> = interp.try_convert_mut(value).unwrap();
+        recovered == arr
+    }
+
     #[quickcheck]
     fn roundtrip_err(i: i64) -> bool {
         let mut interp = crate::interpreter().unwrap();
@@ -521,4 +569,17 @@ mod tests {
         let value = value.try_into_mut::<Vec<Value>>(&mut interp);
         value.is_err()
     }
+
+    #[test]
+    fn streaming_array_from_iterator() {
+        let mut interp = crate::interpreter().unwrap();
+        let iter = (0..10).map(|i| interp.convert(i));
+        let values: Vec<Value> = iter.collect();
+        let value = interp
+            .try_convert_mut(super::StreamingArray(values))
+            .unwrap();
+        let len = value.funcall(&mut interp, "length", &[], None).unwrap();
+        let len = len.try_into::<usize>(&interp).unwrap();
+        assert_eq!(len, 10);
+    }
 }
@@ -3,7 +3,6 @@ use std::fmt;
 use std::marker::PhantomData;
 use std::mem::ManuallyDrop;
 use std::ops::{Deref, DerefMut};
-use std::ptr;
 
 use crate::def::NotDefinedError;
 use crate::exception::Exception;
@@ -152,10 +151,19 @@ where
                 .ok_or_else(|| NotDefinedError::class(Self::RUBY_TYPE))?
         };
 
-        // Sanity check that the RClass matches.
-        let value_rclass =
-            interp.with_ffi_boundary(|mrb| sys::mrb_sys_class_of_value(mrb, value.inner()))?;
-        if !ptr::eq(value_rclass, rclass.as_mut()) {
+        // Sanity check that `value` is an instance of `Self`'s class or one
+        // of its subclasses. Ruby code is allowed to subclass a Rust-backed
+        // class (e.g. `class MyEnv < Artichoke::Environ; end`), in which
+        // case `value`'s class is the subclass, not `rclass` itself, so this
+        // must be an ancestry check (`is_a?`) and not a strict equality
+        // check. Type safety for the extracted pointer itself is guaranteed
+        // below by `mrb_data_check_get_ptr`, which compares the embedded
+        // `mrb_data_type` pointer and is unaffected by the Ruby class
+        // hierarchy, since that pointer is unique per Rust type `T`.
+        let is_instance = interp.with_ffi_boundary(|mrb| {
+            sys::mrb_obj_is_kind_of(mrb, value.inner(), rclass.as_mut())
+        })?;
+        if is_instance == 0 {
             let mut message = String::from("Could not extract ");
             message.push_str(Self::RUBY_TYPE);
             message.push_str(" from receiver");
@@ -285,10 +293,58 @@ where
     }
 }
 
+/// How a [`HeapAllocatedData`] type is reproduced when a Ruby `Data` object
+/// wrapping it is `dup`ed or `clone`d.
+///
+/// mruby's default `Kernel#initialize_copy` -- which `dup` and `clone` call
+/// on a freshly allocated, empty `Data` object -- only copies instance
+/// variables. It never touches the embedded data pointer, so without this
+/// trait a duped `Data` object is left with a `NULL` pointer and raises
+/// `TypeError: uninitialized <RUBY_TYPE>` the first time any method tries to
+/// unbox it. Implementing `CloneBehavior` and wiring up [`init_copy`] as a
+/// type's `initialize_copy` method gives it a chance to populate that
+/// pointer, whether by deep-copying the original's data or by raising, for
+/// types (like `ENV`) that MRI does not allow to be duplicated.
+pub trait CloneBehavior: HeapAllocatedData + Sized {
+    /// # Errors
+    ///
+    /// Implementations may return an error to reject `dup`/`clone` outright.
+    fn clone_for_dup(&self, interp: &mut Artichoke) -> Result<Self, Exception>;
+}
+
+/// Back a `Data` type's `initialize_copy` method with its [`CloneBehavior`].
+///
+/// `into` is the freshly allocated, still-empty `Data` object dup/clone
+/// created; `from` is the original object being copied. On success, `into`'s
+/// data pointer is populated with the type's [`CloneBehavior::clone_for_dup`]
+/// of `from`'s data.
+///
+/// # Errors
+///
+/// If `from` does not wrap a `T`, or if `T::clone_for_dup` returns an error,
+/// that error is returned.
+pub fn init_copy<T>(
+    interp: &mut Artichoke,
+    into: Value,
+    mut from: Value,
+) -> Result<Value, Exception>
+where
+    T: CloneBehavior + 'static,
+{
+    let cloned = {
+        let data = unsafe { T::unbox_from_value(&mut from, interp) }?;
+        data.clone_for_dup(interp)?
+    };
+    T::box_into_value(cloned, into, interp)
+}
+
 #[cfg(test)]
 mod tests {
+    use crate::extn::core::exception::TypeError;
     use crate::test::prelude::*;
 
+    use super::{init_copy, CloneBehavior};
+
     // this struct is heap allocated.
     #[derive(Default, Debug, Clone, Hash, PartialEq, Eq, PartialOrd, Ord)]
     struct Container(String);
@@ -351,6 +407,43 @@ mod tests {
         assert_eq!(inner, "contained string contents");
     }
 
+    #[test]
+    fn convert_obj_unbox_from_ruby_subclass_instance() {
+        let mut interp = crate::interpreter().unwrap();
+        let spec =
+            class::Spec::new("Container", None, Some(def::box_unbox_free::<Container>)).unwrap();
+        class::Builder::for_spec(&mut interp, &spec)
+            .value_is_rust_object()
+            .add_method("value", container_value, sys::mrb_args_none())
+            .unwrap()
+            .define()
+            .unwrap();
+        interp.def_class::<Container>(spec).unwrap();
+
+        interp.eval(b"class SubContainer < Container; end").unwrap();
+        let instance = interp.eval(b"SubContainer.allocate").unwrap();
+
+        // This mirrors what happens when a `SubContainer#initialize`
+        // override calls `super`: the Rust `initialize` trampoline is
+        // handed a `slf` that is already an instance of the Ruby subclass,
+        // not of the Rust-backed class itself.
+        let obj = Container(String::from("contained string contents"));
+        let mut value = Container::box_into_value(obj, instance, &mut interp).unwrap();
+
+        let class = value.funcall(&mut interp, "class", &[], None).unwrap();
+        let class_display = class.to_s(&mut interp);
+        assert_eq!(class_display, b"SubContainer");
+
+        let data = unsafe { Container::unbox_from_value(&mut value, &mut interp) }.unwrap();
+        let inner = data.0.as_str();
+        assert_eq!(inner, "contained string contents");
+        drop(data);
+
+        let inner = value.funcall(&mut interp, "value", &[], None).unwrap();
+        let inner = inner.try_into_mut::<&str>(&mut interp).unwrap();
+        assert_eq!(inner, "contained string contents");
+    }
+
     #[test]
     fn convert_obj_not_data() {
         let mut interp = crate::interpreter().unwrap();
@@ -389,4 +482,85 @@ mod tests {
         let data = unsafe { Container::unbox_from_value(&mut value, &mut interp) };
         assert!(data.is_err());
     }
+
+    impl CloneBehavior for Container {
+        fn clone_for_dup(&self, _interp: &mut Artichoke) -> Result<Self, Exception> {
+            Ok(self.clone())
+        }
+    }
+
+    unsafe extern "C" fn container_initialize_copy(
+        mrb: *mut sys::mrb_state,
+        slf: sys::mrb_value,
+    ) -> sys::mrb_value {
+        let from = mrb_get_args!(mrb, required = 1);
+        let mut interp = unwrap_interpreter!(mrb);
+        let mut guard = Guard::new(&mut interp);
+        let into = Value::from(slf);
+        let from = Value::from(from);
+        match init_copy::<Container>(&mut guard, into, from) {
+            Ok(value) => value.inner(),
+            Err(exception) => exception::raise(guard, exception),
+        }
+    }
+
+    #[test]
+    fn init_copy_deep_copies_heap_allocated_data() {
+        let mut interp = crate::interpreter().unwrap();
+        let spec =
+            class::Spec::new("Container", None, Some(def::box_unbox_free::<Container>)).unwrap();
+        class::Builder::for_spec(&mut interp, &spec)
+            .value_is_rust_object()
+            .add_method("value", container_value, sys::mrb_args_none())
+            .unwrap()
+            .add_method(
+                "initialize_copy",
+                container_initialize_copy,
+                sys::mrb_args_req(1),
+            )
+            .unwrap()
+            .define()
+            .unwrap();
+        interp.def_class::<Container>(spec).unwrap();
+
+        let obj = Container(String::from("contained string contents"));
+        let value = Container::alloc_value(obj, &mut interp).unwrap();
+
+        let duped = value.funcall(&mut interp, "dup", &[], None).unwrap();
+        let inner = duped.funcall(&mut interp, "value", &[], None).unwrap();
+        let inner = inner.try_into_mut::<&str>(&mut interp).unwrap();
+        assert_eq!(inner, "contained string contents");
+    }
+
+    #[test]
+    fn init_copy_propagates_clone_behavior_error() {
+        let mut interp = crate::interpreter().unwrap();
+
+        struct Rejecting;
+
+        impl HeapAllocatedData for Rejecting {
+            const RUBY_TYPE: &'static str = "Rejecting";
+        }
+
+        impl CloneBehavior for Rejecting {
+            fn clone_for_dup(&self, _interp: &mut Artichoke) -> Result<Self, Exception> {
+                Err(TypeError::from("can't dup Rejecting").into())
+            }
+        }
+
+        let spec =
+            class::Spec::new("Rejecting", None, Some(def::box_unbox_free::<Rejecting>)).unwrap();
+        class::Builder::for_spec(&mut interp, &spec)
+            .value_is_rust_object()
+            .define()
+            .unwrap();
+        interp.def_class::<Rejecting>(spec).unwrap();
+
+        let original = Rejecting::alloc_value(Rejecting, &mut interp).unwrap();
+
+        // `clone_for_dup` errors before `into` is ever touched, so a `nil`
+        // placeholder is fine here.
+        let result = init_copy::<Rejecting>(&mut interp, Value::nil(), original);
+        assert!(result.is_err());
+    }
 }
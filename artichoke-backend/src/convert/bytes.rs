@@ -2,11 +2,12 @@ use std::borrow::Cow;
 use std::convert::TryFrom;
 use std::ffi::{OsStr, OsString};
 use std::slice;
+use std::sync::Arc;
 
 use crate::convert::UnboxRubyError;
 use crate::core::{ConvertMut, TryConvertMut};
 use crate::exception::Exception;
-use crate::ffi;
+use crate::ffi::{self, InterpreterExtractError};
 use crate::sys;
 use crate::types::{Ruby, Rust};
 use crate::value::Value;
@@ -31,6 +32,69 @@ impl ConvertMut<&[u8], Value> for Artichoke {
     }
 }
 
+/// A byte string that is guaranteed to live for the remainder of the
+/// program, e.g. a buffer embedded in the binary with `include_bytes!`.
+///
+/// Wrap a `&'static [u8]` in `StaticBytes` to convert it to a Ruby `String`
+/// with [`ConvertMut`] without copying its contents onto the mruby heap. This
+/// is a newtype rather than an additional `ConvertMut<&'static [u8], Value>`
+/// impl because the existing `ConvertMut<&[u8], Value>` impl is already
+/// generic over all lifetimes, including `'static`, and Rust does not permit
+/// a second, more specific impl to overlap it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct StaticBytes(pub &'static [u8]);
+
+impl ConvertMut<StaticBytes, Value> for Artichoke {
+    /// Convert a [`StaticBytes`] into a Ruby `String` without copying its
+    /// contents onto the mruby heap.
+    ///
+    /// This converter is backed by `mrb_str_new_static`, which stores the
+    /// pointer and length directly in the `RString` and marks it `NOFREE`,
+    /// so mruby never attempts to free or reallocate the backing buffer.
+    /// Because the buffer is shared rather than copied, a `String` created
+    /// this way must never be mutated in place by Ruby code.
+    fn convert_mut(&mut self, value: StaticBytes) -> Value {
+        let raw = value.0.as_ptr() as *const i8;
+        let len = value.0.len();
+        let string =
+            unsafe { self.with_ffi_boundary(|mrb| sys::mrb_str_new_static(mrb, raw, len)) };
+        Value::from(string.unwrap())
+    }
+}
+
+impl Artichoke {
+    /// Convert a reference-counted byte buffer into a Ruby `String` without
+    /// copying its contents onto the mruby heap.
+    ///
+    /// Unlike [`StaticBytes`], `bytes` does not need to be known at compile
+    /// time -- an `Arc<[u8]>` can wrap a buffer allocated at runtime, such as
+    /// the contents of a file read with [`std::fs::read`]. This is backed by
+    /// the same `mrb_str_new_static` as `StaticBytes`, so mruby never frees
+    /// or reallocates the pointer it is given. Because mruby's GC has no
+    /// visibility into `bytes`, the interpreter's
+    /// [`shared_bytes`](crate::state::shared_bytes) registry keeps its own
+    /// strong reference to it for the remainder of the interpreter's
+    /// lifetime rather than dropping it once every `String` built from it is
+    /// collected -- trading a bounded, interpreter-lifetime-scoped retain
+    /// for avoiding the copy. As with `StaticBytes`, a `String` created this
+    /// way must never be mutated in place by Ruby code.
+    ///
+    /// # Errors
+    ///
+    /// If the interpreter's state is inaccessible, an error is returned.
+    pub fn convert_shared_bytes(&mut self, bytes: Arc<[u8]>) -> Result<Value, Exception> {
+        let raw = bytes.as_ptr() as *const i8;
+        let len = bytes.len();
+        {
+            let state = self.state.as_mut().ok_or(InterpreterExtractError)?;
+            state.shared_bytes.retain(bytes);
+        }
+        let string =
+            unsafe { self.with_ffi_boundary(|mrb| sys::mrb_str_new_static(mrb, raw, len)) }?;
+        Ok(Value::from(string))
+    }
+}
+
 impl<'a> ConvertMut<Cow<'a, [u8]>, Value> for Artichoke {
     fn convert_mut(&mut self, value: Cow<'a, [u8]>) -> Value {
         match value {
@@ -113,6 +177,7 @@ impl<'a> TryConvertMut<Value, &'a [u8]> for Artichoke {
 mod tests {
     use quickcheck_macros::quickcheck;
 
+    use super::{Arc, StaticBytes};
     use crate::test::prelude::*;
 
     #[test]
@@ -196,4 +261,33 @@ mod tests {
         let value = value.try_into_mut::<Vec<u8>>(&mut interp);
         value.is_err()
     }
+
+    #[test]
+    fn static_bytes_roundtrip() {
+        let mut interp = crate::interpreter().unwrap();
+        let value = interp.convert_mut(StaticBytes(b"static artichoke"));
+        let value = value.try_into_mut::<Vec<u8>>(&mut interp).unwrap();
+        assert_eq!(value, b"static artichoke".to_vec());
+    }
+
+    #[test]
+    fn shared_bytes_roundtrip() {
+        let mut interp = crate::interpreter().unwrap();
+        let bytes: Arc<[u8]> = Arc::from(b"shared artichoke".to_vec());
+        let value = interp.convert_shared_bytes(Arc::clone(&bytes)).unwrap();
+        let value = value.try_into_mut::<Vec<u8>>(&mut interp).unwrap();
+        assert_eq!(value, bytes.to_vec());
+    }
+
+    #[test]
+    fn shared_bytes_survives_dropping_callers_reference() {
+        let mut interp = crate::interpreter().unwrap();
+        let bytes: Arc<[u8]> = Arc::from(b"shared artichoke".to_vec());
+        let value = interp.convert_shared_bytes(bytes).unwrap();
+        // The caller's `Arc` is gone, but the interpreter's `shared_bytes`
+        // registry keeps its own strong reference alive, so the buffer
+        // `value` points at is still valid.
+        let value = value.try_into_mut::<Vec<u8>>(&mut interp).unwrap();
+        assert_eq!(value, b"shared artichoke".to_vec());
+    }
 }
@@ -230,4 +230,49 @@ mod tests {
         let value = value.try_into::<usize>(&interp);
         assert!(value.is_err());
     }
+
+    // `MRB_INT64` is set unconditionally in `build.rs`, so `Int` (`mrb_int`)
+    // is a full 64-bit integer on every target Artichoke supports, including
+    // 32-bit ones. These tests pin that invariant: if the build is ever
+    // reconfigured to compile mruby with a 32-bit `mrb_int`, these would fail
+    // well before any Ruby-level overflow spec did.
+    #[test]
+    fn int_boundary_values_roundtrip() {
+        let interp = crate::interpreter().unwrap();
+        for boundary in &[Int::MAX, Int::MIN, 0, -1] {
+            let value = interp.convert(*boundary);
+            let value = value.try_into::<Int>(&interp).unwrap();
+            assert_eq!(value, *boundary);
+        }
+    }
+
+    #[test]
+    fn u64_above_int_max_does_not_convert() {
+        let interp = crate::interpreter().unwrap();
+        let too_big = Int::MAX as u64 + 1;
+        let result = TryConvert::<_, Value>::try_convert(&interp, too_big);
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn u64_at_int_max_converts() {
+        let interp = crate::interpreter().unwrap();
+        let at_max = Int::MAX as u64;
+        let value = TryConvert::<_, Value>::try_convert(&interp, at_max).unwrap();
+        let value = value.try_into::<Int>(&interp).unwrap();
+        assert_eq!(value, Int::MAX);
+    }
+
+    #[test]
+    fn usize_above_int_max_does_not_convert() {
+        // This assertion is only meaningful on 64-bit targets, where `usize`
+        // can represent values greater than `Int::MAX`.
+        if usize::try_from(Int::MAX).is_err() {
+            return;
+        }
+        let interp = crate::interpreter().unwrap();
+        let too_big = Int::MAX as usize + 1;
+        let result = TryConvert::<_, Value>::try_convert(&interp, too_big);
+        assert!(result.is_err());
+    }
 }
@@ -39,7 +39,11 @@ mod tests {
         // get a Ruby Value that can't be converted to a primitive type.
         let value = interp.eval(b"Object.new").unwrap();
         let result = value.try_into::<Fp>(&interp);
-        assert!(result.is_err());
+        let err = result.unwrap_err();
+        assert_eq!(
+            &b"failed to convert from Ruby Object to Rust f64"[..],
+            err.message().as_ref()
+        );
     }
 
     #[quickcheck]
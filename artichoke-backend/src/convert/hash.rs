@@ -13,6 +13,40 @@ use crate::Artichoke;
 // TODO(GH-28): implement `PartialEq`, `Eq`, and `Hash` on `Value`.
 // TODO(GH-29): implement `Convert<HashMap<Value, Value>>`.
 
+/// Wrap a Rust [`IntoIterator`] of `(K, V)` pairs to convert it to a Ruby
+/// `Hash` by streaming entries directly into a single pre-sized `Hash`.
+///
+/// Unlike the [`ConvertMut<Vec<(Value, Value)>, Value>`] converter, which
+/// requires the caller to have already materialized a `Vec` of converted
+/// `Value` pairs, this converter accepts any iterator of native pairs and
+/// converts each key and value as it is inserted, so a large result set
+/// (e.g. `ENV#to_h` or `MatchData#named_captures`) can be converted without
+/// an intermediate `Vec<(Value, Value)>`.
+#[derive(Debug, Clone, Copy)]
+pub struct StreamingHash<I>(pub I);
+
+impl<I, K, V> TryConvertMut<StreamingHash<I>, Value> for Artichoke
+where
+    I: IntoIterator<Item = (K, V)>,
+    Artichoke: TryConvertMut<K, Value, Error = Exception>,
+    Artichoke: TryConvertMut<V, Value, Error = Exception>,
+{
+    type Error = Exception;
+
+    fn try_convert_mut(&mut self, value: StreamingHash<I>) -> Result<Value, Self::Error> {
+        let iter = value.0.into_iter();
+        let capa = Int::try_from(iter.size_hint().0).unwrap_or_default();
+        let hash = unsafe { self.with_ffi_boundary(|mrb| sys::mrb_hash_new_capa(mrb, capa)) };
+        let hash = hash.unwrap();
+        for (key, val) in iter {
+            let key = self.try_convert_mut(key)?.inner();
+            let val = self.try_convert_mut(val)?.inner();
+            let _ = unsafe { self.with_ffi_boundary(|mrb| sys::mrb_hash_set(mrb, hash, key, val)) };
+        }
+        Ok(Value::from(hash))
+    }
+}
+
 impl ConvertMut<Vec<(Value, Value)>, Value> for Artichoke {
     fn convert_mut(&mut self, value: Vec<(Value, Value)>) -> Value {
         let capa = Int::try_from(value.len()).unwrap_or_default();
@@ -106,8 +140,35 @@ mod tests {
     use quickcheck_macros::quickcheck;
     use std::collections::HashMap;
 
+    use super::StreamingHash;
     use crate::test::prelude::*;
 
+    #[test]
+    fn streaming_hash_converts_native_pairs_without_an_intermediate_vec() {
+        let mut interp = crate::interpreter().unwrap();
+        let pairs = vec![
+            (b"a".to_vec(), b"1".to_vec()),
+            (b"b".to_vec(), b"2".to_vec()),
+        ];
+        let value = interp.try_convert_mut(StreamingHash(pairs)).unwrap();
+        let len = value.funcall(&mut interp, "length", &[], None).unwrap();
+        let len = len.try_into::<usize>(&interp).unwrap();
+        assert_eq!(len, 2);
+        let key = interp.convert_mut(b"a".to_vec());
+        let retrieved = value.funcall(&mut interp, "[]", &[key], None).unwrap();
+        let retrieved: Vec<u8> = retrieved.try_into_mut(&mut interp).unwrap();
+        assert_eq!(retrieved, b"1".to_vec());
+    }
+
+    #[test]
+    fn streaming_hash_of_empty_iterator_is_an_empty_hash() {
+        let mut interp = crate::interpreter().unwrap();
+        let pairs: Vec<(Vec<u8>, Vec<u8>)> = vec![];
+        let value = interp.try_convert_mut(StreamingHash(pairs)).unwrap();
+        let empty = value.funcall(&mut interp, "empty?", &[], None).unwrap();
+        assert!(empty.try_into::<bool>(&interp).unwrap());
+    }
+
     #[quickcheck]
     fn roundtrip_kv(hash: HashMap<Vec<u8>, Vec<u8>>) -> bool {
         let mut interp = crate::interpreter().unwrap();
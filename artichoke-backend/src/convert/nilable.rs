@@ -4,7 +4,7 @@
 
 use crate::core::{Convert, ConvertMut, TryConvert, TryConvertMut, Value as _};
 use crate::exception::Exception;
-use crate::types::Int;
+use crate::types::{Fp, Int};
 use crate::value::Value;
 use crate::Artichoke;
 
@@ -137,3 +137,15 @@ impl TryConvert<Value, Option<Int>> for Artichoke {
         }
     }
 }
+
+impl TryConvert<Value, Option<Fp>> for Artichoke {
+    type Error = Exception;
+
+    fn try_convert(&self, value: Value) -> Result<Option<Fp>, Self::Error> {
+        if value.is_nil() {
+            Ok(None)
+        } else {
+            self.try_convert(value).map(Some)
+        }
+    }
+}
@@ -0,0 +1,79 @@
+use std::fmt;
+
+use crate::extn::core::env::backend::EnvArgumentError;
+
+/// Hooks an embedder can install to moderate access to `ENV`.
+///
+/// Hosts that embed Artichoke to run untrusted or multi-tenant scripts can
+/// use these hooks to deny writes to certain environment variables, keep
+/// secret values out of `ENV.inspect`/`ENV.to_h` output, and audit which
+/// variables scripts read.
+///
+/// Install a set of hooks with
+/// [`Artichoke::set_env_security_hooks`](crate::Artichoke::set_env_security_hooks).
+/// Hooks are consulted by every `Artichoke::Environ`, including the one
+/// lazily created to back the `ENV` global the first time a script touches
+/// it.
+#[derive(Clone, Copy)]
+pub struct EnvSecurityHooks {
+    /// Called before a write to `ENV`. Return `Err` to deny the write; the
+    /// returned error is raised in the interpreter as an `ArgumentError`.
+    pub validate_write: fn(name: &[u8], value: Option<&[u8]>) -> Result<(), EnvArgumentError>,
+
+    /// Called with each key/value pair before it is returned from `to_h`
+    /// (and therefore from `ENV.inspect`, which is implemented in terms of
+    /// `to_h`). Return a replacement value, for example `***REDACTED***`, to
+    /// keep a secret out of dumped output.
+    pub redact: fn(name: &[u8], value: Vec<u8>) -> Vec<u8>,
+
+    /// Called whenever a key is read via `ENV[name]`. Does not affect the
+    /// read's result; intended for audit logging of access to configured
+    /// secret keys.
+    pub audit_read: fn(name: &[u8]),
+
+    /// Called after a successful write via `ENV[name] = value`, with the
+    /// value the key held before the write (`None` if it was unset) and the
+    /// value it was just set to (`None` if the write unset it). Does not
+    /// affect the write; intended for config hot-reload and audit logging in
+    /// host applications. See also `ENV.on_change`, a pure-Ruby observer API
+    /// built on top of `ENV[]=` for scripts that want the same callback.
+    pub on_change: fn(name: &[u8], old: Option<&[u8]>, new: Option<&[u8]>),
+}
+
+impl fmt::Debug for EnvSecurityHooks {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("EnvSecurityHooks")
+            .field("validate_write", &"fn(&[u8], Option<&[u8]>) -> Result<(), EnvArgumentError>")
+            .field("redact", &"fn(&[u8], Vec<u8>) -> Vec<u8>")
+            .field("audit_read", &"fn(&[u8])")
+            .field("on_change", &"fn(&[u8], Option<&[u8]>, Option<&[u8]>)")
+            .finish()
+    }
+}
+
+/// Default [`EnvSecurityHooks`].
+///
+/// Allows all writes, redacts nothing, and audit-logs nothing, which
+/// reproduces the behavior of `ENV` before these hooks existed.
+impl Default for EnvSecurityHooks {
+    fn default() -> Self {
+        fn allow_all(_name: &[u8], _value: Option<&[u8]>) -> Result<(), EnvArgumentError> {
+            Ok(())
+        }
+
+        fn no_redact(_name: &[u8], value: Vec<u8>) -> Vec<u8> {
+            value
+        }
+
+        fn no_audit(_name: &[u8]) {}
+
+        fn no_on_change(_name: &[u8], _old: Option<&[u8]>, _new: Option<&[u8]>) {}
+
+        Self {
+            validate_write: allow_all,
+            redact: no_redact,
+            audit_read: no_audit,
+            on_change: no_on_change,
+        }
+    }
+}
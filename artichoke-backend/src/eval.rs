@@ -2,16 +2,111 @@ use bstr::ByteSlice;
 use std::ffi::OsStr;
 use std::path::Path;
 
-use crate::core::{Eval, LoadSources, Parser, Value as _};
+use crate::convert::BoxUnboxVmValue;
+use crate::core::{DefineConstant, Eval, LoadSources, Parser, Value as _};
 use crate::exception::Exception;
 use crate::exception_handler;
 use crate::extn::core::exception::{ArgumentError, Fatal};
+use crate::extn::core::io::IO;
 use crate::ffi::{self, InterpreterExtractError};
 use crate::state::parser::Context;
-use crate::sys::protect;
+use crate::sys::{self, protect};
 use crate::value::Value;
 use crate::Artichoke;
 
+/// A `%{name}`-templated `eval` that is parsed once and callable many times.
+///
+/// Build one with [`Artichoke::prepare`] and run it with [`Prepared::call`],
+/// passing a value for each `%{name}` placeholder that appeared in the
+/// template. Parameters are bound as call arguments to a `Proc` compiled once
+/// at [`Artichoke::prepare`] time, not spliced into Ruby source on every
+/// call, so running the same template with different inputs does not
+/// re-parse the source and cannot be used to inject arbitrary Ruby the way
+/// building up a source string with `format!`/`String::push_str` can.
+///
+/// `Prepared` intentionally never unregisters the compiled `Proc` it roots
+/// with [`sys::mrb_gc_register`](crate::sys::mrb_gc_register): it is meant
+/// for templates that are prepared once -- for example, at startup -- and
+/// reused for the life of the interpreter, like a compiled regular
+/// expression, not for one-off substitutions. [`Drop`] cannot be given the
+/// `&mut Artichoke` a call to
+/// [`mrb_gc_unregister`](crate::sys::mrb_gc_unregister) would need, so
+/// there's no way to root-then-unregister on a normal Rust value lifecycle
+/// without also giving `Prepared` a `close`-style method every caller would
+/// have to remember to call.
+#[derive(Debug, Clone)]
+pub struct Prepared {
+    proc: Value,
+    params: Vec<Vec<u8>>,
+}
+
+impl Prepared {
+    /// Call this template, binding `params` to the placeholders that
+    /// appeared in the template at [`Artichoke::prepare`] time.
+    ///
+    /// # Errors
+    ///
+    /// If `params` does not contain a value for every placeholder in the
+    /// template, an [`ArgumentError`] is returned.
+    pub fn call(
+        &self,
+        interp: &mut Artichoke,
+        params: &[(&str, Value)],
+    ) -> Result<Value, Exception> {
+        let mut args = Vec::with_capacity(self.params.len());
+        for name in &self.params {
+            let value = params
+                .iter()
+                .find(|(param, _)| param.as_bytes() == name.as_slice())
+                .map(|(_, value)| *value)
+                .ok_or_else(|| {
+                    let mut message = String::from("missing value for template parameter `");
+                    message.push_str(&String::from_utf8_lossy(name));
+                    message.push('`');
+                    ArgumentError::from(message)
+                })?;
+            args.push(value);
+        }
+        self.proc.funcall(interp, "call", &args, None)
+    }
+}
+
+/// Split `code` on a standalone `__END__` marker line, mirroring the
+/// `__END__` handling in mruby's parser (see
+/// `mrbgems/mruby-compiler/core/parse.y`), which recognizes a line
+/// consisting of exactly `__END__` at the start of a line as the end of the
+/// program and stops parsing there without exposing the remaining bytes.
+///
+/// Returns `Some((source, data))`, where `source` is everything before the
+/// marker line and `data` is everything after it (excluding the marker
+/// line's own newline), or `None` if `code` has no such marker line.
+fn split_end_of_file_data(code: &[u8]) -> Option<(&[u8], &[u8])> {
+    let mut line_start = 0;
+    loop {
+        let line_end = code[line_start..]
+            .find_byte(b'\n')
+            .map_or(code.len(), |offset| line_start + offset);
+        let line = &code[line_start..line_end];
+        let line = if line.ends_with(b"\r") {
+            &line[..line.len() - 1]
+        } else {
+            line
+        };
+        if line == b"__END__" {
+            let data_start = if line_end < code.len() {
+                line_end + 1
+            } else {
+                line_end
+            };
+            return Some((&code[..line_start], &code[data_start..]));
+        }
+        if line_end >= code.len() {
+            return None;
+        }
+        line_start = line_end + 1;
+    }
+}
+
 impl Eval for Artichoke {
     type Value = Value;
 
@@ -19,13 +114,16 @@ impl Eval for Artichoke {
 
     fn eval(&mut self, code: &[u8]) -> Result<Self::Value, Self::Error> {
         trace!("Attempting eval of Ruby source");
+        self.replay_eval(code)?;
+        self.record_eval(code);
+        let mut guard = self.enter_recursive_call()?;
         let result = unsafe {
-            let state = self.state.as_mut().ok_or(InterpreterExtractError)?;
+            let state = guard.state.as_mut().ok_or(InterpreterExtractError)?;
             let parser = state.parser.as_mut().ok_or(InterpreterExtractError)?;
             let context = parser.context_mut() as *mut _;
-            self.with_ffi_boundary(|mrb| protect::eval(mrb, context, code))?
+            guard.with_ffi_boundary(|mrb| protect::eval(mrb, context, code))?
         };
-        match result {
+        let result = match result {
             Ok(value) => {
                 let value = Value::from(value);
                 if value.is_unreachable() {
@@ -43,11 +141,18 @@ impl Eval for Artichoke {
             }
             Err(exception) => {
                 let exception = Value::from(exception);
-                let debug = exception.inspect(self);
+                let debug = exception.inspect(&mut guard);
                 debug!("Failed eval raised exception: {:?}", debug.as_bstr());
-                Err(exception_handler::last_error(self, exception)?)
+                Err(exception_handler::last_error(&mut guard, exception)?)
             }
+        };
+        // Flush any output buffered while running this eval so hosts see
+        // script output promptly without needing to call `$stdout.flush`
+        // themselves.
+        if let Some(state) = guard.state.as_mut() {
+            let _ = state.output.flush();
         }
+        result
     }
 
     fn eval_os_str(&mut self, code: &OsStr) -> Result<Self::Value, Self::Error> {
@@ -60,10 +165,114 @@ impl Eval for Artichoke {
             .ok_or_else(|| ArgumentError::from("path name contains null byte"))?;
         self.push_context(context)?;
         let code = self.read_source_file_contents(file)?.into_owned();
-        let result = self.eval(code.as_slice());
+        let result = if let Some((code, data)) = split_end_of_file_data(&code) {
+            let data = IO::alloc_value(IO::data(data.to_vec()), self)?;
+            self.define_global_constant("DATA", data)
+                .and_then(|()| self.eval(code))
+        } else {
+            self.eval(code.as_slice())
+        };
         let _ = self.pop_context()?;
         result
     }
+
+    fn release_last_eval_result(&mut self) -> Result<(), Self::Error> {
+        unsafe {
+            let state = self.state.as_mut().ok_or(InterpreterExtractError)?;
+            let parser = state.parser.as_mut().ok_or(InterpreterExtractError)?;
+            parser.release_stack_keep();
+        }
+        // Eval trivial code so the VM clears its register stack on this run
+        // instead of preserving the slot that pinned the previous eval's
+        // return value.
+        let _ = self.eval(b"nil")?;
+        Ok(())
+    }
+}
+
+impl Artichoke {
+    /// Parse `code`, which may contain `%{name}` placeholders, into a
+    /// [`Prepared`] template that can be run repeatedly with
+    /// [`Prepared::call`], binding a different value to each placeholder on
+    /// every call without re-parsing `code`.
+    ///
+    /// Each placeholder becomes a parameter of a `Proc` compiled once by this
+    /// call; [`Prepared::call`] invokes that `Proc` with the bound values as
+    /// call arguments, so they are never interpolated into Ruby source.
+    ///
+    /// # Errors
+    ///
+    /// If `code` cannot be parsed as Ruby source once placeholders are
+    /// extracted, the resulting [`SyntaxError`](crate::extn::core::exception::SyntaxError)
+    /// is returned.
+    pub fn prepare(&mut self, code: &[u8]) -> Result<Prepared, Exception> {
+        let mut params = Vec::new();
+        let mut template = Vec::with_capacity(code.len());
+        let mut rest = code;
+        while let Some(start) = rest.find("%{") {
+            template.extend_from_slice(&rest[..start]);
+            rest = &rest[start + 2..];
+            let end = rest
+                .find("}")
+                .ok_or_else(|| ArgumentError::from("unterminated template placeholder"))?;
+            let name = &rest[..end];
+            if name.is_empty() || !name.iter().all(|&b| b == b'_' || b.is_ascii_alphanumeric()) {
+                return Err(ArgumentError::from("invalid template placeholder name").into());
+            }
+            if !params.iter().any(|param: &Vec<u8>| param == name) {
+                params.push(name.to_vec());
+            }
+            template.extend_from_slice(name);
+            rest = &rest[end + 1..];
+        }
+        template.extend_from_slice(rest);
+
+        let mut source = Vec::with_capacity(template.len() + 32);
+        source.extend_from_slice(b"lambda { |");
+        for (index, name) in params.iter().enumerate() {
+            if index > 0 {
+                source.extend_from_slice(b", ");
+            }
+            source.extend_from_slice(name);
+        }
+        source.extend_from_slice(b"| ");
+        source.extend_from_slice(&template);
+        source.extend_from_slice(b" }");
+
+        let proc = self.eval(&source)?;
+        // Safety:
+        //
+        // `proc` is rooted with `mrb_gc_register` so it survives past the
+        // next `eval`, which would otherwise release the VM stack slot that
+        // protects it from collection. See the type-level documentation on
+        // `Prepared` for why this root is never unregistered.
+        unsafe {
+            self.with_ffi_boundary(|mrb| sys::mrb_gc_register(mrb, proc.inner()))?;
+        }
+        Ok(Prepared { proc, params })
+    }
+
+    /// Run `code`, guaranteeing `cleanup` runs afterward, whether `code`
+    /// returned a value or raised.
+    ///
+    /// This is for host resources acquired before handing control to Ruby --
+    /// a file handle, a lock, a timer -- that must be released regardless of
+    /// how the eval finished, without the caller having to duplicate the
+    /// release on every `eval` error path.
+    ///
+    /// `eval` already runs `code` behind [`sys::mrb_protect`], which catches
+    /// any `longjmp` a raise performs before it reaches this call (see
+    /// [`sys::protect`]), so by the time `eval` returns here, `code` has
+    /// unconditionally finished one way or the other -- `cleanup` cannot be
+    /// skipped by an exception unwinding past it.
+    pub fn eval_scoped<F>(&mut self, code: &[u8], cleanup: F) -> Result<Value, Exception>
+    where
+        F: FnOnce(&mut Self),
+    {
+        let result = self.eval(code);
+        cleanup(self);
+        result
+    }
 }
 
 #[cfg(test)]
@@ -147,6 +356,62 @@ mod tests {
         }
     }
 
+    mod reentrant {
+        use crate::test::prelude::*;
+
+        #[derive(Debug)]
+        struct Reenter;
+
+        unsafe extern "C" fn reenter_eval(
+            mrb: *mut sys::mrb_state,
+            _slf: sys::mrb_value,
+        ) -> sys::mrb_value {
+            let mut interp = unwrap_interpreter!(mrb);
+            let mut guard = Guard::new(&mut interp);
+            // This eval re-enters the VM from within a trampoline that was
+            // itself invoked from a Ruby call. `Guard` ensures the `State`
+            // moved into this trampoline is not concurrently held anywhere
+            // else on the call stack while `eval` moves it into the `mrb`
+            // userdata pointer again.
+            let result = if let Ok(value) = guard.eval(b"1 + 1") {
+                value
+            } else {
+                Value::nil()
+            };
+            result.inner()
+        }
+
+        impl File for Reenter {
+            type Artichoke = Artichoke;
+
+            type Error = Exception;
+
+            fn require(interp: &mut Artichoke) -> Result<(), Self::Error> {
+                let spec = module::Spec::new(interp, "Reenter", None)?;
+                module::Builder::for_spec(interp, &spec)
+                    .add_self_method("two", reenter_eval, sys::mrb_args_none())?
+                    .define()?;
+                interp.def_module::<Self>(spec)?;
+                Ok(())
+            }
+        }
+
+        #[test]
+        fn rust_to_ruby_to_rust_to_ruby() {
+            let mut interp = crate::interpreter().unwrap();
+            interp
+                .def_file_for_type::<_, Reenter>("reenter.rb")
+                .unwrap();
+            // `require` evals Ruby, which calls back into a Rust trampoline
+            // (`reenter_eval`), which evals Ruby again -- two levels of
+            // Rust/Ruby nesting beyond the top-level `eval` call.
+            let code = br#"require 'reenter'; Reenter.two"#;
+            let result = interp.eval(code).unwrap();
+            let result = result.try_into::<i64>(&interp).unwrap();
+            assert_eq!(result, 2);
+        }
+    }
+
     #[test]
     fn eval_with_context() {
         let mut interp = crate::interpreter().unwrap();
@@ -215,6 +480,22 @@ mod tests {
         assert_eq!(result, "(eval)");
     }
 
+    #[test]
+    fn release_last_eval_result_allows_retained_value_to_be_collected() {
+        use crate::gc::MrbGarbageCollection;
+
+        let mut interp = crate::interpreter().unwrap();
+        let baseline_object_count = interp.live_object_count();
+        let _ = interp.eval(b"'retained'").unwrap();
+        interp.release_last_eval_result().unwrap();
+        interp.full_gc();
+        assert_eq!(
+            interp.live_object_count(),
+            baseline_object_count,
+            "releasing the last eval result should allow it to be collected"
+        );
+    }
+
     #[test]
     fn return_syntax_error() {
         let mut interp = crate::interpreter().unwrap();
@@ -224,4 +505,122 @@ mod tests {
         let err = interp.eval(b"require 'fail'").unwrap_err();
         assert_eq!("SyntaxError", err.name().as_ref());
     }
+
+    mod end_of_file_data {
+        use super::super::split_end_of_file_data;
+
+        #[test]
+        fn no_marker_returns_none() {
+            assert_eq!(split_end_of_file_data(b"puts 'hi'\n"), None);
+        }
+
+        #[test]
+        fn marker_splits_source_from_trailing_data() {
+            let code = b"puts 'hi'\n__END__\nsome data\nmore data\n";
+            let (source, data) = split_end_of_file_data(code).unwrap();
+            assert_eq!(source, &b"puts 'hi'\n"[..]);
+            assert_eq!(data, &b"some data\nmore data\n"[..]);
+        }
+
+        #[test]
+        fn marker_with_no_trailing_newline_has_no_data() {
+            let code = b"puts 'hi'\n__END__";
+            let (source, data) = split_end_of_file_data(code).unwrap();
+            assert_eq!(source, &b"puts 'hi'\n"[..]);
+            assert_eq!(data, &b""[..]);
+        }
+
+        #[test]
+        fn marker_not_at_start_of_line_is_not_recognized() {
+            assert_eq!(split_end_of_file_data(b"x = '__END__'\n"), None);
+        }
+
+        #[test]
+        fn marker_not_alone_on_line_is_not_recognized() {
+            assert_eq!(split_end_of_file_data(b"__END__ trailing\n"), None);
+        }
+    }
+
+    mod scoped {
+        use crate::test::prelude::*;
+
+        #[test]
+        fn cleanup_runs_on_success() {
+            let mut interp = crate::interpreter().unwrap();
+            let mut cleaned_up = false;
+            let result = interp
+                .eval_scoped(b"1 + 1", |_interp| cleaned_up = true)
+                .unwrap();
+            let result = result.try_into::<i64>(&interp).unwrap();
+            assert_eq!(result, 2);
+            assert!(cleaned_up);
+        }
+
+        #[test]
+        fn cleanup_runs_on_raise() {
+            let mut interp = crate::interpreter().unwrap();
+            let mut cleaned_up = false;
+            let err = interp
+                .eval_scoped(b"raise ArgumentError, 'oops'", |_interp| cleaned_up = true)
+                .unwrap_err();
+            assert_eq!("ArgumentError", err.name().as_ref());
+            assert!(cleaned_up);
+        }
+
+        #[test]
+        fn interpreter_is_usable_after_cleanup() {
+            let mut interp = crate::interpreter().unwrap();
+            let _ = interp.eval_scoped(b"raise 'boom'", |_interp| {});
+            let result = interp.eval(b"1 + 1").unwrap();
+            let result = result.try_into::<i64>(&interp).unwrap();
+            assert_eq!(result, 2);
+        }
+    }
+
+    mod prepared {
+        use crate::test::prelude::*;
+
+        #[test]
+        fn call_binds_placeholders_as_arguments_not_source() {
+            let mut interp = crate::interpreter().unwrap();
+            let prepared = interp.prepare(b"%{user}.length").unwrap();
+
+            let user = interp.convert_mut("hello");
+            let result = prepared.call(&mut interp, &[("user", user)]).unwrap();
+            let result = result.try_into::<i64>(&interp).unwrap();
+            assert_eq!(result, 5);
+
+            // The same `Prepared` is reused with a different value, without
+            // re-parsing the template.
+            let user = interp.convert_mut("a malicious '); system(\"rm -rf /\"); (\"");
+            let result = prepared.call(&mut interp, &[("user", user)]).unwrap();
+            let result = result.try_into::<i64>(&interp).unwrap();
+            assert_eq!(result, 38);
+        }
+
+        #[test]
+        fn call_with_repeated_placeholder_binds_once() {
+            let mut interp = crate::interpreter().unwrap();
+            let prepared = interp.prepare(b"%{n} + %{n}").unwrap();
+            let n = interp.convert(21_i64);
+            let result = prepared.call(&mut interp, &[("n", n)]).unwrap();
+            let result = result.try_into::<i64>(&interp).unwrap();
+            assert_eq!(result, 42);
+        }
+
+        #[test]
+        fn call_missing_param_is_argument_error() {
+            let mut interp = crate::interpreter().unwrap();
+            let prepared = interp.prepare(b"%{user}.length").unwrap();
+            let err = prepared.call(&mut interp, &[]).unwrap_err();
+            assert_eq!("ArgumentError", err.name().as_ref());
+        }
+
+        #[test]
+        fn unterminated_placeholder_is_argument_error() {
+            let mut interp = crate::interpreter().unwrap();
+            let err = interp.prepare(b"%{user").unwrap_err();
+            assert_eq!("ArgumentError", err.name().as_ref());
+        }
+    }
 }
@@ -0,0 +1,159 @@
+//! A GC-rooted registry of Ruby callbacks for host-driven event dispatch.
+//!
+//! A host embedding this interpreter can register a Ruby `Proc` against a
+//! named event with [`Artichoke::on_event`] and later invoke every listener
+//! registered for that name from Rust with [`Artichoke::emit`], without
+//! having to manage rooting the `Proc` against the GC itself -- plugin
+//! scripts that hook `"user_created"` or similar host-defined events are the
+//! motivating use case.
+
+use std::collections::HashMap;
+
+use crate::core::Value as _;
+use crate::exception::Exception;
+use crate::ffi::InterpreterExtractError;
+use crate::sys;
+use crate::value::Value;
+use crate::Artichoke;
+
+/// A handle identifying one listener registered with [`Artichoke::on_event`],
+/// for later removal with [`Artichoke::remove_listener`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct ListenerToken(u64);
+
+#[derive(Debug)]
+struct Listener {
+    token: ListenerToken,
+    callback: Value,
+}
+
+/// GC-rooted storage for callbacks registered with [`Artichoke::on_event`].
+///
+/// Lives on [`State`](crate::state::State) for the life of the interpreter.
+#[derive(Debug, Default)]
+pub struct EventRegistry {
+    next_token: u64,
+    listeners: HashMap<String, Vec<Listener>>,
+}
+
+impl EventRegistry {
+    pub(crate) fn new() -> Self {
+        Self::default()
+    }
+}
+
+/// One listener's failure when [`Artichoke::emit`] invoked it.
+#[derive(Debug)]
+pub struct ListenerError {
+    /// The listener that raised, for passing to
+    /// [`Artichoke::remove_listener`] if the host wants to drop a
+    /// misbehaving callback.
+    pub token: ListenerToken,
+    /// The exception the listener raised.
+    pub exception: Exception,
+}
+
+/// The result of [`Artichoke::emit`]: how many listeners ran successfully,
+/// and the failures of any that did not.
+///
+/// A failing listener does not stop the remaining listeners for the same
+/// event from running -- `emit` aggregates every failure instead of
+/// stopping at the first one, since one plugin's bug should not prevent
+/// other plugins from observing the same event.
+#[derive(Debug, Default)]
+pub struct EmitOutcome {
+    /// Number of listeners that returned without raising.
+    pub succeeded: usize,
+    /// Failures of listeners that raised, in registration order.
+    pub errors: Vec<ListenerError>,
+}
+
+impl Artichoke {
+    /// Register `callback` to run whenever `name` is [`emit`](Self::emit)ted.
+    ///
+    /// `callback` is rooted with [`sys::mrb_gc_register`] so it survives
+    /// being dropped from the Rust-side caller's stack and from the VM's own
+    /// GC arena; the root is released when the returned token is passed to
+    /// [`Artichoke::remove_listener`].
+    ///
+    /// # Errors
+    ///
+    /// If the interpreter has already been freed, an error is returned.
+    pub fn on_event(
+        &mut self,
+        name: &str,
+        callback: Value,
+    ) -> Result<ListenerToken, InterpreterExtractError> {
+        unsafe {
+            self.with_ffi_boundary(|mrb| sys::mrb_gc_register(mrb, callback.inner()))?;
+        }
+        let state = self.state.as_mut().ok_or(InterpreterExtractError)?;
+        let token = ListenerToken(state.events.next_token);
+        state.events.next_token += 1;
+        state
+            .events
+            .listeners
+            .entry(name.to_string())
+            .or_insert_with(Vec::new)
+            .push(Listener { token, callback });
+        Ok(token)
+    }
+
+    /// Unregister a listener previously returned by [`Artichoke::on_event`],
+    /// releasing its GC root.
+    ///
+    /// Returns `false` if `token` does not identify a currently registered
+    /// listener, for example because it was already removed.
+    pub fn remove_listener(&mut self, token: ListenerToken) -> bool {
+        let removed = match self.state.as_mut() {
+            Some(state) => {
+                let mut removed = None;
+                for listeners in state.events.listeners.values_mut() {
+                    let position = listeners.iter().position(|listener| listener.token == token);
+                    if let Some(index) = position {
+                        removed = Some(listeners.remove(index));
+                        break;
+                    }
+                }
+                removed
+            }
+            None => None,
+        };
+        if let Some(listener) = removed {
+            let _ = unsafe {
+                self.with_ffi_boundary(|mrb| sys::mrb_gc_unregister(mrb, listener.callback.inner()))
+            };
+            true
+        } else {
+            false
+        }
+    }
+
+    /// Invoke every listener registered for `name` with `payload`, in
+    /// registration order.
+    ///
+    /// Every listener runs even if an earlier one raises; see
+    /// [`EmitOutcome`] for how failures are aggregated.
+    pub fn emit(&mut self, name: &str, payload: Value) -> EmitOutcome {
+        let callbacks: Vec<(ListenerToken, Value)> = self
+            .state
+            .as_ref()
+            .and_then(|state| state.events.listeners.get(name))
+            .map(|listeners| {
+                listeners
+                    .iter()
+                    .map(|listener| (listener.token, listener.callback))
+                    .collect()
+            })
+            .unwrap_or_default();
+
+        let mut outcome = EmitOutcome::default();
+        for (token, callback) in callbacks {
+            match callback.funcall(self, "call", &[payload], None) {
+                Ok(_) => outcome.succeeded += 1,
+                Err(exception) => outcome.errors.push(ListenerError { token, exception }),
+            }
+        }
+        outcome
+    }
+}
@@ -1,10 +1,13 @@
 use bstr::BString;
 use std::borrow::Cow;
+use std::convert::TryFrom;
 use std::error;
 use std::fmt;
 use std::hint;
 
-use crate::core::{TryConvertMut, Value as _};
+use crate::core::{Convert, ConvertMut, Eval, TopSelf, TryConvertMut, Value as _};
+use crate::extn::core::exception::RuntimeError;
+use crate::state::native_exception;
 use crate::string;
 use crate::sys;
 use crate::value::Value;
@@ -46,6 +49,192 @@ impl From<Box<dyn RubyException>> for Exception {
     }
 }
 
+impl From<DynamicException> for Exception {
+    fn from(exc: DynamicException) -> Self {
+        Self(Box::new(exc))
+    }
+}
+
+impl Exception {
+    /// Construct an `Exception` whose Ruby class is resolved by constant
+    /// path (e.g. `"MyGem::Error"`) when it is raised, rather than being one
+    /// of the compiled-in types generated by `ruby_exception_impl!` in
+    /// [`extn::core::exception`](crate::extn::core::exception).
+    ///
+    /// This lets Rust-backed methods raise exception classes defined
+    /// entirely in Ruby -- for example a gem's own `MyGem::Error` -- without
+    /// giving that class a corresponding Rust struct. See
+    /// [`DynamicException`] for the fallback behavior when `class_path`
+    /// does not resolve to a usable exception class.
+    #[must_use]
+    pub fn from_class_path<T, M>(class_path: T, message: M) -> Self
+    where
+        T: Into<Cow<'static, str>>,
+        M: Into<Cow<'static, [u8]>>,
+    {
+        DynamicException::new(class_path, message).into()
+    }
+
+    /// Retrieve a [`Value`] handle to the original Ruby exception object.
+    ///
+    /// This is the same object [`exception::raise`](raise) would raise for
+    /// `self`, so it can be used to call `instance_variable_get` and recover
+    /// ivars set by a user-defined exception subclass, or passed to
+    /// [`Artichoke::reraise`] to raise it again unchanged.
+    ///
+    /// Returns `None` if the interpreter cannot produce a [`sys::mrb_value`]
+    /// for this exception; see [`RubyException::as_mrb_value`].
+    #[must_use]
+    pub fn as_value(&self, interp: &mut Artichoke) -> Option<Value> {
+        self.as_mrb_value(interp).map(Value::from)
+    }
+}
+
+impl Artichoke {
+    /// Convenience for [`Exception::from_class_path`], callable as
+    /// `interp.raise_class("MyGem::Error", message)`.
+    #[must_use]
+    pub fn raise_class<T, M>(&self, class_path: T, message: M) -> Exception
+    where
+        T: Into<Cow<'static, str>>,
+        M: Into<Cow<'static, [u8]>>,
+    {
+        let _ = self;
+        Exception::from_class_path(class_path, message)
+    }
+
+    /// Raise the Ruby object behind `exception` again, unchanged.
+    ///
+    /// This calls `Kernel#raise` on [`top_self`](TopSelf::top_self) with
+    /// [`exception.as_value`](Exception::as_value) -- the same mechanism a
+    /// Ruby-level `raise exc` uses -- so the re-raised exception keeps its
+    /// original class, message, backtrace, and any custom ivars. Unlike
+    /// [`exception::raise`](raise), this does not require a live [`Guard`]
+    /// and so can be called from ordinary host code, for example to
+    /// propagate an exception caught from one `eval` into another.
+    ///
+    /// Returns the newly caught [`Exception`] wrapping the same underlying
+    /// object, or `exception` itself unchanged if it could not be recovered
+    /// as a `Value`.
+    #[must_use]
+    pub fn reraise(&mut self, exception: Exception) -> Exception {
+        let value = match exception.as_value(self) {
+            Some(value) => value,
+            None => return exception,
+        };
+        let top_self = self.top_self();
+        match top_self.funcall(self, "raise", &[value], None) {
+            Ok(_) => exception,
+            Err(reraised) => reraised,
+        }
+    }
+
+    /// Run `body`, trapping a matching `throw` the way a Ruby `catch` block
+    /// would.
+    ///
+    /// `tag` is the same object a paired [`Artichoke::throw`] (or a Ruby
+    /// `throw`) is called with; tags are compared with Ruby `==`, exactly
+    /// like the `catch` method defined in `kernel.rb`. If `body` returns an
+    /// `Err` wrapping an `UncaughtThrowError` whose `tag` matches, this
+    /// returns `Ok` with the thrown value instead of propagating the
+    /// exception. Any other error -- including an `UncaughtThrowError` for a
+    /// different tag -- propagates unchanged.
+    ///
+    /// This lets a host callback invoked from inside `body` call
+    /// [`Artichoke::throw`] to unwind straight back here, without the
+    /// callback needing to fabricate and raise its own exception type, and
+    /// without disturbing a Ruby-level `catch` of the same tag further up
+    /// the call stack.
+    pub fn catch<F>(&mut self, tag: Value, body: F) -> Result<Value, Exception>
+    where
+        F: FnOnce(&mut Self) -> Result<Value, Exception>,
+    {
+        let exception = match body(self) {
+            Ok(value) => return Ok(value),
+            Err(exception) => exception,
+        };
+        let caught = match exception.as_value(self) {
+            Some(value) => value,
+            None => return Err(exception),
+        };
+        let uncaught_throw = match self.eval(b"UncaughtThrowError") {
+            Ok(class) => class,
+            Err(_) => return Err(exception),
+        };
+        let is_uncaught_throw = match caught.funcall(self, "is_a?", &[uncaught_throw], None) {
+            Ok(is_a) => is_a.try_into(self).unwrap_or(false),
+            Err(_) => false,
+        };
+        if !is_uncaught_throw {
+            return Err(exception);
+        }
+        let caught_tag = match caught.funcall(self, "tag", &[], None) {
+            Ok(caught_tag) => caught_tag,
+            Err(_) => return Err(exception),
+        };
+        let tags_match = match tag.funcall(self, "==", &[caught_tag], None) {
+            Ok(eql) => eql.try_into(self).unwrap_or(false),
+            Err(_) => false,
+        };
+        if !tags_match {
+            return Err(self.reraise(exception));
+        }
+        caught.funcall(self, "value", &[], None)
+    }
+
+    /// Throw `value` to the nearest enclosing [`Artichoke::catch`] (or Ruby
+    /// `catch`) of the same `tag`, raising if there is none.
+    ///
+    /// This calls `Kernel#throw` on [`top_self`](TopSelf::top_self), the
+    /// same pure-Ruby method a Ruby-level `throw tag, value` uses, so `tag`
+    /// is shared by identity with a paired `catch` regardless of whether
+    /// that `catch` was started from Ruby or from [`Artichoke::catch`].
+    ///
+    /// `throw` never returns normally -- like a Ruby `throw`, it always
+    /// performs a non-local exit -- so this returns the resulting
+    /// [`Exception`] for the caller to propagate, typically as the `Err` of
+    /// a trampoline.
+    #[must_use]
+    pub fn throw(&mut self, tag: Value, value: Option<Value>) -> Exception {
+        let value = value.unwrap_or_default();
+        let top_self = self.top_self();
+        match top_self.funcall(self, "throw", &[tag, value], None) {
+            Ok(_) => {
+                // `Kernel#throw` always raises `UncaughtThrowError`; reaching
+                // here would mean it was redefined to return normally, which
+                // this API has no way to represent.
+                self.raise_class("fatal", "throw returned without unwinding")
+            }
+            Err(exception) => exception,
+        }
+    }
+
+    /// Recover the Rust value behind a rescued Ruby exception, if it was
+    /// raised with [`exception::raise`](raise) and `T` matches the type
+    /// originally raised.
+    ///
+    /// `value` is the exception object as caught by a Ruby `rescue`, for
+    /// example the receiver of a trampoline backing a `rescue =>` clause.
+    ///
+    /// Returns `None` if `value` was not raised by `exception::raise` (for
+    /// example, it was constructed and raised directly in Ruby), or if `T`
+    /// does not match the type captured at raise time.
+    #[must_use]
+    pub fn downcast_native_exception<T>(&mut self, value: &Value) -> Option<T>
+    where
+        T: Clone + 'static,
+    {
+        let ivar = self.convert_mut(native_exception::IVAR_NAME);
+        let id = value
+            .funcall(self, "instance_variable_get", &[ivar], None)
+            .ok()?;
+        let id: i64 = id.try_into(self).ok()?;
+        let id = u64::try_from(id).ok()?;
+        let state = self.state.as_ref()?;
+        state.native_exceptions.downcast_ref::<T>(id).cloned()
+    }
+}
+
 /// Raise implementation for `RubyException` boxed trait objects.
 ///
 /// # Safety
@@ -62,16 +251,23 @@ where
     T: RubyException + fmt::Debug,
 {
     let exc = exception.as_mrb_value(&mut guard);
-    let mrb = guard.mrb.as_mut() as *mut _;
-    drop(guard);
     if let Some(exc) = exc {
-        // Any non-`Copy` objects that we haven't cleaned up at this point will
-        // leak, so drop everything.
-        drop(exception);
+        let mut value = Value::from(exc);
+        // Stash `exception` in the interpreter's native exception table and
+        // tag `value` with the slot, so a `rescue` of `value` can later
+        // recover `exception` with `Artichoke::downcast_native_exception`.
+        // This also satisfies the "drop everything" requirement below: the
+        // table, not this stack frame, now owns `exception`.
+        tag_native_exception(&mut guard, &mut value, exception);
+        let mrb = guard.mrb.as_mut() as *mut _;
+        let exc = value.inner();
+        drop(guard);
         // `mrb_exc_raise` will call longjmp which will unwind the stack.
         sys::mrb_exc_raise(mrb, exc);
     } else {
         error!("unable to raise {:?}", exception);
+        let mrb = guard.mrb.as_mut() as *mut _;
+        drop(guard);
         // Any non-`Copy` objects that we haven't cleaned up at this point will
         // leak, so drop everything.
         drop(exception);
@@ -86,6 +282,30 @@ where
     hint::unreachable_unchecked()
 }
 
+/// Store `exception` in the interpreter's
+/// [native exception table](native_exception::State) and tag `value` with
+/// the hidden ivar naming its slot.
+///
+/// Best-effort: if the interpreter's state is unavailable or tagging the
+/// ivar fails, `value` still raises correctly -- it just cannot be
+/// downcast back to a Rust value later.
+fn tag_native_exception<T>(guard: &mut Guard<'_>, value: &mut Value, exception: T)
+where
+    T: RubyException + fmt::Debug,
+{
+    let state = match guard.state.as_mut() {
+        Some(state) => state,
+        None => {
+            drop(exception);
+            return;
+        }
+    };
+    let id = state.native_exceptions.capture(Box::new(exception));
+    let ivar = guard.convert_mut(native_exception::IVAR_NAME);
+    let id = guard.convert(id as i64);
+    let _ = value.funcall(&mut *guard, "instance_variable_set", &[ivar, id], None);
+}
+
 /// Polymorphic exception type that corresponds to Ruby's `Exception`.
 ///
 /// All types that implement `RubyException` can be raised with
@@ -144,7 +364,22 @@ pub(crate) struct CaughtException {
 
 impl CaughtException {
     /// Construct a new `CaughtException`.
-    pub fn new(value: Value, name: String, message: Vec<u8>) -> Self {
+    ///
+    /// `value` is rooted with [`sys::mrb_gc_register`] so the original Ruby
+    /// exception object stays alive for the life of the returned
+    /// `CaughtException`, not just for the GC cycle in which it was caught.
+    /// Rooting is best-effort: if the interpreter's state is unavailable,
+    /// `value` is still wrapped and usable, it just is not protected from
+    /// being collected later.
+    ///
+    /// Like `Prepared`'s compiled template, this root is never
+    /// unregistered: [`Drop`] cannot be given the `&mut Artichoke` a call to
+    /// [`mrb_gc_unregister`](sys::mrb_gc_unregister) would need. A caught
+    /// exception that is reported or re-raised stays reachable through that
+    /// path anyway; one that is simply dropped leaves behind an extra root
+    /// until the interpreter closes.
+    pub fn new(interp: &mut Artichoke, value: Value, name: String, message: Vec<u8>) -> Self {
+        let _ = unsafe { interp.with_ffi_boundary(|mrb| sys::mrb_gc_register(mrb, value.inner())) };
         Self {
             value,
             name,
@@ -198,3 +433,90 @@ impl From<CaughtException> for Exception {
         Self(Box::new(exc))
     }
 }
+
+/// An exception whose Ruby class is resolved by constant path (e.g.
+/// `"MyGem::Error"`) when it is raised, rather than being one of the
+/// compiled-in types generated by `ruby_exception_impl!` in
+/// [`extn::core::exception`](crate::extn::core::exception).
+///
+/// Prefer [`Exception::from_class_path`] or [`Artichoke::raise_class`] to
+/// constructing this directly.
+#[derive(Debug, Clone)]
+pub struct DynamicException {
+    class_path: Cow<'static, str>,
+    message: Cow<'static, [u8]>,
+}
+
+impl DynamicException {
+    /// Construct a new `DynamicException` that resolves and raises as
+    /// `class_path`.
+    #[must_use]
+    pub fn new<T, M>(class_path: T, message: M) -> Self
+    where
+        T: Into<Cow<'static, str>>,
+        M: Into<Cow<'static, [u8]>>,
+    {
+        Self {
+            class_path: class_path.into(),
+            message: message.into(),
+        }
+    }
+
+    /// Resolve `class_path` against `Object` and instantiate it with
+    /// `message`.
+    ///
+    /// `Module#const_get` natively understands a `"A::B::C"` class path, so
+    /// this does not need to walk `class_path` a segment at a time.
+    ///
+    /// Returns `None` if `class_path` is undefined or the resolved constant
+    /// cannot be instantiated with a single message argument.
+    fn instantiate(&self, interp: &mut Artichoke) -> Option<Value> {
+        let object = interp.eval(b"Object").ok()?;
+        let class_path = interp.convert_mut(self.class_path.as_ref());
+        let class = object
+            .funcall(interp, "const_get", &[class_path], None)
+            .ok()?;
+        let message = interp.convert_mut(self.message());
+        class.funcall(interp, "new", &[message], None).ok()
+    }
+}
+
+impl fmt::Display for DynamicException {
+    fn fmt(&self, mut f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.name())?;
+        f.write_str(" (")?;
+        string::format_unicode_debug_into(&mut f, &self.message())
+            .map_err(string::WriteError::into_inner)?;
+        f.write_str(")")?;
+        Ok(())
+    }
+}
+
+impl error::Error for DynamicException {}
+
+impl RubyException for DynamicException {
+    fn message(&self) -> Cow<'_, [u8]> {
+        Cow::Borrowed(self.message.as_ref())
+    }
+
+    fn name(&self) -> Cow<'_, str> {
+        self.class_path.clone()
+    }
+
+    fn vm_backtrace(&self, interp: &mut Artichoke) -> Option<Vec<Vec<u8>>> {
+        let _ = interp;
+        None
+    }
+
+    fn as_mrb_value(&self, interp: &mut Artichoke) -> Option<sys::mrb_value> {
+        if let Some(value) = self.instantiate(interp) {
+            return Some(value.inner());
+        }
+        // `class_path` did not resolve to a usable exception class -- fall
+        // back to `RuntimeError`, the default `Exception` type for `raise`,
+        // rather than failing to raise at all.
+        let message = interp.convert_mut(self.message());
+        let value = interp.new_instance::<RuntimeError>(&[message]).ok().flatten()?;
+        Some(value.inner())
+    }
+}
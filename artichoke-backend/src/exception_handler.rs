@@ -1,9 +1,42 @@
-use crate::core::Value as _;
-use crate::exception::{CaughtException, Exception};
+use crate::core::{Io, Value as _};
+use crate::exception::{CaughtException, Exception, RubyException};
 use crate::gc::MrbGarbageCollection;
 use crate::value::Value;
 use crate::Artichoke;
 
+/// Function pointer for a hook invoked when an exception raised on the
+/// interpreter is about to be returned to the host as uncaught.
+///
+/// Handlers may inspect or render the exception and choose to suppress it by
+/// returning `None`, or return `Some` to let the caller continue handling the
+/// exception (for example, by using it to set a process exit code).
+///
+/// See [`Artichoke::set_uncaught_exception_handler`].
+pub type UncaughtExceptionHandler = fn(&mut Artichoke, Exception) -> Option<Exception>;
+
+/// Default [`UncaughtExceptionHandler`].
+///
+/// Prints a MRI-style `file:line: message (Class)` line to the interpreter's
+/// stderr stream and returns `None` to suppress further reporting of the
+/// exception.
+#[must_use]
+pub fn default_uncaught_exception_handler(
+    interp: &mut Artichoke,
+    exception: Exception,
+) -> Option<Exception> {
+    let frame = exception
+        .vm_backtrace(interp)
+        .and_then(|backtrace| backtrace.into_iter().next());
+    let location = frame.map_or_else(
+        || String::from("-"),
+        |frame| String::from_utf8_lossy(&frame).into_owned(),
+    );
+    let message = String::from_utf8_lossy(&exception.message()).into_owned();
+    let rendered = format!("{}: {} ({})\n", location, message, exception.name());
+    let _ = interp.write_stderr(rendered);
+    None
+}
+
 /// Transform a `Exception` Ruby `Value` into an [`Exception`].
 ///
 /// # Errors
@@ -44,7 +77,12 @@ pub fn last_error(interp: &mut Artichoke, exception: Value) -> Result<Exception,
     let message = exception.funcall(&mut arena, "message", &[], None)?;
     let message = message.try_into_mut::<&[u8]>(&mut arena)?;
 
-    let exception = CaughtException::new(exception, String::from(classname), message.to_vec());
+    let exception = CaughtException::new(
+        &mut arena,
+        exception,
+        String::from(classname),
+        message.to_vec(),
+    );
     debug!("Extracted exception from interpreter: {}", exception);
     Ok(Exception::from(exception))
 }
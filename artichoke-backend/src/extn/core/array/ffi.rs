@@ -12,11 +12,7 @@ unsafe extern "C" fn artichoke_ary_new(mrb: *mut sys::mrb_state) -> sys::mrb_val
     let mut interp = unwrap_interpreter!(mrb);
     let mut guard = Guard::new(&mut interp);
     let result = Array::default();
-    let result = Array::alloc_value(result, &mut guard);
-    match result {
-        Ok(value) => value.inner(),
-        Err(exception) => exception::raise(guard, exception),
-    }
+    ffi_catch_unwind!(guard, Array::alloc_value(result, &mut guard))
 }
 
 // MRB_API mrb_value mrb_ary_new_capa(mrb_state*, mrb_int);
@@ -29,11 +25,7 @@ unsafe extern "C" fn artichoke_ary_new_capa(
     let mut guard = Guard::new(&mut interp);
     let capacity = usize::try_from(capa).unwrap_or_default();
     let result = Array::with_capacity(capacity);
-    let result = Array::alloc_value(result, &mut guard);
-    match result {
-        Ok(value) => value.inner(),
-        Err(exception) => exception::raise(guard, exception),
-    }
+    ffi_catch_unwind!(guard, Array::alloc_value(result, &mut guard))
 }
 
 // MRB_API mrb_value mrb_ary_new_from_values(mrb_state *mrb, mrb_int size, const mrb_value *vals);
@@ -48,15 +40,14 @@ unsafe extern "C" fn artichoke_ary_new_from_values(
     let size = usize::try_from(size).unwrap_or_default();
     let values = slice::from_raw_parts(vals, size);
     let result = Array::from(values);
-    let result = Array::alloc_value(result, &mut guard);
-    match result {
-        Ok(value) => {
+    ffi_catch_unwind!(
+        guard,
+        Array::alloc_value(result, &mut guard).map(|value| {
             let basic = sys::mrb_sys_basic_ptr(value.inner());
             sys::mrb_write_barrier(mrb, basic);
-            value.inner()
-        }
-        Err(exception) => exception::raise(guard, exception),
-    }
+            value
+        })
+    )
 }
 
 // MRB_API mrb_value mrb_assoc_new(mrb_state *mrb, mrb_value car, mrb_value cdr)
@@ -69,15 +60,14 @@ unsafe extern "C" fn artichoke_ary_new_assoc(
     let mut interp = unwrap_interpreter!(mrb);
     let mut guard = Guard::new(&mut interp);
     let result = Array::assoc(one.into(), two.into());
-    let result = Array::alloc_value(result, &mut guard);
-    match result {
-        Ok(value) => {
+    ffi_catch_unwind!(
+        guard,
+        Array::alloc_value(result, &mut guard).map(|value| {
             let basic = sys::mrb_sys_basic_ptr(value.inner());
             sys::mrb_write_barrier(mrb, basic);
-            value.inner()
-        }
-        Err(exception) => exception::raise(guard, exception),
-    }
+            value
+        })
+    )
 }
 
 // MRB_API mrb_value mrb_ary_splat(mrb_state *mrb, mrb_value value);
@@ -89,17 +79,15 @@ unsafe extern "C" fn artichoke_ary_splat(
     let mut interp = unwrap_interpreter!(mrb);
     let mut guard = Guard::new(&mut interp);
     let mut value = Value::from(value);
-    let result = if Array::unbox_from_value(&mut value, &mut guard).is_ok() {
-        Ok(value)
-    } else {
-        let mut result = Array::with_capacity(1);
-        result.push(value);
-        Array::alloc_value(result, &mut guard)
-    };
-    match result {
-        Ok(value) => value.inner(),
-        Err(exception) => exception::raise(guard, exception),
-    }
+    ffi_catch_unwind!(guard, {
+        if Array::unbox_from_value(&mut value, &mut guard).is_ok() {
+            Ok(value)
+        } else {
+            let mut result = Array::with_capacity(1);
+            result.push(value);
+            Array::alloc_value(result, &mut guard)
+        }
+    })
 }
 
 // MRB_API void mrb_ary_concat(mrb_state *mrb, mrb_value self, mrb_value other);
@@ -113,26 +101,25 @@ unsafe extern "C" fn artichoke_ary_concat(
     let mut guard = Guard::new(&mut interp);
     let mut ary = Value::from(ary);
     let other = Value::from(other);
-    let result = if let Ok(mut array) = Array::unbox_from_value(&mut ary, &mut guard) {
-        let prior_gc_state = guard.disable_gc();
+    ffi_catch_unwind!(guard, {
+        let result = if let Ok(mut array) = Array::unbox_from_value(&mut ary, &mut guard) {
+            let prior_gc_state = guard.disable_gc();
 
-        let result = array.concat(&mut guard, other);
+            let result = array.concat(&mut guard, other);
 
-        if let GcState::Enabled = prior_gc_state {
-            guard.enable_gc();
-        }
-        result
-    } else {
-        Ok(())
-    };
-    match result {
-        Ok(()) => {
+            if let GcState::Enabled = prior_gc_state {
+                guard.enable_gc();
+            }
+            result
+        } else {
+            Ok(())
+        };
+        result.map(|()| {
             let basic = sys::mrb_sys_basic_ptr(ary.inner());
             sys::mrb_write_barrier(mrb, basic);
-            ary.inner()
-        }
-        Err(exception) => exception::raise(guard, exception),
-    }
+            ary
+        })
+    })
 }
 
 // MRB_API mrb_value mrb_ary_pop(mrb_state *mrb, mrb_value ary);
@@ -1,4 +1,5 @@
 use spinoso_array::SmallArray as SpinosoArray;
+use std::cmp::Ordering;
 use std::convert::TryFrom;
 use std::iter::FromIterator;
 use std::slice;
@@ -413,6 +414,135 @@ impl Array {
     pub fn shift_n(&mut self, count: usize) -> Self {
         Self(self.0.shift_n(count))
     }
+
+    /// Sort a snapshot of `Array` elements.
+    ///
+    /// Elements are ordered by `block`, if given, or by `<=>` otherwise.
+    /// `block` and `<=>` are called through the mruby VM, so either can
+    /// raise; if either does, sorting stops and `Err` is returned.
+    ///
+    /// This takes an owned `Vec` rather than `&mut self` because `block` and
+    /// `<=>` re-enter the VM and may reach the `Array` this snapshot was
+    /// taken from again (directly or transitively). Comparing against a live
+    /// `&mut Array` borrow -- rather than a disconnected snapshot -- risks a
+    /// second, aliasing unboxing of the same underlying object. Callers are
+    /// responsible for writing the result back to the boxed `Array` only
+    /// after re-unboxing it fresh.
+    pub fn sort(
+        interp: &mut Artichoke,
+        mut values: Vec<Value>,
+        block: Option<&Block>,
+    ) -> Result<Vec<Value>, Exception> {
+        let mut error = None;
+        values.sort_by(|&left, &right| {
+            if error.is_some() {
+                return Ordering::Equal;
+            }
+            match Self::compare(interp, left, right, block) {
+                Ok(ordering) => ordering,
+                Err(exception) => {
+                    error = Some(exception);
+                    Ordering::Equal
+                }
+            }
+        });
+        if let Some(error) = error {
+            return Err(error);
+        }
+        Ok(values)
+    }
+
+    /// Find the minimum element in a snapshot of `Array` elements.
+    ///
+    /// Elements are compared by `block`, if given, or by `<=>` otherwise, per
+    /// the same rules -- and for the same reasons -- as [`Array::sort`].
+    pub fn min(
+        interp: &mut Artichoke,
+        values: Vec<Value>,
+        block: Option<&Block>,
+    ) -> Result<Option<Value>, Exception> {
+        let mut iter = values.into_iter();
+        let mut min = match iter.next() {
+            Some(value) => value,
+            None => return Ok(None),
+        };
+        for value in iter {
+            if let Ordering::Less = Self::compare(interp, value, min, block)? {
+                min = value;
+            }
+        }
+        Ok(Some(min))
+    }
+
+    /// Find the maximum element in a snapshot of `Array` elements.
+    ///
+    /// See [`Array::min`].
+    pub fn max(
+        interp: &mut Artichoke,
+        values: Vec<Value>,
+        block: Option<&Block>,
+    ) -> Result<Option<Value>, Exception> {
+        let mut iter = values.into_iter();
+        let mut max = match iter.next() {
+            Some(value) => value,
+            None => return Ok(None),
+        };
+        for value in iter {
+            if let Ordering::Greater = Self::compare(interp, value, max, block)? {
+                max = value;
+            }
+        }
+        Ok(Some(max))
+    }
+
+    /// Find the minimum and maximum elements in a snapshot of `Array`
+    /// elements.
+    ///
+    /// See [`Array::min`].
+    pub fn minmax(
+        interp: &mut Artichoke,
+        values: Vec<Value>,
+        block: Option<&Block>,
+    ) -> Result<(Option<Value>, Option<Value>), Exception> {
+        let mut iter = values.into_iter();
+        let first = match iter.next() {
+            Some(value) => value,
+            None => return Ok((None, None)),
+        };
+        let (mut min, mut max) = (first, first);
+        for value in iter {
+            if let Ordering::Less = Self::compare(interp, value, min, block)? {
+                min = value;
+            }
+            if let Ordering::Greater = Self::compare(interp, value, max, block)? {
+                max = value;
+            }
+        }
+        Ok((Some(min), Some(max)))
+    }
+
+    fn compare(
+        interp: &mut Artichoke,
+        left: Value,
+        right: Value,
+        block: Option<&Block>,
+    ) -> Result<Ordering, Exception> {
+        let ordering = if let Some(block) = block {
+            block.yield_args(interp, &[left, right])?
+        } else {
+            left.funcall(interp, "<=>", &[right], None)?
+        };
+        if let Ok(ordering) = ordering.try_into::<Int>(interp) {
+            Ok(ordering.cmp(&0))
+        } else {
+            let mut message = String::from("comparison of ");
+            message.push_str(left.pretty_name(interp));
+            message.push_str(" with ");
+            message.push_str(right.pretty_name(interp));
+            message.push_str(" failed");
+            Err(ArgumentError::from(message).into())
+        }
+    }
 }
 
 #[cfg(test)]
@@ -24,9 +24,17 @@ pub fn init(interp: &mut Artichoke) -> InitializeResult<()> {
         )?
         .add_method("initialize_copy", ary_initialize_copy, sys::mrb_args_req(1))?
         .add_method("length", ary_len, sys::mrb_args_none())?
+        .add_method("max", ary_max, sys::mrb_args_none() | sys::mrb_args_block())?
+        .add_method("min", ary_min, sys::mrb_args_none() | sys::mrb_args_block())?
+        .add_method("minmax", ary_minmax, sys::mrb_args_none() | sys::mrb_args_block())?
         .add_method("pop", ary_pop, sys::mrb_args_none())?
         .add_method("reverse!", ary_reverse_bang, sys::mrb_args_none())?
         .add_method("shift", ary_shift, sys::mrb_args_opt(1))?
+        .add_method(
+            "sort!",
+            ary_sort_bang,
+            sys::mrb_args_none() | sys::mrb_args_block(),
+        )?
         .add_method("size", ary_len, sys::mrb_args_none())?
         .define()?;
     interp.def_class::<array::Array>(spec)?;
@@ -40,15 +48,56 @@ unsafe extern "C" fn ary_pop(mrb: *mut sys::mrb_state, ary: sys::mrb_value) -> s
     let mut interp = unwrap_interpreter!(mrb);
     let mut guard = Guard::new(&mut interp);
     let array = Value::from(ary);
-    let result = array::trampoline::pop(&mut guard, array);
-    match result {
-        Ok(value) => {
+    ffi_catch_unwind!(
+        guard,
+        array::trampoline::pop(&mut guard, array).map(|value| {
             let basic = sys::mrb_sys_basic_ptr(ary);
             sys::mrb_write_barrier(mrb, basic);
-            value.inner()
-        }
-        Err(exception) => exception::raise(guard, exception),
-    }
+            value
+        })
+    )
+}
+
+unsafe extern "C" fn ary_sort_bang(
+    mrb: *mut sys::mrb_state,
+    ary: sys::mrb_value,
+) -> sys::mrb_value {
+    let block = mrb_get_args!(mrb, &block);
+    let mut interp = unwrap_interpreter!(mrb);
+    let mut guard = Guard::new(&mut interp);
+    let array = Value::from(ary);
+    ffi_catch_unwind!(
+        guard,
+        array::trampoline::sort_bang(&mut guard, array, block).map(|value| {
+            let basic = sys::mrb_sys_basic_ptr(ary);
+            sys::mrb_write_barrier(mrb, basic);
+            value
+        })
+    )
+}
+
+unsafe extern "C" fn ary_min(mrb: *mut sys::mrb_state, ary: sys::mrb_value) -> sys::mrb_value {
+    let block = mrb_get_args!(mrb, &block);
+    let mut interp = unwrap_interpreter!(mrb);
+    let mut guard = Guard::new(&mut interp);
+    let array = Value::from(ary);
+    ffi_catch_unwind!(guard, array::trampoline::min(&mut guard, array, block))
+}
+
+unsafe extern "C" fn ary_max(mrb: *mut sys::mrb_state, ary: sys::mrb_value) -> sys::mrb_value {
+    let block = mrb_get_args!(mrb, &block);
+    let mut interp = unwrap_interpreter!(mrb);
+    let mut guard = Guard::new(&mut interp);
+    let array = Value::from(ary);
+    ffi_catch_unwind!(guard, array::trampoline::max(&mut guard, array, block))
+}
+
+unsafe extern "C" fn ary_minmax(mrb: *mut sys::mrb_state, ary: sys::mrb_value) -> sys::mrb_value {
+    let block = mrb_get_args!(mrb, &block);
+    let mut interp = unwrap_interpreter!(mrb);
+    let mut guard = Guard::new(&mut interp);
+    let array = Value::from(ary);
+    ffi_catch_unwind!(guard, array::trampoline::minmax(&mut guard, array, block))
 }
 
 unsafe extern "C" fn ary_len(mrb: *mut sys::mrb_state, ary: sys::mrb_value) -> sys::mrb_value {
@@ -56,20 +105,18 @@ unsafe extern "C" fn ary_len(mrb: *mut sys::mrb_state, ary: sys::mrb_value) -> s
     let mut interp = unwrap_interpreter!(mrb);
     let mut guard = Guard::new(&mut interp);
     let ary = Value::from(ary);
-    let result = array::trampoline::len(&mut guard, ary).and_then(|len| {
-        if let Ok(len) = sys::mrb_int::try_from(len) {
-            Ok(len)
-        } else {
-            Err(Fatal::from("Array length does not fit in mruby Integer max").into())
-        }
-    });
-    match result {
-        Ok(len) => {
-            let len = guard.convert(len);
-            len.inner()
-        }
-        Err(exception) => exception::raise(guard, exception),
-    }
+    ffi_catch_unwind!(
+        guard,
+        array::trampoline::len(&mut guard, ary)
+            .and_then(|len| {
+                if let Ok(len) = sys::mrb_int::try_from(len) {
+                    Ok(len)
+                } else {
+                    Err(Fatal::from("Array length does not fit in mruby Integer max").into())
+                }
+            })
+            .map(|len| guard.convert(len))
+    )
 }
 
 unsafe extern "C" fn ary_concat(mrb: *mut sys::mrb_state, ary: sys::mrb_value) -> sys::mrb_value {
@@ -78,15 +125,14 @@ unsafe extern "C" fn ary_concat(mrb: *mut sys::mrb_state, ary: sys::mrb_value) -
     let mut guard = Guard::new(&mut interp);
     let array = Value::from(ary);
     let other = other.map(Value::from);
-    let result = array::trampoline::concat(&mut guard, array, other);
-    match result {
-        Ok(value) => {
+    ffi_catch_unwind!(
+        guard,
+        array::trampoline::concat(&mut guard, array, other).map(|value| {
             let basic = sys::mrb_sys_basic_ptr(ary);
             sys::mrb_write_barrier(mrb, basic);
-            value.inner()
-        }
-        Err(exception) => exception::raise(guard, exception),
-    }
+            value
+        })
+    )
 }
 
 unsafe extern "C" fn ary_initialize(
@@ -99,15 +145,14 @@ unsafe extern "C" fn ary_initialize(
     let array = Value::from(ary);
     let first = first.map(Value::from);
     let second = second.map(Value::from);
-    let result = array::trampoline::initialize(&mut guard, array, first, second, block);
-    match result {
-        Ok(value) => {
+    ffi_catch_unwind!(
+        guard,
+        array::trampoline::initialize(&mut guard, array, first, second, block).map(|value| {
             let basic = sys::mrb_sys_basic_ptr(ary);
             sys::mrb_write_barrier(mrb, basic);
-            value.inner()
-        }
-        Err(exception) => exception::raise(guard, exception),
-    }
+            value
+        })
+    )
 }
 
 unsafe extern "C" fn ary_initialize_copy(
@@ -119,15 +164,14 @@ unsafe extern "C" fn ary_initialize_copy(
     let mut guard = Guard::new(&mut interp);
     let array = Value::from(ary);
     let other = Value::from(other);
-    let result = array::trampoline::initialize_copy(&mut guard, array, other);
-    match result {
-        Ok(value) => {
+    ffi_catch_unwind!(
+        guard,
+        array::trampoline::initialize_copy(&mut guard, array, other).map(|value| {
             let basic = sys::mrb_sys_basic_ptr(ary);
             sys::mrb_write_barrier(mrb, basic);
-            value.inner()
-        }
-        Err(exception) => exception::raise(guard, exception),
-    }
+            value
+        })
+    )
 }
 
 unsafe extern "C" fn ary_reverse_bang(
@@ -138,15 +182,14 @@ unsafe extern "C" fn ary_reverse_bang(
     let mut interp = unwrap_interpreter!(mrb);
     let mut guard = Guard::new(&mut interp);
     let array = Value::from(ary);
-    let result = array::trampoline::reverse_bang(&mut guard, array);
-    match result {
-        Ok(value) => {
+    ffi_catch_unwind!(
+        guard,
+        array::trampoline::reverse_bang(&mut guard, array).map(|value| {
             let basic = sys::mrb_sys_basic_ptr(ary);
             sys::mrb_write_barrier(mrb, basic);
-            value.inner()
-        }
-        Err(exception) => exception::raise(guard, exception),
-    }
+            value
+        })
+    )
 }
 
 unsafe extern "C" fn ary_element_reference(
@@ -159,11 +202,7 @@ unsafe extern "C" fn ary_element_reference(
     let elem = Value::from(elem);
     let len = len.map(Value::from);
     let array = Value::from(ary);
-    let result = array::trampoline::element_reference(&mut guard, array, elem, len);
-    match result {
-        Ok(value) => value.inner(),
-        Err(exception) => exception::raise(guard, exception),
-    }
+    ffi_catch_unwind!(guard, array::trampoline::element_reference(&mut guard, array, elem, len))
 }
 
 unsafe extern "C" fn ary_element_assignment(
@@ -177,15 +216,16 @@ unsafe extern "C" fn ary_element_assignment(
     let second = Value::from(second);
     let third = third.map(Value::from);
     let array = Value::from(ary);
-    let result = array::trampoline::element_assignment(&mut guard, array, first, second, third);
-    match result {
-        Ok(value) => {
-            let basic = sys::mrb_sys_basic_ptr(ary);
-            sys::mrb_write_barrier(mrb, basic);
-            value.inner()
-        }
-        Err(exception) => exception::raise(guard, exception),
-    }
+    ffi_catch_unwind!(
+        guard,
+        array::trampoline::element_assignment(&mut guard, array, first, second, third).map(
+            |value| {
+                let basic = sys::mrb_sys_basic_ptr(ary);
+                sys::mrb_write_barrier(mrb, basic);
+                value
+            }
+        )
+    )
 }
 
 unsafe extern "C" fn ary_shift(mrb: *mut sys::mrb_state, ary: sys::mrb_value) -> sys::mrb_value {
@@ -194,13 +234,12 @@ unsafe extern "C" fn ary_shift(mrb: *mut sys::mrb_state, ary: sys::mrb_value) ->
     let mut guard = Guard::new(&mut interp);
     let count = count.map(Value::from);
     let array = Value::from(ary);
-    let result = array::trampoline::shift(&mut guard, array, count);
-    match result {
-        Ok(value) => {
+    ffi_catch_unwind!(
+        guard,
+        array::trampoline::shift(&mut guard, array, count).map(|value| {
             let basic = sys::mrb_sys_basic_ptr(ary);
             sys::mrb_write_barrier(mrb, basic);
-            value.inner()
-        }
-        Err(exception) => exception::raise(guard, exception),
-    }
+            value
+        })
+    )
 }
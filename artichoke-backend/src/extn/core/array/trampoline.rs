@@ -92,6 +92,74 @@ pub fn reverse_bang(interp: &mut Artichoke, mut ary: Value) -> Result<Value, Exc
     Ok(ary)
 }
 
+pub fn sort_bang(
+    interp: &mut Artichoke,
+    mut ary: Value,
+    block: Option<Block>,
+) -> Result<Value, Exception> {
+    if ary.is_frozen(interp) {
+        return Err(FrozenError::from("can't modify frozen Array").into());
+    }
+    // Snapshot the elements and drop the unboxing guard before comparing --
+    // the block (or `<=>`) re-enters the VM and may reach `ary` again, which
+    // would otherwise unbox the same `Array` a second time while this guard
+    // is still live.
+    let elements = {
+        let array = unsafe { Array::unbox_from_value(&mut ary, interp)? };
+        array.iter().collect::<Vec<_>>()
+    };
+    let len = elements.len();
+    let sorted = Array::sort(interp, elements, block.as_ref())?;
+
+    let mut array = unsafe { Array::unbox_from_value(&mut ary, interp)? };
+    if array.len() != len {
+        return Err(RuntimeError::from("can't modify array during sort").into());
+    }
+    *array = sorted.into();
+    Ok(ary)
+}
+
+pub fn min(
+    interp: &mut Artichoke,
+    mut ary: Value,
+    block: Option<Block>,
+) -> Result<Value, Exception> {
+    let elements = {
+        let array = unsafe { Array::unbox_from_value(&mut ary, interp)? };
+        array.iter().collect::<Vec<_>>()
+    };
+    let min = Array::min(interp, elements, block.as_ref())?;
+    Ok(interp.convert(min))
+}
+
+pub fn max(
+    interp: &mut Artichoke,
+    mut ary: Value,
+    block: Option<Block>,
+) -> Result<Value, Exception> {
+    let elements = {
+        let array = unsafe { Array::unbox_from_value(&mut ary, interp)? };
+        array.iter().collect::<Vec<_>>()
+    };
+    let max = Array::max(interp, elements, block.as_ref())?;
+    Ok(interp.convert(max))
+}
+
+pub fn minmax(
+    interp: &mut Artichoke,
+    mut ary: Value,
+    block: Option<Block>,
+) -> Result<Value, Exception> {
+    let elements = {
+        let array = unsafe { Array::unbox_from_value(&mut ary, interp)? };
+        array.iter().collect::<Vec<_>>()
+    };
+    let (min, max) = Array::minmax(interp, elements, block.as_ref())?;
+    let min = interp.convert(min);
+    let max = interp.convert(max);
+    Array::alloc_value(vec![min, max].into(), interp)
+}
+
 pub fn len(interp: &mut Artichoke, mut ary: Value) -> Result<usize, Exception> {
     let array = unsafe { Array::unbox_from_value(&mut ary, interp)? };
     Ok(array.len())
@@ -0,0 +1,50 @@
+use crate::extn::prelude::*;
+
+mod trampoline;
+
+pub fn init(interp: &mut Artichoke, enclosing: &module::Spec) -> InitializeResult<()> {
+    if interp.is_module_defined::<Cache>() {
+        return Ok(());
+    }
+    let spec = module::Spec::new(interp, "Cache", Some(EnclosingRubyScope::module(enclosing)))?;
+    module::Builder::for_spec(interp, &spec)
+        .add_self_method(
+            "__capacity__",
+            artichoke_cache_self_capacity,
+            sys::mrb_args_none(),
+        )?
+        .add_self_method(
+            "__on_evict__",
+            artichoke_cache_self_on_evict,
+            sys::mrb_args_req(1),
+        )?
+        .define()?;
+    interp.def_module::<Cache>(spec)?;
+    let _ = interp.eval(&include_bytes!("cache.rb")[..])?;
+    trace!("Patched Artichoke::Cache onto interpreter");
+    Ok(())
+}
+
+#[derive(Debug)]
+pub struct Cache;
+
+unsafe extern "C" fn artichoke_cache_self_capacity(
+    mrb: *mut sys::mrb_state,
+    _slf: sys::mrb_value,
+) -> sys::mrb_value {
+    mrb_get_args!(mrb, none);
+    let mut interp = unwrap_interpreter!(mrb);
+    let mut guard = Guard::new(&mut interp);
+    ffi_catch_unwind!(guard, trampoline::capacity(&mut guard))
+}
+
+unsafe extern "C" fn artichoke_cache_self_on_evict(
+    mrb: *mut sys::mrb_state,
+    _slf: sys::mrb_value,
+) -> sys::mrb_value {
+    let key = mrb_get_args!(mrb, required = 1);
+    let mut interp = unwrap_interpreter!(mrb);
+    let mut guard = Guard::new(&mut interp);
+    let key = Value::from(key);
+    ffi_catch_unwind!(guard, trampoline::on_evict(&mut guard, key))
+}
@@ -0,0 +1,24 @@
+use crate::cache_hooks::CacheHooks;
+use crate::extn::prelude::*;
+
+pub fn capacity(interp: &mut Artichoke) -> Result<Value, Exception> {
+    let hooks = interp
+        .state
+        .as_ref()
+        .map_or_else(CacheHooks::default, |state| state.cache_hooks);
+    // `CacheHooks::capacity` is an embedder-supplied entry count, expected to
+    // be small enough to size a `Hash` with; it cannot plausibly exceed
+    // `Int::MAX`.
+    let capacity = (hooks.capacity)() as Int;
+    Ok(interp.convert(capacity))
+}
+
+pub fn on_evict(interp: &mut Artichoke, mut key: Value) -> Result<Value, Exception> {
+    let hooks = interp
+        .state
+        .as_ref()
+        .map_or_else(CacheHooks::default, |state| state.cache_hooks);
+    let key = key.implicitly_convert_to_string(interp)?;
+    (hooks.on_evict)(key);
+    Ok(Value::nil())
+}
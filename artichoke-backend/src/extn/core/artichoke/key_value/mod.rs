@@ -0,0 +1,255 @@
+use std::collections::BTreeMap;
+use std::convert::TryInto;
+use std::fmt;
+use std::path::{Path, PathBuf};
+
+use crate::checkpoint::CheckpointValue;
+use crate::extn::prelude::*;
+use crate::fs::Filesystem;
+
+pub mod mruby;
+pub mod trampoline;
+
+/// A durable, log-structured key/value store read and written through
+/// Artichoke's [virtual filesystem](crate::fs), backing
+/// `Artichoke::KeyValue` (Artichoke's `PStore` equivalent).
+///
+/// There is no `Marshal` in Artichoke and no general way to serialize an
+/// arbitrary Ruby object from outside the VM (see
+/// [`crate::checkpoint::CheckpointValue`], which `KeyValue` reuses as its
+/// on-disk value representation and so shares this limitation with
+/// `Checkpoint`): only `nil`, `true`/`false`, `Fixnum`, `Float`, and
+/// `String` round-trip through a store.
+///
+/// Writes are staged with [`KeyValue::set`]/[`KeyValue::delete`] and only
+/// take effect on [`KeyValue::commit`], which appends one log record per
+/// staged key to the file at `path` and applies them to the in-memory view
+/// -- this is the durability and all-or-nothing-per-transaction behavior
+/// `Artichoke::KeyValue#transaction` is built on. The log is append-only:
+/// a long-lived store accumulates a record per write rather than rewriting
+/// the whole file, trading disk space for not needing a compaction step to
+/// implement; nothing in this module currently reclaims that space.
+pub struct KeyValue {
+    path: PathBuf,
+    entries: BTreeMap<Vec<u8>, CheckpointValue>,
+    pending: BTreeMap<Vec<u8>, Option<CheckpointValue>>,
+}
+
+impl fmt::Debug for KeyValue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("KeyValue")
+            .field("path", &self.path)
+            .field("entries", &self.entries.len())
+            .field("pending", &self.pending.len())
+            .finish()
+    }
+}
+
+impl HeapAllocatedData for KeyValue {
+    const RUBY_TYPE: &'static str = "Artichoke::KeyValue";
+}
+
+impl KeyValue {
+    /// Open (or create) the log-structured store at `path` on the
+    /// interpreter's virtual filesystem.
+    ///
+    /// # Errors
+    ///
+    /// If the interpreter's state is inaccessible, reading `path` fails, or
+    /// `path` exists but its contents are not a log this module wrote, an
+    /// error is returned.
+    pub fn open(interp: &mut Artichoke, path: &Path) -> Result<Self, Exception> {
+        let state = interp.state.as_ref().ok_or(InterpreterExtractError)?;
+        let entries = if state.vfs.is_file(path) {
+            let log = state.vfs.read_file(path)?;
+            decode_log(&log)?
+        } else {
+            BTreeMap::new()
+        };
+        Ok(Self {
+            path: path.to_owned(),
+            entries,
+            pending: BTreeMap::new(),
+        })
+    }
+
+    /// Read the committed value for `key`.
+    ///
+    /// Does not see a write staged in the current transaction until it is
+    /// committed, matching `PStore`'s semantics of reading back what has
+    /// actually been persisted.
+    #[must_use]
+    pub fn get(&self, key: &[u8]) -> Option<&CheckpointValue> {
+        self.entries.get(key)
+    }
+
+    /// Enumerate the keys of every committed entry.
+    pub fn keys(&self) -> impl Iterator<Item = &Vec<u8>> {
+        self.entries.keys()
+    }
+
+    /// Stage a write for `key`, to take effect on the next
+    /// [`KeyValue::commit`].
+    pub fn set(&mut self, key: Vec<u8>, value: CheckpointValue) {
+        self.pending.insert(key, Some(value));
+    }
+
+    /// Stage a delete for `key`, to take effect on the next
+    /// [`KeyValue::commit`].
+    pub fn delete(&mut self, key: Vec<u8>) {
+        self.pending.insert(key, None);
+    }
+
+    /// Discard all writes staged since the last commit.
+    pub fn rollback(&mut self) {
+        self.pending.clear();
+    }
+
+    /// Append every staged write to the log as one batch, then apply it to
+    /// the in-memory view.
+    ///
+    /// A no-op if nothing is staged, so committing an empty transaction
+    /// does not touch the file on disk.
+    ///
+    /// # Errors
+    ///
+    /// If the interpreter's state is inaccessible, or reading or writing
+    /// `path` on the virtual filesystem fails, an error is returned.
+    pub fn commit(&mut self, interp: &mut Artichoke) -> Result<(), Exception> {
+        if self.pending.is_empty() {
+            return Ok(());
+        }
+        let state = interp.state.as_ref().ok_or(InterpreterExtractError)?;
+        let mut log = if state.vfs.is_file(&self.path) {
+            state.vfs.read_file(&self.path)?.into_owned()
+        } else {
+            Vec::new()
+        };
+        for (key, value) in &self.pending {
+            encode_record(&mut log, key, value.as_ref());
+        }
+        let state = interp.state.as_mut().ok_or(InterpreterExtractError)?;
+        state.vfs.write_file(&self.path, log.into())?;
+        for (key, value) in self.pending.drain() {
+            match value {
+                Some(value) => {
+                    self.entries.insert(key, value);
+                }
+                None => {
+                    self.entries.remove(&key);
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+fn corrupt_log_error() -> Exception {
+    Exception::from(IOError::from(
+        "corrupt Artichoke::KeyValue log: unrecognized record",
+    ))
+}
+
+/// Replay a log's records in order -- a later record for a key always wins
+/// over an earlier one -- to reconstruct the set of currently live entries.
+fn decode_log(log: &[u8]) -> Result<BTreeMap<Vec<u8>, CheckpointValue>, Exception> {
+    let mut entries = BTreeMap::new();
+    for line in log.split(|&byte| byte == b'\n') {
+        if line.is_empty() {
+            continue;
+        }
+        let mut fields = line.splitn(3, |&byte| byte == b'\t');
+        let (tag, key, value) = match (fields.next(), fields.next(), fields.next()) {
+            (Some(tag), Some(key), Some(value)) => (tag, key, value),
+            _ => return Err(corrupt_log_error()),
+        };
+        let key = decode_hex(key).ok_or_else(corrupt_log_error)?;
+        match tag {
+            b"S" => {
+                let value = decode_hex(value).ok_or_else(corrupt_log_error)?;
+                let value = decode_value(&value).ok_or_else(corrupt_log_error)?;
+                entries.insert(key, value);
+            }
+            b"D" => {
+                entries.remove(&key);
+            }
+            _ => return Err(corrupt_log_error()),
+        }
+    }
+    Ok(entries)
+}
+
+/// Append one record for `key`/`value` to `buf`: `S\t<hex key>\t<hex value>\n`
+/// for a write, `D\t<hex key>\t\n` for a delete.
+fn encode_record(buf: &mut Vec<u8>, key: &[u8], value: Option<&CheckpointValue>) {
+    buf.push(if value.is_some() { b'S' } else { b'D' });
+    buf.push(b'\t');
+    push_hex(buf, key);
+    buf.push(b'\t');
+    if let Some(value) = value {
+        push_hex(buf, &encode_value(value));
+    }
+    buf.push(b'\n');
+}
+
+/// Encode a [`CheckpointValue`] as a type tag byte followed by its payload.
+fn encode_value(value: &CheckpointValue) -> Vec<u8> {
+    match value {
+        CheckpointValue::Nil => vec![0],
+        CheckpointValue::Bool(value) => vec![1, u8::from(*value)],
+        CheckpointValue::Fixnum(value) => {
+            let mut buf = vec![2];
+            buf.extend_from_slice(&value.to_be_bytes());
+            buf
+        }
+        CheckpointValue::Float(value) => {
+            let mut buf = vec![3];
+            buf.extend_from_slice(&value.to_be_bytes());
+            buf
+        }
+        CheckpointValue::String(value) => {
+            let mut buf = vec![4];
+            buf.extend_from_slice(value);
+            buf
+        }
+    }
+}
+
+fn decode_value(bytes: &[u8]) -> Option<CheckpointValue> {
+    let (tag, payload) = bytes.split_first()?;
+    match *tag {
+        0 => Some(CheckpointValue::Nil),
+        1 => Some(CheckpointValue::Bool(*payload.first()? != 0)),
+        2 => Some(CheckpointValue::Fixnum(i64::from_be_bytes(
+            payload.try_into().ok()?,
+        ))),
+        3 => Some(CheckpointValue::Float(f64::from_be_bytes(
+            payload.try_into().ok()?,
+        ))),
+        4 => Some(CheckpointValue::String(payload.to_vec())),
+        _ => None,
+    }
+}
+
+fn push_hex(buf: &mut Vec<u8>, bytes: &[u8]) {
+    const DIGITS: &[u8; 16] = b"0123456789abcdef";
+    for byte in bytes {
+        let byte = *byte;
+        buf.push(DIGITS[usize::from(byte >> 4)]);
+        buf.push(DIGITS[usize::from(byte & 0x0f)]);
+    }
+}
+
+fn decode_hex(bytes: &[u8]) -> Option<Vec<u8>> {
+    if bytes.len() % 2 != 0 {
+        return None;
+    }
+    bytes
+        .chunks_exact(2)
+        .map(|pair| {
+            let hi = (pair[0] as char).to_digit(16)?;
+            let lo = (pair[1] as char).to_digit(16)?;
+            Some(((hi as u8) << 4) | (lo as u8))
+        })
+        .collect()
+}
@@ -0,0 +1,136 @@
+use crate::extn::core::artichoke::{self, key_value};
+use crate::extn::prelude::*;
+
+pub fn init(interp: &mut Artichoke) -> InitializeResult<()> {
+    if interp.is_class_defined::<key_value::KeyValue>() {
+        return Ok(());
+    }
+    let scope = interp
+        .module_spec::<artichoke::Artichoke>()?
+        .map(EnclosingRubyScope::module)
+        .ok_or_else(|| NotDefinedError::module("Artichoke"))?;
+    let spec = class::Spec::new(
+        "KeyValue",
+        Some(scope),
+        Some(def::box_unbox_free::<key_value::KeyValue>),
+    )?;
+    class::Builder::for_spec(interp, &spec)
+        .value_is_rust_object()
+        .add_method(
+            "initialize",
+            artichoke_key_value_initialize,
+            sys::mrb_args_req(1),
+        )?
+        .add_method("__get__", artichoke_key_value_get, sys::mrb_args_req(1))?
+        .add_method("__set__", artichoke_key_value_set, sys::mrb_args_req(2))?
+        .add_method(
+            "__delete__",
+            artichoke_key_value_delete,
+            sys::mrb_args_req(1),
+        )?
+        .add_method(
+            "__commit__",
+            artichoke_key_value_commit,
+            sys::mrb_args_none(),
+        )?
+        .add_method(
+            "__rollback__",
+            artichoke_key_value_rollback,
+            sys::mrb_args_none(),
+        )?
+        .add_method("__keys__", artichoke_key_value_keys, sys::mrb_args_none())?
+        .define()?;
+    interp.def_class::<key_value::KeyValue>(spec)?;
+    let _ = interp.eval(&include_bytes!("key_value.rb")[..])?;
+    trace!("Patched Artichoke::KeyValue onto interpreter");
+    Ok(())
+}
+
+#[no_mangle]
+unsafe extern "C" fn artichoke_key_value_initialize(
+    mrb: *mut sys::mrb_state,
+    slf: sys::mrb_value,
+) -> sys::mrb_value {
+    let path = mrb_get_args!(mrb, required = 1);
+    let mut interp = unwrap_interpreter!(mrb);
+    let mut guard = Guard::new(&mut interp);
+    let into = Value::from(slf);
+    let path = Value::from(path);
+    ffi_catch_unwind!(guard, key_value::trampoline::initialize(&mut guard, into, path))
+}
+
+#[no_mangle]
+unsafe extern "C" fn artichoke_key_value_get(
+    mrb: *mut sys::mrb_state,
+    slf: sys::mrb_value,
+) -> sys::mrb_value {
+    let key = mrb_get_args!(mrb, required = 1);
+    let mut interp = unwrap_interpreter!(mrb);
+    let mut guard = Guard::new(&mut interp);
+    let store = Value::from(slf);
+    let key = Value::from(key);
+    ffi_catch_unwind!(guard, key_value::trampoline::get(&mut guard, store, key))
+}
+
+#[no_mangle]
+unsafe extern "C" fn artichoke_key_value_set(
+    mrb: *mut sys::mrb_state,
+    slf: sys::mrb_value,
+) -> sys::mrb_value {
+    let (key, value) = mrb_get_args!(mrb, required = 2);
+    let mut interp = unwrap_interpreter!(mrb);
+    let mut guard = Guard::new(&mut interp);
+    let store = Value::from(slf);
+    let key = Value::from(key);
+    let value = Value::from(value);
+    ffi_catch_unwind!(guard, key_value::trampoline::set(&mut guard, store, key, value))
+}
+
+#[no_mangle]
+unsafe extern "C" fn artichoke_key_value_delete(
+    mrb: *mut sys::mrb_state,
+    slf: sys::mrb_value,
+) -> sys::mrb_value {
+    let key = mrb_get_args!(mrb, required = 1);
+    let mut interp = unwrap_interpreter!(mrb);
+    let mut guard = Guard::new(&mut interp);
+    let store = Value::from(slf);
+    let key = Value::from(key);
+    ffi_catch_unwind!(guard, key_value::trampoline::delete(&mut guard, store, key))
+}
+
+#[no_mangle]
+unsafe extern "C" fn artichoke_key_value_commit(
+    mrb: *mut sys::mrb_state,
+    slf: sys::mrb_value,
+) -> sys::mrb_value {
+    mrb_get_args!(mrb, none);
+    let mut interp = unwrap_interpreter!(mrb);
+    let mut guard = Guard::new(&mut interp);
+    let store = Value::from(slf);
+    ffi_catch_unwind!(guard, key_value::trampoline::commit(&mut guard, store))
+}
+
+#[no_mangle]
+unsafe extern "C" fn artichoke_key_value_rollback(
+    mrb: *mut sys::mrb_state,
+    slf: sys::mrb_value,
+) -> sys::mrb_value {
+    mrb_get_args!(mrb, none);
+    let mut interp = unwrap_interpreter!(mrb);
+    let mut guard = Guard::new(&mut interp);
+    let store = Value::from(slf);
+    ffi_catch_unwind!(guard, key_value::trampoline::rollback(&mut guard, store))
+}
+
+#[no_mangle]
+unsafe extern "C" fn artichoke_key_value_keys(
+    mrb: *mut sys::mrb_state,
+    slf: sys::mrb_value,
+) -> sys::mrb_value {
+    mrb_get_args!(mrb, none);
+    let mut interp = unwrap_interpreter!(mrb);
+    let mut guard = Guard::new(&mut interp);
+    let store = Value::from(slf);
+    ffi_catch_unwind!(guard, key_value::trampoline::keys(&mut guard, store))
+}
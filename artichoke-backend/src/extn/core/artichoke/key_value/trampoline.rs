@@ -0,0 +1,82 @@
+use std::path::Path;
+
+use crate::checkpoint::CheckpointValue;
+use crate::extn::core::artichoke::key_value::KeyValue;
+use crate::extn::prelude::*;
+use crate::ffi;
+
+pub fn initialize(
+    interp: &mut Artichoke,
+    into: Value,
+    mut path: Value,
+) -> Result<Value, Exception> {
+    let path = path.implicitly_convert_to_string(interp)?;
+    let path = ffi::bytes_to_os_str(path)?;
+    let store = KeyValue::open(interp, Path::new(path))?;
+    let result = KeyValue::box_into_value(store, into, interp)?;
+    Ok(result)
+}
+
+pub fn get(interp: &mut Artichoke, mut store: Value, mut key: Value) -> Result<Value, Exception> {
+    let store = unsafe { KeyValue::unbox_from_value(&mut store, interp) }?;
+    let key = key.implicitly_convert_to_string(interp)?;
+    let value = store.get(key).cloned();
+    drop(store);
+    let result = match value {
+        Some(value) => value.restore(interp),
+        None => Value::nil(),
+    };
+    Ok(result)
+}
+
+pub fn set(
+    interp: &mut Artichoke,
+    mut store: Value,
+    mut key: Value,
+    value: Value,
+) -> Result<Value, Exception> {
+    let pretty_name = value.pretty_name(interp);
+    let value = match CheckpointValue::capture(interp, value) {
+        Some(value) => value,
+        None => {
+            let mut message = String::from("can't store instance of ");
+            message.push_str(pretty_name);
+            message.push_str(" in Artichoke::KeyValue");
+            return Err(TypeError::from(message).into());
+        }
+    };
+    let mut guard = unsafe { KeyValue::unbox_from_value(&mut store, interp) }?;
+    let key = key.implicitly_convert_to_string(interp)?.to_vec();
+    guard.set(key, value);
+    Ok(Value::nil())
+}
+
+pub fn delete(
+    interp: &mut Artichoke,
+    mut store: Value,
+    mut key: Value,
+) -> Result<Value, Exception> {
+    let mut guard = unsafe { KeyValue::unbox_from_value(&mut store, interp) }?;
+    let key = key.implicitly_convert_to_string(interp)?.to_vec();
+    guard.delete(key);
+    Ok(Value::nil())
+}
+
+pub fn commit(interp: &mut Artichoke, mut store: Value) -> Result<Value, Exception> {
+    let mut guard = unsafe { KeyValue::unbox_from_value(&mut store, interp) }?;
+    guard.commit(interp)?;
+    Ok(Value::nil())
+}
+
+pub fn rollback(interp: &mut Artichoke, mut store: Value) -> Result<Value, Exception> {
+    let mut guard = unsafe { KeyValue::unbox_from_value(&mut store, interp) }?;
+    guard.rollback();
+    Ok(Value::nil())
+}
+
+pub fn keys(interp: &mut Artichoke, mut store: Value) -> Result<Value, Exception> {
+    let guard = unsafe { KeyValue::unbox_from_value(&mut store, interp) }?;
+    let keys: Vec<Vec<u8>> = guard.keys().cloned().collect();
+    drop(guard);
+    interp.try_convert_mut(keys)
+}
@@ -1,18 +1,117 @@
 use crate::extn::prelude::*;
 
+pub mod cache;
+pub mod key_value;
+pub mod remote_object;
+pub mod syntax;
+pub mod vm;
+
+#[cfg(feature = "interpreter-registry")]
+pub mod trampoline;
+
+#[cfg(feature = "artichoke-test")]
+pub mod test;
+
 pub fn init(interp: &mut crate::Artichoke) -> InitializeResult<()> {
     if interp.is_module_defined::<Artichoke>() {
         return Ok(());
     }
     let spec = module::Spec::new(interp, "Artichoke", None)?;
-    module::Builder::for_spec(interp, &spec).define()?;
+    #[cfg(feature = "interpreter-registry")]
+    let builder = module::Builder::for_spec(interp, &spec).add_self_method(
+        "current_id",
+        artichoke_self_current_id,
+        sys::mrb_args_none(),
+    )?;
+    #[cfg(not(feature = "interpreter-registry"))]
+    let builder = module::Builder::for_spec(interp, &spec);
+    builder.define()?;
+    cache::init(interp, &spec)?;
+    syntax::init(interp, &spec)?;
+    vm::init(interp, &spec)?;
+    #[cfg(feature = "artichoke-test")]
+    test::init(interp, &spec)?;
     interp.def_module::<Artichoke>(spec)?;
+    let _ = interp.eval(&include_bytes!("artichoke.rb")[..])?;
     trace!("Patched Artichoke onto interpreter");
     Ok(())
 }
 
+#[cfg(feature = "interpreter-registry")]
+unsafe extern "C" fn artichoke_self_current_id(
+    mrb: *mut sys::mrb_state,
+    _slf: sys::mrb_value,
+) -> sys::mrb_value {
+    mrb_get_args!(mrb, none);
+    let mut interp = unwrap_interpreter!(mrb);
+    let mut guard = Guard::new(&mut interp);
+    ffi_catch_unwind!(guard, trampoline::current_id(&mut guard))
+}
+
 #[derive(Debug)]
 pub struct Artichoke;
 
 #[derive(Debug)]
 pub struct Kernel;
+
+#[cfg(test)]
+mod tests {
+    use crate::test::prelude::*;
+
+    #[test]
+    fn build_info_reports_engine_family_constants() {
+        let mut interp = crate::interpreter().unwrap();
+        let result = interp
+            .eval(b"Artichoke.build_info.engine == RUBY_ENGINE")
+            .unwrap();
+        assert!(result.try_into::<bool>(&interp).unwrap());
+        let result = interp.eval(b"Artichoke.build_info.frozen?").unwrap();
+        assert!(result.try_into::<bool>(&interp).unwrap());
+    }
+
+    #[test]
+    fn channel_round_trips_copies_not_references() {
+        let mut interp = crate::interpreter().unwrap();
+        let result = interp
+            .eval(
+                br#"
+                channel = Artichoke::Channel.new
+                sent = +"hello"
+                channel.send(sent)
+                sent << ", world"
+
+                received = channel.receive
+                received == "hello" && sent == "hello, world"
+                "#,
+            )
+            .unwrap();
+        assert!(result.try_into::<bool>(&interp).unwrap());
+    }
+
+    #[test]
+    fn channel_receive_on_empty_channel_raises_thread_error() {
+        let mut interp = crate::interpreter().unwrap();
+        let result = interp.eval(b"Artichoke::Channel.new.receive");
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn channel_send_after_close_raises() {
+        let mut interp = crate::interpreter().unwrap();
+        let result = interp
+            .eval(
+                br#"
+                channel = Artichoke::Channel.new
+                channel.close
+                begin
+                  channel.send(1)
+                  false
+                rescue Artichoke::Channel::ClosedError
+                  true
+                end
+                "#,
+            )
+            .unwrap();
+        assert!(result.try_into::<bool>(&interp).unwrap());
+    }
+}
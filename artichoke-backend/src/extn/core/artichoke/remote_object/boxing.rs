@@ -0,0 +1,6 @@
+use crate::convert::HeapAllocatedData;
+use crate::extn::core::artichoke::remote_object::RemoteObject;
+
+impl HeapAllocatedData for RemoteObject {
+    const RUBY_TYPE: &'static str = "Artichoke::RemoteObject";
+}
@@ -0,0 +1,109 @@
+use std::any::Any;
+use std::collections::BTreeMap;
+use std::fmt;
+
+use crate::extn::prelude::*;
+
+pub mod boxing;
+pub mod mruby;
+pub mod trampoline;
+
+type Method =
+    Box<dyn Fn(&mut Artichoke, &mut (dyn Any + Send), &[Value]) -> Result<Value, Exception> + Send>;
+
+/// A Ruby-visible proxy around an embedder-owned Rust value that only
+/// forwards the specific method names the embedder registered with
+/// [`RemoteObject::allow`].
+///
+/// `RemoteObject` is `Artichoke::RemoteObject`'s backing Rust type. Like
+/// [`HostValue`](crate::extn::core::host_value::HostValue), it exists so a
+/// host embedding Artichoke can thread a Rust value through Ruby code
+/// without defining a full native class for it; unlike `HostValue`, which
+/// is opaque to Ruby, a `RemoteObject` forwards an explicit per-method
+/// allow-list of calls to Rust closures supplied by the embedder, the way a
+/// `DRbObject` forwards calls across a DRb connection to the real object on
+/// the other end. There is no reverse proxy for Rust calling into Ruby: a
+/// [`Value`] can already be called directly with
+/// [`Value::funcall`](crate::value::Value::funcall), so only the direction
+/// of Ruby calling into Rust needs a wrapper.
+///
+/// Instances are only ever created on the Rust side by building up a
+/// `RemoteObject` with [`RemoteObject::new`]/[`RemoteObject::allow`] and
+/// boxing it with [`BoxUnboxVmValue::alloc_value`]; there is no way to
+/// construct a meaningful `RemoteObject` from Ruby.
+pub struct RemoteObject {
+    data: Box<dyn Any + Send>,
+    methods: BTreeMap<String, Method>,
+}
+
+impl fmt::Debug for RemoteObject {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("RemoteObject")
+            .field("methods", &self.methods.keys().collect::<Vec<_>>())
+            .finish()
+    }
+}
+
+impl RemoteObject {
+    /// Box an arbitrary `Send` Rust value with no methods allow-listed yet.
+    ///
+    /// Chain calls to [`RemoteObject::allow`] to expose methods on it.
+    #[must_use]
+    pub fn new<T>(data: T) -> Self
+    where
+        T: Any + Send,
+    {
+        Self {
+            data: Box::new(data),
+            methods: BTreeMap::new(),
+        }
+    }
+
+    /// Allow Ruby to call `name` on this proxy, forwarding to `method`.
+    ///
+    /// `method` is only ever invoked with the `T` this `RemoteObject` was
+    /// constructed with; registering `name` against a `T` other than the one
+    /// passed to [`RemoteObject::new`] is a programming error on the
+    /// embedder's part that this has no way to catch at registration time.
+    #[must_use]
+    pub fn allow<T, F>(mut self, name: &str, method: F) -> Self
+    where
+        T: Any + Send,
+        F: Fn(&mut Artichoke, &mut T, &[Value]) -> Result<Value, Exception> + Send + 'static,
+    {
+        let method: Method = Box::new(move |interp, data, args| {
+            let data = data.downcast_mut::<T>().ok_or_else(|| {
+                Fatal::from("RemoteObject method registered against the wrong type")
+            })?;
+            method(interp, data, args)
+        });
+        self.methods.insert(name.to_string(), method);
+        self
+    }
+
+    /// Whether `name` is allow-listed on this proxy.
+    #[must_use]
+    pub fn is_allowed(&self, name: &str) -> bool {
+        self.methods.contains_key(name)
+    }
+
+    /// Forward a call to `name` with `args` to its registered method.
+    ///
+    /// # Errors
+    ///
+    /// If `name` is not allow-listed, or the registered method errors, an
+    /// error is returned.
+    pub fn invoke(
+        &mut self,
+        interp: &mut Artichoke,
+        name: &str,
+        args: &[Value],
+    ) -> Result<Value, Exception> {
+        let Self { data, methods } = self;
+        let method = methods.get(name).ok_or_else(|| {
+            let message = format!("undefined method `{}' for Artichoke::RemoteObject", name);
+            NoMethodError::from(message)
+        })?;
+        method(interp, data.as_mut(), args)
+    }
+}
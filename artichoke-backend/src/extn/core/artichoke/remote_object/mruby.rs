@@ -0,0 +1,62 @@
+use crate::extn::core::artichoke;
+use crate::extn::core::artichoke::remote_object::{self, trampoline};
+use crate::extn::prelude::*;
+
+pub fn init(interp: &mut Artichoke) -> InitializeResult<()> {
+    if interp.is_class_defined::<remote_object::RemoteObject>() {
+        return Ok(());
+    }
+    let scope = interp
+        .module_spec::<artichoke::Artichoke>()?
+        .map(EnclosingRubyScope::module)
+        .ok_or_else(|| NotDefinedError::module("Artichoke"))?;
+    let spec = class::Spec::new(
+        "RemoteObject",
+        Some(scope),
+        Some(def::box_unbox_free::<remote_object::RemoteObject>),
+    )?;
+    class::Builder::for_spec(interp, &spec)
+        .value_is_rust_object()
+        .add_method(
+            "__allowed__?",
+            artichoke_remote_object_allowed,
+            sys::mrb_args_req(1),
+        )?
+        .add_method(
+            "__invoke__",
+            artichoke_remote_object_invoke,
+            sys::mrb_args_req(2),
+        )?
+        .define()?;
+    interp.def_class::<remote_object::RemoteObject>(spec)?;
+    let _ = interp.eval(&include_bytes!("remote_object.rb")[..])?;
+    trace!("Patched Artichoke::RemoteObject onto interpreter");
+    Ok(())
+}
+
+#[no_mangle]
+unsafe extern "C" fn artichoke_remote_object_allowed(
+    mrb: *mut sys::mrb_state,
+    slf: sys::mrb_value,
+) -> sys::mrb_value {
+    let name = mrb_get_args!(mrb, required = 1);
+    let mut interp = unwrap_interpreter!(mrb);
+    let mut guard = Guard::new(&mut interp);
+    let slf = Value::from(slf);
+    let name = Value::from(name);
+    ffi_catch_unwind!(guard, trampoline::allowed(&mut guard, slf, name))
+}
+
+#[no_mangle]
+unsafe extern "C" fn artichoke_remote_object_invoke(
+    mrb: *mut sys::mrb_state,
+    slf: sys::mrb_value,
+) -> sys::mrb_value {
+    let (name, args) = mrb_get_args!(mrb, required = 2);
+    let mut interp = unwrap_interpreter!(mrb);
+    let mut guard = Guard::new(&mut interp);
+    let slf = Value::from(slf);
+    let name = Value::from(name);
+    let args = Value::from(args);
+    ffi_catch_unwind!(guard, trampoline::invoke(&mut guard, slf, name, args))
+}
@@ -0,0 +1,32 @@
+use std::str;
+
+use crate::extn::core::artichoke::remote_object::RemoteObject;
+use crate::extn::prelude::*;
+
+pub fn invoke(
+    interp: &mut Artichoke,
+    mut value: Value,
+    mut name: Value,
+    mut args: Value,
+) -> Result<Value, Exception> {
+    let name = name.implicitly_convert_to_string(interp)?.to_vec();
+    let name = str::from_utf8(&name).map_err(|_| ArgumentError::from("invalid byte sequence"))?;
+    let args = args.try_into_mut::<Vec<Value>>(interp)?;
+    let mut remote_object = unsafe { RemoteObject::unbox_from_value(&mut value, interp) }?;
+    remote_object.invoke(interp, name, &args)
+}
+
+pub fn allowed(
+    interp: &mut Artichoke,
+    mut value: Value,
+    mut name: Value,
+) -> Result<Value, Exception> {
+    let name = name.implicitly_convert_to_string(interp)?.to_vec();
+    let remote_object = unsafe { RemoteObject::unbox_from_value(&mut value, interp) }?;
+    let allowed = if let Ok(name) = str::from_utf8(&name) {
+        remote_object.is_allowed(name)
+    } else {
+        false
+    };
+    Ok(interp.convert(allowed))
+}
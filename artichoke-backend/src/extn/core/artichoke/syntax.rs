@@ -0,0 +1,396 @@
+use crate::extn::prelude::*;
+
+mod trampoline;
+
+pub fn init(interp: &mut Artichoke, enclosing: &module::Spec) -> InitializeResult<()> {
+    if interp.is_module_defined::<Syntax>() {
+        return Ok(());
+    }
+    let spec = module::Spec::new(interp, "Syntax", Some(EnclosingRubyScope::module(enclosing)))?;
+    module::Builder::for_spec(interp, &spec)
+        .add_self_method(
+            "__tokenize__",
+            artichoke_syntax_self_tokenize,
+            sys::mrb_args_req(1),
+        )?
+        .define()?;
+    interp.def_module::<Syntax>(spec)?;
+    let _ = interp.eval(&include_bytes!("syntax.rb")[..])?;
+    trace!("Patched Artichoke::Syntax onto interpreter");
+    Ok(())
+}
+
+#[derive(Debug)]
+pub struct Syntax;
+
+unsafe extern "C" fn artichoke_syntax_self_tokenize(
+    mrb: *mut sys::mrb_state,
+    _slf: sys::mrb_value,
+) -> sys::mrb_value {
+    let code = mrb_get_args!(mrb, required = 1);
+    let mut interp = unwrap_interpreter!(mrb);
+    let mut guard = Guard::new(&mut interp);
+    let code = Value::from(code);
+    ffi_catch_unwind!(guard, trampoline::tokenize(&mut guard, code))
+}
+
+/// The category a [`Token`] belongs to.
+///
+/// This is a best-effort classification for colorizing a REPL prompt or
+/// driving a simple linter, not a faithful reproduction of mruby's internal
+/// lexer states (mruby's lexer is generated by yacc and is not exposed as a
+/// public, tokenizable API, so there is nothing to bind to here).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum TokenKind {
+    Keyword,
+    Constant,
+    Identifier,
+    InstanceVariable,
+    ClassVariable,
+    GlobalVariable,
+    Integer,
+    Float,
+    String,
+    Symbol,
+    Comment,
+    Operator,
+    Newline,
+    Whitespace,
+    Unknown,
+}
+
+impl TokenKind {
+    #[must_use]
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Keyword => "kw",
+            Self::Constant => "const",
+            Self::Identifier => "ident",
+            Self::InstanceVariable => "ivar",
+            Self::ClassVariable => "cvar",
+            Self::GlobalVariable => "gvar",
+            Self::Integer => "int",
+            Self::Float => "float",
+            Self::String => "string",
+            Self::Symbol => "symbol",
+            Self::Comment => "comment",
+            Self::Operator => "op",
+            Self::Newline => "newline",
+            Self::Whitespace => "space",
+            Self::Unknown => "unknown",
+        }
+    }
+}
+
+/// One lexeme from [`tokenize`] with its byte offset span in the source.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Token {
+    pub kind: TokenKind,
+    pub start: usize,
+    pub end: usize,
+}
+
+const KEYWORDS: &[&[u8]] = &[
+    b"__ENCODING__",
+    b"__LINE__",
+    b"__FILE__",
+    b"BEGIN",
+    b"END",
+    b"alias",
+    b"and",
+    b"begin",
+    b"break",
+    b"case",
+    b"class",
+    b"def",
+    b"defined?",
+    b"do",
+    b"else",
+    b"elsif",
+    b"end",
+    b"ensure",
+    b"false",
+    b"for",
+    b"if",
+    b"in",
+    b"module",
+    b"next",
+    b"nil",
+    b"not",
+    b"or",
+    b"redo",
+    b"rescue",
+    b"retry",
+    b"return",
+    b"self",
+    b"super",
+    b"then",
+    b"true",
+    b"undef",
+    b"unless",
+    b"until",
+    b"when",
+    b"while",
+    b"yield",
+];
+
+// Longest operators first so a greedy scan prefers the longest match.
+const OPERATORS: &[&[u8]] = &[
+    b"<=>", b"===", b"**=", b"...", b"&&=", b"||=", b"<<=", b">>=", b"==", b"!=", b"<=", b">=",
+    b"&&", b"||", b"**", b"..", b"::", b"->", b"=>", b"+=", b"-=", b"*=", b"/=", b"%=", b"|=",
+    b"&=", b"^=", b"<<", b">>",
+];
+
+fn is_ident_continue(byte: u8) -> bool {
+    byte.is_ascii_alphanumeric() || byte == b'_'
+}
+
+fn is_keyword(lexeme: &[u8]) -> bool {
+    KEYWORDS.contains(&lexeme)
+}
+
+/// Scan `source` into a sequence of [`Token`]s.
+///
+/// This is a hand-rolled, best-effort scanner covering the common Ruby
+/// lexical categories (keywords, identifiers and constants, `@ivar`s,
+/// `@@cvar`s, `$gvar`s, numbers, single/double-quoted strings, `:symbol`s,
+/// comments, and operators). It does not resolve string interpolation,
+/// heredocs, `%`-literals, or `=begin`/`=end` block comments -- those spans
+/// come back as whatever plain tokens their contents happen to scan as.
+#[must_use]
+pub fn tokenize(source: &[u8]) -> Vec<Token> {
+    let mut tokens = Vec::new();
+    let len = source.len();
+    let mut pos = 0;
+    while pos < len {
+        let start = pos;
+        let byte = source[pos];
+        let kind = match byte {
+            b'\n' => {
+                pos += 1;
+                TokenKind::Newline
+            }
+            b' ' | b'\t' | b'\r' => {
+                while pos < len && matches!(source[pos], b' ' | b'\t' | b'\r') {
+                    pos += 1;
+                }
+                TokenKind::Whitespace
+            }
+            b'#' => {
+                while pos < len && source[pos] != b'\n' {
+                    pos += 1;
+                }
+                TokenKind::Comment
+            }
+            b'"' | b'\'' => {
+                pos += 1;
+                scan_quoted(source, &mut pos, byte);
+                TokenKind::String
+            }
+            b':' if matches!(source.get(pos + 1), Some(&c) if is_ident_continue(c) || c == b'"') =>
+            {
+                pos += 1;
+                if source[pos] == b'"' {
+                    pos += 1;
+                    scan_quoted(source, &mut pos, b'"');
+                } else {
+                    while pos < len && is_ident_continue(source[pos]) {
+                        pos += 1;
+                    }
+                    if matches!(source.get(pos), Some(b'?') | Some(b'!') | Some(b'=')) {
+                        pos += 1;
+                    }
+                }
+                TokenKind::Symbol
+            }
+            b'@' => {
+                pos += 1;
+                let is_class_var = source.get(pos) == Some(&b'@');
+                if is_class_var {
+                    pos += 1;
+                }
+                while pos < len && is_ident_continue(source[pos]) {
+                    pos += 1;
+                }
+                if is_class_var {
+                    TokenKind::ClassVariable
+                } else {
+                    TokenKind::InstanceVariable
+                }
+            }
+            b'$' => {
+                pos += 1;
+                while pos < len && is_ident_continue(source[pos]) {
+                    pos += 1;
+                }
+                TokenKind::GlobalVariable
+            }
+            b'0'..=b'9' => scan_number(source, &mut pos),
+            b'A'..=b'Z' | b'a'..=b'z' | b'_' => {
+                while pos < len && is_ident_continue(source[pos]) {
+                    pos += 1;
+                }
+                if matches!(source.get(pos), Some(b'?') | Some(b'!')) {
+                    pos += 1;
+                }
+                let lexeme = &source[start..pos];
+                if is_keyword(lexeme) {
+                    TokenKind::Keyword
+                } else if byte.is_ascii_uppercase() {
+                    TokenKind::Constant
+                } else {
+                    TokenKind::Identifier
+                }
+            }
+            _ => {
+                let matched = OPERATORS
+                    .iter()
+                    .find(|op| source[pos..].starts_with(*op))
+                    .map_or(1, |op| op.len());
+                pos += matched;
+                TokenKind::Operator
+            }
+        };
+        tokens.push(Token {
+            kind,
+            start,
+            end: pos,
+        });
+    }
+    tokens
+}
+
+/// Advance `pos` past a quoted literal (already past the opening `quote`),
+/// honoring backslash escapes, stopping after the matching close quote or at
+/// EOF on an unterminated literal.
+fn scan_quoted(source: &[u8], pos: &mut usize, quote: u8) {
+    let len = source.len();
+    while *pos < len {
+        if source[*pos] == b'\\' && *pos + 1 < len {
+            *pos += 2;
+            continue;
+        }
+        if source[*pos] == quote {
+            *pos += 1;
+            break;
+        }
+        *pos += 1;
+    }
+}
+
+fn scan_number(source: &[u8], pos: &mut usize) -> TokenKind {
+    let len = source.len();
+    let mut is_float = false;
+    while *pos < len && (source[*pos].is_ascii_digit() || source[*pos] == b'_') {
+        *pos += 1;
+    }
+    let next_is_digit = matches!(source.get(*pos + 1), Some(c) if c.is_ascii_digit());
+    if *pos < len && source[*pos] == b'.' && next_is_digit {
+        is_float = true;
+        *pos += 1;
+        while *pos < len && (source[*pos].is_ascii_digit() || source[*pos] == b'_') {
+            *pos += 1;
+        }
+    }
+    if matches!(source.get(*pos), Some(b'e') | Some(b'E')) {
+        let mut lookahead = *pos + 1;
+        if matches!(source.get(lookahead), Some(b'+') | Some(b'-')) {
+            lookahead += 1;
+        }
+        if matches!(source.get(lookahead), Some(c) if c.is_ascii_digit()) {
+            is_float = true;
+            *pos = lookahead;
+            while *pos < len && source[*pos].is_ascii_digit() {
+                *pos += 1;
+            }
+        }
+    }
+    if is_float {
+        TokenKind::Float
+    } else {
+        TokenKind::Integer
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{tokenize, TokenKind};
+
+    fn kinds(source: &str) -> Vec<TokenKind> {
+        tokenize(source.as_bytes())
+            .into_iter()
+            .map(|token| token.kind)
+            .collect()
+    }
+
+    #[test]
+    fn tokenizes_keywords_and_identifiers() {
+        assert_eq!(
+            kinds("def foo"),
+            vec![
+                TokenKind::Keyword,
+                TokenKind::Whitespace,
+                TokenKind::Identifier
+            ]
+        );
+    }
+
+    #[test]
+    fn tokenizes_constant() {
+        assert_eq!(kinds("Foo"), vec![TokenKind::Constant]);
+    }
+
+    #[test]
+    fn tokenizes_ivar_cvar_gvar() {
+        assert_eq!(kinds("@a"), vec![TokenKind::InstanceVariable]);
+        assert_eq!(kinds("@@a"), vec![TokenKind::ClassVariable]);
+        assert_eq!(kinds("$a"), vec![TokenKind::GlobalVariable]);
+    }
+
+    #[test]
+    fn tokenizes_numbers() {
+        assert_eq!(kinds("42"), vec![TokenKind::Integer]);
+        assert_eq!(kinds("4.2"), vec![TokenKind::Float]);
+        assert_eq!(kinds("1e10"), vec![TokenKind::Float]);
+    }
+
+    #[test]
+    fn tokenizes_strings_and_symbols() {
+        assert_eq!(kinds(r#""a\"b""#), vec![TokenKind::String]);
+        assert_eq!(kinds("'a'"), vec![TokenKind::String]);
+        assert_eq!(kinds(":foo"), vec![TokenKind::Symbol]);
+    }
+
+    #[test]
+    fn tokenizes_comment_to_end_of_line() {
+        let tokens = tokenize(b"1 # comment\n2");
+        assert_eq!(
+            tokens.iter().map(|token| token.kind).collect::<Vec<_>>(),
+            vec![
+                TokenKind::Integer,
+                TokenKind::Whitespace,
+                TokenKind::Comment,
+                TokenKind::Newline,
+                TokenKind::Integer,
+            ]
+        );
+    }
+
+    #[test]
+    fn tokenizes_multi_char_operators_greedily() {
+        assert_eq!(kinds("<=>"), vec![TokenKind::Operator]);
+        assert_eq!(kinds("<="), vec![TokenKind::Operator]);
+    }
+
+    #[test]
+    fn spans_cover_the_whole_source_with_no_gaps() {
+        let source = "def foo(a, b)\n  a <=> b\nend\n";
+        let tokens = tokenize(source.as_bytes());
+        let mut pos = 0;
+        for token in &tokens {
+            assert_eq!(token.start, pos);
+            pos = token.end;
+        }
+        assert_eq!(pos, source.len());
+    }
+}
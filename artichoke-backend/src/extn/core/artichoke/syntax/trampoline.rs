@@ -0,0 +1,21 @@
+use crate::extn::core::artichoke::syntax;
+use crate::extn::prelude::*;
+
+pub fn tokenize(interp: &mut Artichoke, mut code: Value) -> Result<Value, Exception> {
+    let code = code.implicitly_convert_to_string(interp)?;
+    let tokens = syntax::tokenize(code);
+    let mut rows = Vec::with_capacity(tokens.len());
+    for token in tokens {
+        let start = Int::try_from(token.start).map_err(|_| Fatal::from("token span overflow"))?;
+        let end = Int::try_from(token.end).map_err(|_| Fatal::from("token span overflow"))?;
+        let lexeme = &code[token.start..token.end];
+        let row = vec![
+            interp.convert_mut(token.kind.as_str()),
+            interp.convert_mut(lexeme),
+            interp.convert(start),
+            interp.convert(end),
+        ];
+        rows.push(interp.try_convert_mut(row)?);
+    }
+    interp.try_convert_mut(rows)
+}
@@ -0,0 +1,151 @@
+use crate::extn::prelude::*;
+
+mod trampoline;
+
+pub fn init(interp: &mut Artichoke, enclosing: &module::Spec) -> InitializeResult<()> {
+    if interp.is_module_defined::<Test>() {
+        return Ok(());
+    }
+    let spec = module::Spec::new(interp, "Test", Some(EnclosingRubyScope::module(enclosing)))?;
+    module::Builder::for_spec(interp, &spec)
+        .add_self_method(
+            "__freeze_time__",
+            artichoke_test_self_freeze_time,
+            sys::mrb_args_opt(1),
+        )?
+        .add_self_method(
+            "__unfreeze_time__",
+            artichoke_test_self_unfreeze_time,
+            sys::mrb_args_none(),
+        )?
+        .add_self_method(
+            "__travel_to__",
+            artichoke_test_self_travel_to,
+            sys::mrb_args_req(1),
+        )?
+        .add_self_method(
+            "__travel_back__",
+            artichoke_test_self_travel_back,
+            sys::mrb_args_none(),
+        )?
+        .define()?;
+    interp.def_module::<Test>(spec)?;
+    let _ = interp.eval(&include_bytes!("test.rb")[..])?;
+    trace!("Patched Artichoke::Test onto interpreter");
+    Ok(())
+}
+
+#[derive(Debug)]
+pub struct Test;
+
+unsafe extern "C" fn artichoke_test_self_freeze_time(
+    mrb: *mut sys::mrb_state,
+    _slf: sys::mrb_value,
+) -> sys::mrb_value {
+    let at = mrb_get_args!(mrb, optional = 1);
+    let mut interp = unwrap_interpreter!(mrb);
+    let mut guard = Guard::new(&mut interp);
+    let at = at.map(Value::from);
+    ffi_catch_unwind!(guard, trampoline::freeze_time(&mut guard, at))
+}
+
+unsafe extern "C" fn artichoke_test_self_unfreeze_time(
+    mrb: *mut sys::mrb_state,
+    _slf: sys::mrb_value,
+) -> sys::mrb_value {
+    mrb_get_args!(mrb, none);
+    let mut interp = unwrap_interpreter!(mrb);
+    let mut guard = Guard::new(&mut interp);
+    ffi_catch_unwind!(guard, trampoline::unfreeze_time(&mut guard))
+}
+
+unsafe extern "C" fn artichoke_test_self_travel_to(
+    mrb: *mut sys::mrb_state,
+    _slf: sys::mrb_value,
+) -> sys::mrb_value {
+    let at = mrb_get_args!(mrb, required = 1);
+    let mut interp = unwrap_interpreter!(mrb);
+    let mut guard = Guard::new(&mut interp);
+    let at = Value::from(at);
+    ffi_catch_unwind!(guard, trampoline::travel_to(&mut guard, at))
+}
+
+unsafe extern "C" fn artichoke_test_self_travel_back(
+    mrb: *mut sys::mrb_state,
+    _slf: sys::mrb_value,
+) -> sys::mrb_value {
+    mrb_get_args!(mrb, none);
+    let mut interp = unwrap_interpreter!(mrb);
+    let mut guard = Guard::new(&mut interp);
+    ffi_catch_unwind!(guard, trampoline::travel_back(&mut guard))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::test::prelude::*;
+
+    #[test]
+    fn freeze_time_pins_time_now_for_the_duration_of_the_block() {
+        let mut interp = crate::interpreter().unwrap();
+        let result = interp
+            .eval(
+                br#"
+                first = nil
+                second = nil
+                Artichoke::Test.freeze_time do
+                  first = Time.now
+                  second = Time.now
+                end
+                first == second
+                "#,
+            )
+            .unwrap();
+        assert!(result.try_into::<bool>(&interp).unwrap());
+    }
+
+    #[test]
+    fn time_now_is_unfrozen_after_the_freeze_time_block_returns() {
+        let mut interp = crate::interpreter().unwrap();
+        let result = interp
+            .eval(b"Artichoke::Test.freeze_time {}; Artichoke::Test.instance_variable_defined?(:@never_set)")
+            .unwrap();
+        assert!(!result.try_into::<bool>(&interp).unwrap());
+        let result = interp
+            .eval(br#"Artichoke::Test.freeze_time(Time.at(0)) { Time.now.to_i }"#)
+            .unwrap();
+        assert_eq!(result.try_into::<Int>(&interp).unwrap(), 0);
+        let result = interp.eval(b"Time.now.to_i").unwrap();
+        assert!(result.try_into::<Int>(&interp).unwrap() > 0);
+    }
+
+    #[test]
+    fn travel_to_shifts_the_apparent_time() {
+        let mut interp = crate::interpreter().unwrap();
+        let result = interp
+            .eval(br#"Artichoke::Test.travel_to(Time.at(0)) { Time.now.to_i }"#)
+            .unwrap();
+        assert_eq!(result.try_into::<Int>(&interp).unwrap(), 0);
+    }
+
+    #[test]
+    fn stub_env_restores_the_original_value_after_the_block() {
+        let mut interp = crate::interpreter().unwrap();
+        let result = interp
+            .eval(
+                br#"
+                ENV['ARTICHOKE_TEST_STUB_ENV'] = 'original'
+                seen = nil
+                Artichoke::Test.stub_env('ARTICHOKE_TEST_STUB_ENV' => 'stubbed') do
+                  seen = ENV['ARTICHOKE_TEST_STUB_ENV']
+                end
+                [seen, ENV['ARTICHOKE_TEST_STUB_ENV']]
+                "#,
+            )
+            .unwrap();
+        let result: Vec<Option<String>> = result.try_into_mut(&mut interp).unwrap();
+        assert_eq!(
+            result,
+            vec![Some("stubbed".to_string()), Some("original".to_string())]
+        );
+    }
+}
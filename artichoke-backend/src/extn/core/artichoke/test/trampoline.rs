@@ -0,0 +1,40 @@
+use chrono::{DateTime, Local, TimeZone};
+
+use crate::extn::core::time::Time;
+use crate::extn::prelude::*;
+use crate::state::clock::ClockOverride;
+
+pub fn freeze_time(interp: &mut Artichoke, at: Option<Value>) -> Result<Value, Exception> {
+    let anchor = if let Some(at) = at {
+        extract_datetime(interp, at)?
+    } else {
+        Local::now()
+    };
+    let state = interp.state.as_mut().ok_or(InterpreterExtractError)?;
+    state.clock_override = Some(ClockOverride::frozen_at(anchor));
+    Ok(Value::nil())
+}
+
+pub fn unfreeze_time(interp: &mut Artichoke) -> Result<Value, Exception> {
+    let state = interp.state.as_mut().ok_or(InterpreterExtractError)?;
+    state.clock_override = None;
+    Ok(Value::nil())
+}
+
+pub fn travel_to(interp: &mut Artichoke, at: Value) -> Result<Value, Exception> {
+    let anchor = extract_datetime(interp, at)?;
+    let state = interp.state.as_mut().ok_or(InterpreterExtractError)?;
+    state.clock_override = Some(ClockOverride::traveled_to(anchor));
+    Ok(Value::nil())
+}
+
+pub fn travel_back(interp: &mut Artichoke) -> Result<Value, Exception> {
+    unfreeze_time(interp)
+}
+
+fn extract_datetime(interp: &mut Artichoke, mut value: Value) -> Result<DateTime<Local>, Exception> {
+    let time = unsafe { Time::unbox_from_value(&mut value, interp)? };
+    let secs = time.inner().to_int();
+    let nanos = time.inner().nanosecond();
+    Ok(Local.timestamp(secs, nanos))
+}
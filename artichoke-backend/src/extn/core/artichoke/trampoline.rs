@@ -0,0 +1,6 @@
+use crate::extn::prelude::*;
+
+pub fn current_id(interp: &mut Artichoke) -> Result<Value, Exception> {
+    let id = interp.id();
+    interp.try_convert(id)
+}
@@ -0,0 +1,34 @@
+use crate::extn::prelude::*;
+
+mod trampoline;
+
+pub fn init(interp: &mut Artichoke, enclosing: &module::Spec) -> InitializeResult<()> {
+    if interp.is_module_defined::<VM>() {
+        return Ok(());
+    }
+    let spec = module::Spec::new(interp, "VM", Some(EnclosingRubyScope::module(enclosing)))?;
+    module::Builder::for_spec(interp, &spec)
+        .add_self_method(
+            "__profile__",
+            artichoke_vm_self_profile,
+            sys::mrb_args_none(),
+        )?
+        .define()?;
+    interp.def_module::<VM>(spec)?;
+    let _ = interp.eval(&include_bytes!("vm.rb")[..])?;
+    trace!("Patched Artichoke::VM onto interpreter");
+    Ok(())
+}
+
+#[derive(Debug)]
+pub struct VM;
+
+unsafe extern "C" fn artichoke_vm_self_profile(
+    mrb: *mut sys::mrb_state,
+    _slf: sys::mrb_value,
+) -> sys::mrb_value {
+    mrb_get_args!(mrb, none);
+    let mut interp = unwrap_interpreter!(mrb);
+    let mut guard = Guard::new(&mut interp);
+    ffi_catch_unwind!(guard, trampoline::profile(&mut guard))
+}
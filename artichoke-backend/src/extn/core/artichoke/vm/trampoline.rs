@@ -0,0 +1,10 @@
+use crate::extn::prelude::*;
+use crate::ffi::InterpreterExtractError;
+
+pub fn profile(interp: &mut Artichoke) -> Result<Value, Exception> {
+    let profile = {
+        let state = interp.state.as_ref().ok_or(InterpreterExtractError)?;
+        state.profile.as_str()
+    };
+    Ok(interp.convert_mut(profile))
+}
@@ -0,0 +1,29 @@
+use crate::extn::prelude::*;
+
+pub fn init(interp: &mut Artichoke) -> InitializeResult<()> {
+    if interp.is_class_defined::<Data>() {
+        return Ok(());
+    }
+    let spec = class::Spec::new("Data", None, None)?;
+    interp.def_class::<Data>(spec)?;
+    let _ = interp.eval(&include_bytes!("data.rb")[..])?;
+    trace!("Patched Data onto interpreter");
+    Ok(())
+}
+
+#[derive(Debug)]
+pub struct Data;
+
+#[cfg(test)]
+mod tests {
+    use crate::test::prelude::*;
+
+    #[test]
+    fn integration_test() {
+        let mut interp = crate::interpreter().unwrap();
+        let _ = interp.eval(&include_bytes!("data_test.rb")[..]).unwrap();
+        let result = interp.eval(b"spec");
+        let result = result.unwrap().try_into::<bool>(&interp).unwrap();
+        assert!(result);
+    }
+}
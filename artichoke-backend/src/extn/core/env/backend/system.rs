@@ -8,6 +8,14 @@ use crate::extn::core::env::backend::{EnvArgumentError, EnvType};
 use crate::extn::prelude::*;
 use crate::ffi;
 
+/// `ENV` backend proxying to the host process's real environment via
+/// `std::env`.
+///
+/// This also works unmodified on `wasm32-wasi`: WASI's libstd implements
+/// `std::env::var_os`/`set_var`/`vars_os` on top of the
+/// `wasi_snapshot_preview1` `environ_get`/`environ_sizes_get` syscalls, which
+/// every WASI host (e.g. wasmtime) provides without extra guest
+/// configuration, unlike preopened directories for filesystem access.
 #[derive(Default, Debug, Clone, Copy, Hash, PartialEq, Eq, PartialOrd, Ord)]
 pub struct System;
 
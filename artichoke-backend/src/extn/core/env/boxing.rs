@@ -1,6 +1,23 @@
-use crate::convert::HeapAllocatedData;
+use crate::convert::{CloneBehavior, HeapAllocatedData};
+use crate::exception::Exception;
 use crate::extn::core::env::Environ;
+use crate::extn::core::exception::TypeError;
+use crate::Artichoke;
 
 impl HeapAllocatedData for Environ {
     const RUBY_TYPE: &'static str = "Artichoke::Environ";
 }
+
+impl CloneBehavior for Environ {
+    /// `Environ`'s backend has no clone mechanism, and MRI's `ENV` is not
+    /// duplicable either -- `ENV.dup` raises a `TypeError` there too --  so
+    /// `dup`/`clone` reject `Environ` outright rather than pretending to
+    /// copy a handle to the process environment.
+    fn clone_for_dup(&self, interp: &mut Artichoke) -> Result<Self, Exception> {
+        let _ = interp;
+        let _ = self;
+        let mut message = String::from("can't dup ");
+        message.push_str(<Self as HeapAllocatedData>::RUBY_TYPE);
+        Err(TypeError::from(message).into())
+    }
+}
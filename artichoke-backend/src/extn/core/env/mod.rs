@@ -2,6 +2,7 @@ use std::borrow::Cow;
 use std::collections::HashMap;
 use std::fmt;
 
+use crate::env_security::EnvSecurityHooks;
 use crate::extn::prelude::*;
 
 pub mod backend;
@@ -13,12 +14,16 @@ use backend::memory::Memory;
 use backend::system::System;
 use backend::EnvType;
 
-pub struct Environ(Box<dyn EnvType>);
+pub struct Environ {
+    backend: Box<dyn EnvType>,
+    hooks: EnvSecurityHooks,
+}
 
 impl fmt::Debug for Environ {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         f.debug_struct("Environ")
-            .field("backend", self.0.as_debug())
+            .field("backend", self.backend.as_debug())
+            .field("hooks", &self.hooks)
             .finish()
     }
 }
@@ -26,34 +31,62 @@ impl fmt::Debug for Environ {
 impl Environ {
     #[must_use]
     pub fn new_system_env() -> Self {
-        Self(Box::new(System::new()))
+        Self {
+            backend: Box::new(System::new()),
+            hooks: EnvSecurityHooks::default(),
+        }
     }
 
     #[must_use]
     pub fn new_memory_env() -> Self {
-        Self(Box::new(Memory::new()))
+        Self {
+            backend: Box::new(Memory::new()),
+            hooks: EnvSecurityHooks::default(),
+        }
+    }
+
+    /// Install the given [`EnvSecurityHooks`] on this `Environ`, replacing
+    /// its default no-op hooks.
+    #[must_use]
+    pub fn with_hooks(mut self, hooks: EnvSecurityHooks) -> Self {
+        self.hooks = hooks;
+        self
     }
 
     #[must_use]
-    pub fn initialize() -> Self {
+    pub fn initialize(hooks: EnvSecurityHooks) -> Self {
         #[cfg(feature = "core-env-system")]
         let environ = Self::new_system_env();
         #[cfg(not(feature = "core-env-system"))]
         let environ = Self::new_memory_env();
 
-        environ
+        environ.with_hooks(hooks)
     }
 
     pub fn get(&self, name: &[u8]) -> Result<Option<Cow<'_, [u8]>>, Exception> {
-        self.0.get(name)
+        let value = self.backend.get(name)?;
+        (self.hooks.audit_read)(name);
+        Ok(value)
     }
 
     pub fn put(&mut self, name: &[u8], value: Option<&[u8]>) -> Result<(), Exception> {
-        self.0.put(name, value)?;
+        (self.hooks.validate_write)(name, value)?;
+        let old = self.backend.get(name)?.map(Cow::into_owned);
+        self.backend.put(name, value)?;
+        (self.hooks.on_change)(name, old.as_deref(), value);
         Ok(())
     }
 
     pub fn to_map(&self) -> Result<HashMap<Vec<u8>, Vec<u8>>, Exception> {
-        self.0.to_map()
+        let map = self.backend.to_map()?;
+        let redact = self.hooks.redact;
+        let map = map
+            .into_iter()
+            .map(|(name, value)| {
+                let value = redact(&name, value);
+                (name, value)
+            })
+            .collect();
+        Ok(map)
     }
 }
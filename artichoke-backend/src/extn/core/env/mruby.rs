@@ -24,6 +24,11 @@ pub fn init(interp: &mut Artichoke) -> InitializeResult<()> {
             sys::mrb_args_req(2),
         )?
         .add_method("initialize", artichoke_env_initialize, sys::mrb_args_none())?
+        .add_method(
+            "initialize_copy",
+            artichoke_env_initialize_copy,
+            sys::mrb_args_req(1),
+        )?
         .add_method("to_h", artichoke_env_to_h, sys::mrb_args_none())?
         .define()?;
     interp.def_class::<env::Environ>(spec)?;
@@ -42,11 +47,20 @@ unsafe extern "C" fn artichoke_env_initialize(
     let mut interp = unwrap_interpreter!(mrb);
     let mut guard = Guard::new(&mut interp);
     let slf = Value::from(slf);
-    let result = trampoline::initialize(&mut guard, slf);
-    match result {
-        Ok(value) => value.inner(),
-        Err(exception) => exception::raise(guard, exception),
-    }
+    ffi_catch_unwind!(guard, trampoline::initialize(&mut guard, slf))
+}
+
+#[no_mangle]
+unsafe extern "C" fn artichoke_env_initialize_copy(
+    mrb: *mut sys::mrb_state,
+    slf: sys::mrb_value,
+) -> sys::mrb_value {
+    let from = mrb_get_args!(mrb, required = 1);
+    let mut interp = unwrap_interpreter!(mrb);
+    let mut guard = Guard::new(&mut interp);
+    let into = Value::from(slf);
+    let from = Value::from(from);
+    ffi_catch_unwind!(guard, trampoline::initialize_copy(&mut guard, into, from))
 }
 
 #[no_mangle]
@@ -59,11 +73,7 @@ unsafe extern "C" fn artichoke_env_element_reference(
     let mut guard = Guard::new(&mut interp);
     let obj = Value::from(slf);
     let name = Value::from(name);
-    let result = trampoline::element_reference(&mut guard, obj, name);
-    match result {
-        Ok(value) => value.inner(),
-        Err(exception) => exception::raise(guard, exception),
-    }
+    ffi_catch_unwind!(guard, trampoline::element_reference(&mut guard, obj, name))
 }
 
 #[no_mangle]
@@ -77,11 +87,7 @@ unsafe extern "C" fn artichoke_env_element_assignment(
     let obj = Value::from(slf);
     let name = Value::from(name);
     let value = Value::from(value);
-    let result = trampoline::element_assignment(&mut guard, obj, name, value);
-    match result {
-        Ok(value) => value.inner(),
-        Err(exception) => exception::raise(guard, exception),
-    }
+    ffi_catch_unwind!(guard, trampoline::element_assignment(&mut guard, obj, name, value))
 }
 
 #[no_mangle]
@@ -93,9 +99,5 @@ unsafe extern "C" fn artichoke_env_to_h(
     let mut interp = unwrap_interpreter!(mrb);
     let mut guard = Guard::new(&mut interp);
     let obj = Value::from(slf);
-    let result = trampoline::to_h(&mut guard, obj);
-    match result {
-        Ok(value) => value.inner(),
-        Err(exception) => exception::raise(guard, exception),
-    }
+    ffi_catch_unwind!(guard, trampoline::to_h(&mut guard, obj))
 }
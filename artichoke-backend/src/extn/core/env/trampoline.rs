@@ -1,14 +1,28 @@
 use std::borrow::Cow;
 
+use crate::convert::{init_copy, StreamingHash};
+use crate::env_security::EnvSecurityHooks;
 use crate::extn::core::env::Environ;
 use crate::extn::prelude::*;
 
 pub fn initialize(interp: &mut Artichoke, into: Value) -> Result<Value, Exception> {
-    let environ = Environ::initialize();
+    let hooks = interp
+        .state
+        .as_ref()
+        .map_or_else(EnvSecurityHooks::default, |state| state.env_security_hooks);
+    let environ = Environ::initialize(hooks);
     let result = Environ::box_into_value(environ, into, interp)?;
     Ok(result)
 }
 
+pub fn initialize_copy(
+    interp: &mut Artichoke,
+    into: Value,
+    from: Value,
+) -> Result<Value, Exception> {
+    init_copy::<Environ>(interp, into, from)
+}
+
 pub fn element_reference(
     interp: &mut Artichoke,
     mut environ: Value,
@@ -16,8 +30,14 @@ pub fn element_reference(
 ) -> Result<Value, Exception> {
     let environ = unsafe { Environ::unbox_from_value(&mut environ, interp) }?;
     let name = name.implicitly_convert_to_string(interp)?;
-    let result = environ.get(name)?;
-    let mut result = interp.convert_mut(result.as_ref().map(Cow::as_ref));
+    let result = if let Some(replayed) = interp.replay_env_read(name)? {
+        replayed
+    } else {
+        let result = environ.get(name)?;
+        interp.record_env_read(name, result.as_deref());
+        result.map(Cow::into_owned)
+    };
+    let mut result = interp.convert_mut(result.as_ref().map(Vec::as_slice));
     result.freeze(interp)?;
     Ok(result)
 }
@@ -39,5 +59,5 @@ pub fn element_assignment(
 pub fn to_h(interp: &mut Artichoke, mut environ: Value) -> Result<Value, Exception> {
     let environ = unsafe { Environ::unbox_from_value(&mut environ, interp) }?;
     let result = environ.to_map()?;
-    Ok(interp.convert_mut(result))
+    interp.try_convert_mut(StreamingHash(result))
 }
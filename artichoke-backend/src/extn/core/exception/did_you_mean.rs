@@ -0,0 +1,140 @@
+//! `did_you_mean`-style suggestions for `NameError` and `NoMethodError`.
+//!
+//! Computes a "Did you mean?" suggestion for a missing constant or method
+//! name by finding the closest candidate by
+//! [Jaro-Winkler similarity](https://en.wikipedia.org/wiki/Jaro%E2%80%93Winkler_distance).
+
+/// Minimum similarity score, in the range `[0.0, 1.0]`, for a candidate to be
+/// suggested.
+const SIMILARITY_THRESHOLD: f64 = 0.7;
+
+/// Compute the Jaro similarity of two strings.
+fn jaro_similarity(a: &str, b: &str) -> f64 {
+    let a = a.as_bytes();
+    let b = b.as_bytes();
+    if a.is_empty() && b.is_empty() {
+        return 1.0;
+    }
+    if a.is_empty() || b.is_empty() {
+        return 0.0;
+    }
+
+    let match_distance = (a.len().max(b.len()) / 2).saturating_sub(1);
+
+    let mut a_matches = vec![false; a.len()];
+    let mut b_matches = vec![false; b.len()];
+    let mut matches = 0;
+
+    for (i, &a_byte) in a.iter().enumerate() {
+        let lo = i.saturating_sub(match_distance);
+        let hi = (i + match_distance + 1).min(b.len());
+        for (j, matched) in b_matches.iter_mut().enumerate().take(hi).skip(lo) {
+            if *matched || b[j] != a_byte {
+                continue;
+            }
+            a_matches[i] = true;
+            *matched = true;
+            matches += 1;
+            break;
+        }
+    }
+
+    if matches == 0 {
+        return 0.0;
+    }
+
+    let mut transpositions = 0;
+    let mut k = 0;
+    for (i, &matched) in a_matches.iter().enumerate() {
+        if !matched {
+            continue;
+        }
+        while !b_matches[k] {
+            k += 1;
+        }
+        if a[i] != b[k] {
+            transpositions += 1;
+        }
+        k += 1;
+    }
+
+    let matches = matches as f64;
+    (matches / a.len() as f64
+        + matches / b.len() as f64
+        + (matches - (transpositions / 2) as f64) / matches)
+        / 3.0
+}
+
+/// Compute the Jaro-Winkler similarity of two strings.
+///
+/// Jaro-Winkler boosts the Jaro similarity score for strings that share a
+/// common prefix, which is a good fit for misspelled Ruby method and
+/// constant names.
+#[must_use]
+pub fn jaro_winkler(a: &str, b: &str) -> f64 {
+    let jaro = jaro_similarity(a, b);
+    let prefix_len = a
+        .bytes()
+        .zip(b.bytes())
+        .take(4)
+        .take_while(|(x, y)| x == y)
+        .count() as f64;
+    jaro + prefix_len * 0.1 * (1.0 - jaro)
+}
+
+/// Find the candidate most similar to `name`, if any candidate is similar
+/// enough to be worth suggesting.
+#[must_use]
+pub fn suggest<'a, I>(name: &str, candidates: I) -> Option<&'a str>
+where
+    I: IntoIterator<Item = &'a str>,
+{
+    candidates
+        .into_iter()
+        .map(|candidate| (candidate, jaro_winkler(name, candidate)))
+        .filter(|&(candidate, score)| candidate != name && score >= SIMILARITY_THRESHOLD)
+        .max_by(|(_, left), (_, right)| left.partial_cmp(right).unwrap())
+        .map(|(candidate, _)| candidate)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{jaro_winkler, suggest};
+
+    #[test]
+    fn identical_strings_are_maximally_similar() {
+        assert!((jaro_winkler("puts", "puts") - 1.0).abs() < f64::EPSILON);
+    }
+
+    #[test]
+    fn completely_different_strings_are_dissimilar() {
+        assert!(jaro_winkler("abc", "xyz") < 0.5);
+    }
+
+    #[test]
+    fn suggest_finds_single_typo() {
+        let candidates = ["puts", "print", "p", "pp"];
+        assert_eq!(suggest("putz", candidates.iter().copied()), Some("puts"));
+    }
+
+    #[test]
+    fn suggest_prefers_shared_prefix() {
+        let candidates = ["StringError", "RangeError"];
+        assert_eq!(
+            suggest("RangeErorr", candidates.iter().copied()),
+            Some("RangeError")
+        );
+    }
+
+    #[test]
+    fn suggest_returns_none_when_no_close_candidate() {
+        let candidates = ["puts", "print"];
+        assert_eq!(suggest("zzzzzzzzzz", candidates.iter().copied()), None);
+    }
+
+    #[test]
+    fn suggest_excludes_exact_match() {
+        let candidates = ["puts"];
+        assert_eq!(suggest("puts", candidates.iter().copied()), None);
+    }
+}
@@ -43,8 +43,12 @@ use std::borrow::Cow;
 use std::error;
 use std::fmt;
 
+use crate::extn::core::artichoke::Artichoke as ArtichokeModule;
 use crate::extn::prelude::*;
 
+pub mod did_you_mean;
+mod trampoline;
+
 pub fn init(interp: &mut Artichoke) -> InitializeResult<()> {
     let exception_spec = class::Spec::new("Exception", None, None)?;
     class::Builder::for_spec(interp, &exception_spec).define()?;
@@ -253,9 +257,43 @@ pub fn init(interp: &mut Artichoke) -> InitializeResult<()> {
     let _ = interp.eval(&include_bytes!("exception.rb")[..])?;
     trace!("Patched Exception onto interpreter");
     trace!("Patched core exception hierarchy onto interpreter");
+
+    // `did_you_mean` suggestions are computed in Rust and exposed to the
+    // `NameError`/`NoMethodError`/`KeyError` message implementations in
+    // `exception.rb` via the `Artichoke::DidYouMean` module, following the
+    // same delegation pattern as `Artichoke::Kernel.Integer`.
+    let scope = interp
+        .module_spec::<ArtichokeModule>()?
+        .map(EnclosingRubyScope::module)
+        .ok_or_else(|| NotDefinedError::module("Artichoke"))?;
+    let spec = module::Spec::new(interp, "DidYouMean", Some(scope))?;
+    module::Builder::for_spec(interp, &spec)
+        .add_self_method(
+            "suggest",
+            artichoke_did_you_mean_suggest,
+            sys::mrb_args_req(2),
+        )?
+        .define()?;
+    interp.def_module::<DidYouMean>(spec)?;
+    trace!("Patched Artichoke::DidYouMean onto interpreter");
     Ok(())
 }
 
+unsafe extern "C" fn artichoke_did_you_mean_suggest(
+    mrb: *mut sys::mrb_state,
+    _slf: sys::mrb_value,
+) -> sys::mrb_value {
+    let (name, candidates) = mrb_get_args!(mrb, required = 2);
+    let mut interp = unwrap_interpreter!(mrb);
+    let mut guard = Guard::new(&mut interp);
+    let name = Value::from(name);
+    let candidates = Value::from(candidates);
+    ffi_catch_unwind!(guard, trampoline::suggest(&mut guard, name, candidates))
+}
+
+#[derive(Debug)]
+pub struct DidYouMean;
+
 macro_rules! ruby_exception_impl {
     ($exception:ident) => {
         #[derive(Default, Debug, Clone, Hash, PartialEq, Eq, PartialOrd, Ord)]
@@ -449,6 +487,33 @@ mod tests {
         }
     }
 
+    struct RaiseDynamic;
+
+    unsafe extern "C" fn run_raise_dynamic(
+        mrb: *mut sys::mrb_state,
+        _slf: sys::mrb_value,
+    ) -> sys::mrb_value {
+        let mut interp = unwrap_interpreter!(mrb);
+        let guard = Guard::new(&mut interp);
+        let exc = guard.raise_class("Waffle::Error", "syrup");
+        exception::raise(guard, exc)
+    }
+
+    impl File for RaiseDynamic {
+        type Artichoke = Artichoke;
+
+        type Error = Exception;
+
+        fn require(interp: &mut Artichoke) -> Result<(), Self::Error> {
+            let spec = class::Spec::new("RaiseDynamic", None, None).unwrap();
+            class::Builder::for_spec(interp, &spec)
+                .add_self_method("run", run_raise_dynamic, sys::mrb_args_none())?
+                .define()?;
+            interp.def_class::<Self>(spec)?;
+            Ok(())
+        }
+    }
+
     #[test]
     fn raise() {
         let mut interp = crate::interpreter().expect("init");
@@ -461,4 +526,90 @@ mod tests {
             err.vm_backtrace(&mut interp)
         );
     }
+
+    #[test]
+    fn exceptions_raised_separately_with_same_message_are_equal() {
+        let mut interp = crate::interpreter().expect("init");
+        let equal = interp
+            .eval(
+                br#"
+                e1, e2 = [1, 2].map do
+                  begin
+                    raise RuntimeError, 'boom'
+                  rescue => e
+                    e
+                  end
+                end
+                e1 == e2
+                "#,
+            )
+            .unwrap();
+        assert!(equal.try_into::<bool>(&interp).unwrap());
+    }
+
+    #[test]
+    fn exceptions_with_different_messages_are_not_equal() {
+        let mut interp = crate::interpreter().expect("init");
+        let equal = interp
+            .eval(
+                br#"
+                begin
+                  raise RuntimeError, 'boom'
+                rescue => e1
+                end
+                begin
+                  raise RuntimeError, 'splat'
+                rescue => e2
+                end
+                e1 == e2
+                "#,
+            )
+            .unwrap();
+        assert!(!equal.try_into::<bool>(&interp).unwrap());
+    }
+
+    #[test]
+    fn downcast_native_exception_recovers_original_rust_value() {
+        let mut interp = crate::interpreter().expect("init");
+        Run::require(&mut interp).unwrap();
+        let value = interp
+            .eval(b"begin; Run.run; rescue => e; e; end")
+            .unwrap();
+        let exc = interp
+            .downcast_native_exception::<RuntimeError>(&value)
+            .expect("rescued exception should round-trip to its Rust value");
+        assert_eq!(&b"something went wrong"[..], exc.message().as_ref());
+    }
+
+    #[test]
+    fn raise_class_resolves_user_defined_exception_at_raise_time() {
+        let mut interp = crate::interpreter().expect("init");
+        interp
+            .eval(b"module Waffle; class Error < StandardError; end; end")
+            .unwrap();
+        RaiseDynamic::require(&mut interp).unwrap();
+        let err = interp.eval(b"RaiseDynamic.run").unwrap_err();
+        assert_eq!("Waffle::Error", err.name().as_ref());
+        assert_eq!(&b"syrup"[..], err.message().as_ref());
+    }
+
+    #[test]
+    fn raise_class_falls_back_to_runtime_error_for_unresolvable_class_path() {
+        let mut interp = crate::interpreter().expect("init");
+        RaiseDynamic::require(&mut interp).unwrap();
+        let err = interp.eval(b"RaiseDynamic.run").unwrap_err();
+        assert_eq!("RuntimeError", err.name().as_ref());
+        assert_eq!(&b"syrup"[..], err.message().as_ref());
+    }
+
+    #[test]
+    fn downcast_native_exception_is_none_for_exception_raised_in_ruby() {
+        let mut interp = crate::interpreter().expect("init");
+        let value = interp
+            .eval(b"begin; raise RuntimeError, 'oops'; rescue => e; e; end")
+            .unwrap();
+        assert!(interp
+            .downcast_native_exception::<RuntimeError>(&value)
+            .is_none());
+    }
 }
@@ -0,0 +1,14 @@
+use crate::extn::core::exception::did_you_mean;
+use crate::extn::prelude::*;
+
+pub fn suggest(
+    interp: &mut Artichoke,
+    mut name: Value,
+    mut candidates: Value,
+) -> Result<Value, Exception> {
+    let name = name.implicitly_convert_to_string(interp)?;
+    let name = String::from_utf8_lossy(name).into_owned();
+    let candidates: Vec<String> = interp.try_convert_mut(&mut candidates)?;
+    let suggestion = did_you_mean::suggest(&name, candidates.iter().map(String::as_str));
+    Ok(interp.convert_mut(suggestion))
+}
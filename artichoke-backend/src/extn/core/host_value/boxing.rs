@@ -0,0 +1,6 @@
+use crate::convert::HeapAllocatedData;
+use crate::extn::core::host_value::HostValue;
+
+impl HeapAllocatedData for HostValue {
+    const RUBY_TYPE: &'static str = "Artichoke::HostValue";
+}
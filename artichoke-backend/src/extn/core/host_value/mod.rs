@@ -0,0 +1,63 @@
+use std::any::Any;
+use std::fmt;
+
+pub mod boxing;
+pub mod mruby;
+
+/// An opaque handle to an arbitrary, embedder-supplied Rust value.
+///
+/// `HostValue` is the `Artichoke::HostValue` Ruby class. It exists so a host
+/// embedding Artichoke can thread a Rust value it owns -- a database
+/// connection, a request object, a capability token -- through Ruby code as
+/// an ordinary object, without Ruby ever being able to inspect or mutate it,
+/// and get the same Rust value back, typed, from a Ruby callback.
+///
+/// A `HostValue` has no Ruby-visible methods or state of its own: it inherits
+/// `Object`'s default identity `==`/`equal?`, so two `HostValue`s are only
+/// ever equal if they are the same boxed instance. There is no way to
+/// construct a meaningful `HostValue` from Ruby; `Artichoke::HostValue.new`
+/// produces an uninitialized instance that behaves like `Object#allocate`
+/// does for any other Rust-backed class. Instances are only ever created on
+/// the Rust side with [`HostValue::alloc_value`].
+pub struct HostValue(Box<dyn Any + Send>);
+
+impl fmt::Debug for HostValue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("HostValue").field(&"..").finish()
+    }
+}
+
+impl HostValue {
+    /// Box an arbitrary `Send` Rust value for embedding in a `HostValue`.
+    #[must_use]
+    pub fn new<T>(data: T) -> Self
+    where
+        T: Any + Send,
+    {
+        Self(Box::new(data))
+    }
+
+    /// Attempt to downcast the boxed value to a concrete Rust type.
+    ///
+    /// Returns `None` if `T` is not the type this `HostValue` was
+    /// constructed with.
+    #[must_use]
+    pub fn downcast_ref<T>(&self) -> Option<&T>
+    where
+        T: Any + Send,
+    {
+        self.0.downcast_ref::<T>()
+    }
+
+    /// Attempt to mutably downcast the boxed value to a concrete Rust type.
+    ///
+    /// Returns `None` if `T` is not the type this `HostValue` was
+    /// constructed with.
+    #[must_use]
+    pub fn downcast_mut<T>(&mut self) -> Option<&mut T>
+    where
+        T: Any + Send,
+    {
+        self.0.downcast_mut::<T>()
+    }
+}
@@ -0,0 +1,24 @@
+use crate::extn::core::artichoke;
+use crate::extn::core::host_value;
+use crate::extn::prelude::*;
+
+pub fn init(interp: &mut Artichoke) -> InitializeResult<()> {
+    if interp.is_class_defined::<host_value::HostValue>() {
+        return Ok(());
+    }
+    let scope = interp
+        .module_spec::<artichoke::Artichoke>()?
+        .map(EnclosingRubyScope::module)
+        .ok_or_else(|| NotDefinedError::module("Artichoke"))?;
+    let spec = class::Spec::new(
+        "HostValue",
+        Some(scope),
+        Some(def::box_unbox_free::<host_value::HostValue>),
+    )?;
+    class::Builder::for_spec(interp, &spec)
+        .value_is_rust_object()
+        .define()?;
+    interp.def_class::<host_value::HostValue>(spec)?;
+    trace!("Patched Artichoke::HostValue onto interpreter");
+    Ok(())
+}
@@ -63,6 +63,14 @@ impl From<Int> for Outcome {
 }
 
 impl Integer {
+    /// The largest possible value of an `Integer`.
+    ///
+    /// MRI's `Integer` is arbitrary-precision, so it has no such constant.
+    /// This backend represents `Integer` with a fixed-width [`Int`], so
+    /// `Integer::MAX` is exposed as an Artichoke-specific extension for
+    /// numeric code that needs to detect this backend's overflow boundary.
+    pub const MAX: Int = Int::MAX;
+
     /// Constructs a new, default `Integer`.
     #[inline]
     #[must_use]
@@ -19,6 +19,10 @@ pub fn init(interp: &mut Artichoke) -> InitializeResult<()> {
         .define()?;
     interp.def_class::<Integer>(spec)?;
     let _ = interp.eval(&include_bytes!("integer.rb")[..])?;
+
+    let max = interp.convert(Integer::MAX);
+    interp.define_class_constant::<Integer>("MAX", max)?;
+
     trace!("Patched Integer onto interpreter");
     Ok(())
 }
@@ -32,11 +36,7 @@ unsafe extern "C" fn artichoke_integer_chr(
     let mut guard = Guard::new(&mut interp);
     let value = Value::from(slf);
     let encoding = encoding.map(Value::from);
-    let result = trampoline::chr(&mut guard, value, encoding);
-    match result {
-        Ok(value) => value.inner(),
-        Err(exception) => exception::raise(guard, exception),
-    }
+    ffi_catch_unwind!(guard, trampoline::chr(&mut guard, value, encoding))
 }
 
 unsafe extern "C" fn artichoke_integer_element_reference(
@@ -48,11 +48,7 @@ unsafe extern "C" fn artichoke_integer_element_reference(
     let mut guard = Guard::new(&mut interp);
     let value = Value::from(slf);
     let bit = Value::from(bit);
-    let result = trampoline::element_reference(&mut guard, value, bit);
-    match result {
-        Ok(value) => value.inner(),
-        Err(exception) => exception::raise(guard, exception),
-    }
+    ffi_catch_unwind!(guard, trampoline::element_reference(&mut guard, value, bit))
 }
 
 unsafe extern "C" fn artichoke_integer_div(
@@ -64,11 +60,7 @@ unsafe extern "C" fn artichoke_integer_div(
     let mut guard = Guard::new(&mut interp);
     let value = Value::from(slf);
     let denominator = Value::from(denominator);
-    let result = trampoline::div(&mut guard, value, denominator);
-    match result {
-        Ok(value) => value.inner(),
-        Err(exception) => exception::raise(guard, exception),
-    }
+    ffi_catch_unwind!(guard, trampoline::div(&mut guard, value, denominator))
 }
 
 unsafe extern "C" fn artichoke_integer_size(
@@ -78,9 +70,5 @@ unsafe extern "C" fn artichoke_integer_size(
     mrb_get_args!(mrb, none);
     let mut interp = unwrap_interpreter!(mrb);
     let guard = Guard::new(&mut interp);
-    let result = trampoline::size(&guard);
-    match result {
-        Ok(value) => value.inner(),
-        Err(exception) => exception::raise(guard, exception),
-    }
+    ffi_catch_unwind!(guard, trampoline::size(&guard))
 }
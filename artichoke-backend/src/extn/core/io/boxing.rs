@@ -0,0 +1,6 @@
+use crate::convert::HeapAllocatedData;
+use crate::extn::core::io::IO;
+
+impl HeapAllocatedData for IO {
+    const RUBY_TYPE: &'static str = "IO";
+}
@@ -0,0 +1,218 @@
+use std::cmp;
+use std::mem;
+
+use crate::extn::prelude::*;
+
+pub mod boxing;
+pub mod mruby;
+pub mod trampoline;
+
+/// Which of the interpreter's standard output streams an [`IO`] instance
+/// writes to, or the in-memory, read-only buffer an [`IO`] instance reads
+/// from.
+#[derive(Debug, Clone, Hash, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Device {
+    Stdout,
+    Stderr,
+    /// A fixed byte buffer with a read cursor, as used by [`IO::data`].
+    Data { bytes: Vec<u8>, position: usize },
+}
+
+/// A Ruby-facing `IO` object backed by one of the interpreter's standard
+/// output streams, or by an in-memory, read-only byte buffer.
+///
+/// Artichoke does not support opening arbitrary file descriptors as `IO`
+/// objects. `STDOUT`, `STDERR`, `$stdout`, `$stderr`, and `DATA` are the only
+/// `IO` instances the interpreter constructs. Writes made through `STDOUT`
+/// and `STDERR` are routed to the same [output strategy](crate::state::output)
+/// that backs `Kernel#print`/`#puts`; `DATA` is read-only and is never
+/// connected to an output strategy.
+#[derive(Debug, Clone, Hash, PartialEq, Eq, PartialOrd, Ord)]
+pub struct IO {
+    device: Device,
+    sync: bool,
+}
+
+impl IO {
+    #[must_use]
+    pub fn stdout() -> Self {
+        Self {
+            device: Device::Stdout,
+            // MRI's `$stdout`/`STDOUT` default to unbuffered ("sync") writes
+            // when the stream is a tty and buffered writes otherwise; this
+            // interpreter always buffers stdout, so default to unsynced.
+            sync: false,
+        }
+    }
+
+    #[must_use]
+    pub fn stderr() -> Self {
+        Self {
+            device: Device::Stderr,
+            // stderr is never buffered by the output strategy, so it is
+            // already effectively sync.
+            sync: true,
+        }
+    }
+
+    /// Construct a read-only `IO` over `bytes`, positioned at the start.
+    ///
+    /// This backs the `DATA` constant, which exposes the bytes trailing an
+    /// `__END__` marker in a script as a readable stream.
+    #[must_use]
+    pub fn data(bytes: Vec<u8>) -> Self {
+        Self {
+            device: Device::Data { bytes, position: 0 },
+            sync: true,
+        }
+    }
+
+    pub fn write(&self, interp: &mut Artichoke, bytes: &[u8]) -> Result<usize, Exception> {
+        match self.device {
+            Device::Stdout => interp.print(bytes)?,
+            Device::Stderr => interp.write_stderr(bytes)?,
+            Device::Data { .. } => {
+                return Err(Exception::from(IOError::from("not opened for writing")))
+            }
+        }
+        if self.sync {
+            let _ = interp.flush();
+        }
+        Ok(bytes.len())
+    }
+
+    pub fn print(&self, interp: &mut Artichoke, bytes: &[u8]) -> Result<(), Exception> {
+        let _ = self.write(interp, bytes)?;
+        Ok(())
+    }
+
+    pub fn puts(&self, interp: &mut Artichoke, bytes: &[u8]) -> Result<(), Exception> {
+        let _ = self.write(interp, bytes)?;
+        let _ = self.write(interp, b"\n")?;
+        Ok(())
+    }
+
+    #[must_use]
+    pub fn sync(&self) -> bool {
+        self.sync
+    }
+
+    pub fn set_sync(&mut self, sync: bool) {
+        self.sync = sync;
+    }
+
+    /// Read up to `length` bytes, or, if `length` is `None`, all remaining
+    /// bytes, advancing the read cursor by the number of bytes returned.
+    ///
+    /// Returns `None` at end of stream if `length` was given; returns an
+    /// empty buffer at end of stream if `length` was not given, matching
+    /// MRI's `IO#read`. Always returns `None` for a non-[`Device::Data`]
+    /// device, since writable streams do not support reading back.
+    #[must_use]
+    pub fn read(&mut self, length: Option<usize>) -> Option<Vec<u8>> {
+        let (bytes, position) = match &mut self.device {
+            Device::Data { bytes, position } => (bytes, position),
+            Device::Stdout | Device::Stderr => return None,
+        };
+        if *position >= bytes.len() && length.is_some() {
+            return None;
+        }
+        let end = length.map_or(bytes.len(), |length| cmp::min(bytes.len(), *position + length));
+        let chunk = bytes[*position..end].to_vec();
+        *position = end;
+        Some(chunk)
+    }
+
+    /// Read and return the next line, including its trailing `"\n"` if
+    /// present, advancing the read cursor past it.
+    ///
+    /// Returns `None` at end of stream, or for a non-[`Device::Data`]
+    /// device.
+    #[must_use]
+    pub fn gets(&mut self) -> Option<Vec<u8>> {
+        let (bytes, position) = match &mut self.device {
+            Device::Data { bytes, position } => (bytes, position),
+            Device::Stdout | Device::Stderr => return None,
+        };
+        if *position >= bytes.len() {
+            return None;
+        }
+        let start = *position;
+        let end = match bytes[start..].iter().position(|&byte| byte == b'\n') {
+            Some(offset) => start + offset + 1,
+            None => bytes.len(),
+        };
+        *position = end;
+        Some(bytes[start..end].to_vec())
+    }
+
+    /// Reset the read cursor to the beginning of the stream.
+    ///
+    /// A no-op for a non-[`Device::Data`] device.
+    pub fn rewind(&mut self) {
+        if let Device::Data { position, .. } = &mut self.device {
+            *position = 0;
+        }
+    }
+
+    /// The POSIX file descriptor number conventionally associated with this
+    /// stream (`1` for stdout, `2` for stderr), regardless of the
+    /// interpreter's configured output strategy.
+    ///
+    /// A [`Device::Data`] stream is not backed by a real file descriptor, so
+    /// this returns `-1`, matching the convention `libc` functions use for
+    /// "no such descriptor".
+    #[must_use]
+    pub fn fileno(&self) -> Int {
+        match self.device {
+            Device::Stdout => 1,
+            Device::Stderr => 2,
+            Device::Data { .. } => -1,
+        }
+    }
+
+    /// Whether the process's real file descriptor for this stream is
+    /// attached to a terminal.
+    #[must_use]
+    pub fn is_tty(&self) -> bool {
+        #[allow(clippy::cast_possible_truncation)]
+        let fd = self.fileno() as libc::c_int;
+        unsafe { libc::isatty(fd) != 0 }
+    }
+
+    /// The terminal size, in rows and columns, this stream is attached to.
+    ///
+    /// Consults the interpreter's [`TerminalHooks`](crate::terminal_hooks::TerminalHooks)
+    /// first: if the embedder's `columns` hook reports a width, that width is
+    /// used (paired with this stream's real row count, if it has one, or a
+    /// conventional default of 24 rows if it does not). Otherwise falls back
+    /// to asking the real file descriptor, which returns `None` if it is not
+    /// attached to a terminal.
+    #[must_use]
+    pub fn winsize(&self, interp: &Artichoke) -> Option<(u16, u16)> {
+        let hooks = interp.state.as_ref().map(|state| state.terminal_hooks);
+        if let Some(hooks) = hooks {
+            if let Some(columns) = (hooks.columns)() {
+                let rows = self.real_winsize().map_or(24, |(rows, _)| rows);
+                return Some((rows, columns));
+            }
+        }
+        self.real_winsize()
+    }
+
+    /// The real file descriptor's terminal size, in rows and columns, or
+    /// `None` if it is not attached to a terminal.
+    fn real_winsize(&self) -> Option<(u16, u16)> {
+        if !self.is_tty() {
+            return None;
+        }
+        #[allow(clippy::cast_possible_truncation)]
+        let fd = self.fileno() as libc::c_int;
+        let mut winsize: libc::winsize = unsafe { mem::zeroed() };
+        let ret = unsafe { libc::ioctl(fd, libc::TIOCGWINSZ, &mut winsize) };
+        if ret != 0 {
+            return None;
+        }
+        Some((winsize.ws_row, winsize.ws_col))
+    }
+}
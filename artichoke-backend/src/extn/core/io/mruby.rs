@@ -0,0 +1,173 @@
+use crate::extn::core::io::{trampoline, IO};
+use crate::extn::prelude::*;
+
+pub fn init(interp: &mut Artichoke) -> InitializeResult<()> {
+    if interp.is_class_defined::<IO>() {
+        return Ok(());
+    }
+    let spec = class::Spec::new("IO", None, Some(def::box_unbox_free::<IO>))?;
+    class::Builder::for_spec(interp, &spec)
+        .value_is_rust_object()
+        .add_method("write", artichoke_io_write, sys::mrb_args_rest())?
+        .add_method("print", artichoke_io_print, sys::mrb_args_rest())?
+        .add_method("puts", artichoke_io_puts, sys::mrb_args_rest())?
+        .add_method("read", artichoke_io_read, sys::mrb_args_opt(1))?
+        .add_method("gets", artichoke_io_gets, sys::mrb_args_none())?
+        .add_method("rewind", artichoke_io_rewind, sys::mrb_args_none())?
+        .add_method("sync", artichoke_io_sync, sys::mrb_args_none())?
+        .add_method("sync=", artichoke_io_set_sync, sys::mrb_args_req(1))?
+        .add_method("fileno", artichoke_io_fileno, sys::mrb_args_none())?
+        .add_method("tty?", artichoke_io_is_tty, sys::mrb_args_none())?
+        .add_method("isatty", artichoke_io_is_tty, sys::mrb_args_none())?
+        .add_method("winsize", artichoke_io_winsize, sys::mrb_args_none())?
+        .define()?;
+    interp.def_class::<IO>(spec)?;
+
+    let stdout = IO::alloc_value(IO::stdout(), interp)?;
+    interp.define_global_constant("STDOUT", stdout.clone())?;
+    interp.set_global_variable(&b"$stdout"[..], &stdout)?;
+
+    let stderr = IO::alloc_value(IO::stderr(), interp)?;
+    interp.define_global_constant("STDERR", stderr.clone())?;
+    interp.set_global_variable(&b"$stderr"[..], &stderr)?;
+
+    trace!("Patched IO onto interpreter");
+    Ok(())
+}
+
+#[no_mangle]
+unsafe extern "C" fn artichoke_io_write(
+    mrb: *mut sys::mrb_state,
+    slf: sys::mrb_value,
+) -> sys::mrb_value {
+    let args = mrb_get_args!(mrb, *args);
+    let mut interp = unwrap_interpreter!(mrb);
+    let mut guard = Guard::new(&mut interp);
+    let io = Value::from(slf);
+    let args = args.iter().copied().map(Value::from);
+    ffi_catch_unwind!(guard, trampoline::write(&mut guard, io, args))
+}
+
+#[no_mangle]
+unsafe extern "C" fn artichoke_io_print(
+    mrb: *mut sys::mrb_state,
+    slf: sys::mrb_value,
+) -> sys::mrb_value {
+    let args = mrb_get_args!(mrb, *args);
+    let mut interp = unwrap_interpreter!(mrb);
+    let mut guard = Guard::new(&mut interp);
+    let io = Value::from(slf);
+    let args = args.iter().copied().map(Value::from);
+    ffi_catch_unwind!(guard, trampoline::print(&mut guard, io, args))
+}
+
+#[no_mangle]
+unsafe extern "C" fn artichoke_io_puts(
+    mrb: *mut sys::mrb_state,
+    slf: sys::mrb_value,
+) -> sys::mrb_value {
+    let args = mrb_get_args!(mrb, *args);
+    let mut interp = unwrap_interpreter!(mrb);
+    let mut guard = Guard::new(&mut interp);
+    let io = Value::from(slf);
+    let args = args.iter().copied().map(Value::from);
+    ffi_catch_unwind!(guard, trampoline::puts(&mut guard, io, args))
+}
+
+#[no_mangle]
+unsafe extern "C" fn artichoke_io_read(
+    mrb: *mut sys::mrb_state,
+    slf: sys::mrb_value,
+) -> sys::mrb_value {
+    let length = mrb_get_args!(mrb, optional = 1);
+    let mut interp = unwrap_interpreter!(mrb);
+    let mut guard = Guard::new(&mut interp);
+    let length = length.map(Value::from);
+    let io = Value::from(slf);
+    ffi_catch_unwind!(guard, trampoline::read(&mut guard, io, length))
+}
+
+#[no_mangle]
+unsafe extern "C" fn artichoke_io_gets(
+    mrb: *mut sys::mrb_state,
+    slf: sys::mrb_value,
+) -> sys::mrb_value {
+    mrb_get_args!(mrb, none);
+    let mut interp = unwrap_interpreter!(mrb);
+    let mut guard = Guard::new(&mut interp);
+    let io = Value::from(slf);
+    ffi_catch_unwind!(guard, trampoline::gets(&mut guard, io))
+}
+
+#[no_mangle]
+unsafe extern "C" fn artichoke_io_rewind(
+    mrb: *mut sys::mrb_state,
+    slf: sys::mrb_value,
+) -> sys::mrb_value {
+    mrb_get_args!(mrb, none);
+    let mut interp = unwrap_interpreter!(mrb);
+    let mut guard = Guard::new(&mut interp);
+    let io = Value::from(slf);
+    ffi_catch_unwind!(guard, trampoline::rewind(&mut guard, io))
+}
+
+#[no_mangle]
+unsafe extern "C" fn artichoke_io_sync(
+    mrb: *mut sys::mrb_state,
+    slf: sys::mrb_value,
+) -> sys::mrb_value {
+    mrb_get_args!(mrb, none);
+    let mut interp = unwrap_interpreter!(mrb);
+    let mut guard = Guard::new(&mut interp);
+    let io = Value::from(slf);
+    ffi_catch_unwind!(guard, trampoline::sync(&mut guard, io))
+}
+
+#[no_mangle]
+unsafe extern "C" fn artichoke_io_set_sync(
+    mrb: *mut sys::mrb_state,
+    slf: sys::mrb_value,
+) -> sys::mrb_value {
+    let sync = mrb_get_args!(mrb, required = 1);
+    let mut interp = unwrap_interpreter!(mrb);
+    let mut guard = Guard::new(&mut interp);
+    let io = Value::from(slf);
+    let sync = Value::from(sync);
+    ffi_catch_unwind!(guard, trampoline::set_sync(&mut guard, io, sync))
+}
+
+#[no_mangle]
+unsafe extern "C" fn artichoke_io_fileno(
+    mrb: *mut sys::mrb_state,
+    slf: sys::mrb_value,
+) -> sys::mrb_value {
+    mrb_get_args!(mrb, none);
+    let mut interp = unwrap_interpreter!(mrb);
+    let mut guard = Guard::new(&mut interp);
+    let io = Value::from(slf);
+    ffi_catch_unwind!(guard, trampoline::fileno(&mut guard, io))
+}
+
+#[no_mangle]
+unsafe extern "C" fn artichoke_io_is_tty(
+    mrb: *mut sys::mrb_state,
+    slf: sys::mrb_value,
+) -> sys::mrb_value {
+    mrb_get_args!(mrb, none);
+    let mut interp = unwrap_interpreter!(mrb);
+    let mut guard = Guard::new(&mut interp);
+    let io = Value::from(slf);
+    ffi_catch_unwind!(guard, trampoline::is_tty(&mut guard, io))
+}
+
+#[no_mangle]
+unsafe extern "C" fn artichoke_io_winsize(
+    mrb: *mut sys::mrb_state,
+    slf: sys::mrb_value,
+) -> sys::mrb_value {
+    mrb_get_args!(mrb, none);
+    let mut interp = unwrap_interpreter!(mrb);
+    let mut guard = Guard::new(&mut interp);
+    let io = Value::from(slf);
+    ffi_catch_unwind!(guard, trampoline::winsize(&mut guard, io))
+}
@@ -0,0 +1,128 @@
+use crate::extn::core::io::IO;
+use crate::extn::prelude::*;
+
+pub fn write<T>(interp: &mut Artichoke, mut io: Value, args: T) -> Result<Value, Exception>
+where
+    T: IntoIterator<Item = Value>,
+{
+    let io = unsafe { IO::unbox_from_value(&mut io, interp)? };
+    let mut written = 0;
+    for value in args {
+        let display = value.to_s(interp);
+        written += io.write(interp, display.as_slice())?;
+    }
+    let written = Int::try_from(written).map_err(|_| Fatal::from("IO#write byte count overflow"))?;
+    Ok(interp.convert(written))
+}
+
+pub fn print<T>(interp: &mut Artichoke, mut io: Value, args: T) -> Result<Value, Exception>
+where
+    T: IntoIterator<Item = Value>,
+{
+    let io = unsafe { IO::unbox_from_value(&mut io, interp)? };
+    for value in args {
+        let display = value.to_s(interp);
+        io.print(interp, display.as_slice())?;
+    }
+    Ok(Value::nil())
+}
+
+pub fn puts<T>(interp: &mut Artichoke, mut io: Value, args: T) -> Result<Value, Exception>
+where
+    T: IntoIterator<Item = Value>,
+{
+    fn puts_foreach(interp: &mut Artichoke, io: &IO, value: &Value) -> Result<(), Exception> {
+        // TODO(GH-310): Use `Value::implicitly_convert_to_array` when
+        // implemented so `Value`s that respond to `to_ary` are converted
+        // and iterated over.
+        if let Ok(array) = value.try_into_mut::<Vec<_>>(interp) {
+            for value in &array {
+                puts_foreach(interp, io, value)?;
+            }
+        } else {
+            let display = value.to_s(interp);
+            io.puts(interp, display.as_slice())?;
+        }
+        Ok(())
+    }
+
+    let io = unsafe { IO::unbox_from_value(&mut io, interp)? };
+    let mut args = args.into_iter();
+    if let Some(first) = args.next() {
+        puts_foreach(interp, &io, &first)?;
+        for value in args {
+            puts_foreach(interp, &io, &value)?;
+        }
+    } else {
+        io.print(interp, b"\n")?;
+    }
+    Ok(Value::nil())
+}
+
+pub fn sync(interp: &mut Artichoke, mut io: Value) -> Result<Value, Exception> {
+    let io = unsafe { IO::unbox_from_value(&mut io, interp)? };
+    Ok(interp.convert(io.sync()))
+}
+
+pub fn set_sync(interp: &mut Artichoke, mut io: Value, sync: Value) -> Result<Value, Exception> {
+    let sync = sync.try_into::<bool>(interp)?;
+    let mut io = unsafe { IO::unbox_from_value(&mut io, interp)? };
+    io.set_sync(sync);
+    Ok(interp.convert(sync))
+}
+
+pub fn read(
+    interp: &mut Artichoke,
+    mut io: Value,
+    length: Option<Value>,
+) -> Result<Value, Exception> {
+    let length = length
+        .map(|length| length.try_into::<Int>(interp))
+        .transpose()?;
+    let length = length
+        .map(usize::try_from)
+        .transpose()
+        .map_err(|_| ArgumentError::from("negative length"))?;
+    let mut io = unsafe { IO::unbox_from_value(&mut io, interp)? };
+    match io.read(length) {
+        Some(bytes) => interp.try_convert_mut(bytes),
+        None => Ok(Value::nil()),
+    }
+}
+
+pub fn gets(interp: &mut Artichoke, mut io: Value) -> Result<Value, Exception> {
+    let mut io = unsafe { IO::unbox_from_value(&mut io, interp)? };
+    match io.gets() {
+        Some(line) => interp.try_convert_mut(line),
+        None => Ok(Value::nil()),
+    }
+}
+
+pub fn rewind(interp: &mut Artichoke, mut io: Value) -> Result<Value, Exception> {
+    let mut io = unsafe { IO::unbox_from_value(&mut io, interp)? };
+    io.rewind();
+    Ok(interp.convert(0_i64))
+}
+
+pub fn fileno(interp: &mut Artichoke, mut io: Value) -> Result<Value, Exception> {
+    let io = unsafe { IO::unbox_from_value(&mut io, interp)? };
+    Ok(interp.convert(io.fileno()))
+}
+
+pub fn is_tty(interp: &mut Artichoke, mut io: Value) -> Result<Value, Exception> {
+    let io = unsafe { IO::unbox_from_value(&mut io, interp)? };
+    Ok(interp.convert(io.is_tty()))
+}
+
+pub fn winsize(interp: &mut Artichoke, mut io: Value) -> Result<Value, Exception> {
+    let io = unsafe { IO::unbox_from_value(&mut io, interp)? };
+    let winsize = io.winsize(interp);
+    drop(io);
+    let (rows, cols) = match winsize {
+        Some(winsize) => winsize,
+        // TODO: This should raise `Errno::ENOTTY`.
+        None => return Err(Exception::from(IOError::from("not a tty"))),
+    };
+    let winsize = vec![interp.convert(Int::from(rows)), interp.convert(Int::from(cols))];
+    interp.try_convert_mut(winsize)
+}
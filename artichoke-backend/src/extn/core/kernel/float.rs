@@ -0,0 +1,46 @@
+use std::str::FromStr;
+
+use crate::extn::prelude::*;
+
+/// Parse a `String` into a `Float` using the grammar accepted by
+/// `Kernel#Float`.
+///
+/// This is stricter than `String#to_f`: embedded underscores are permitted
+/// only between digits (as in a numeric literal), and any other malformed
+/// input is rejected rather than silently truncated.
+pub fn method(arg: &str) -> Result<Fp, Exception> {
+    let trimmed = arg.trim();
+    if trimmed.is_empty() {
+        return Err(invalid_value(arg)?.into());
+    }
+
+    let mut digits = String::with_capacity(trimmed.len());
+    let mut chars = trimmed.chars().peekable();
+    let mut prev = None::<char>;
+    while let Some(current) = chars.next() {
+        if current == '_' {
+            let valid_prev = prev.map_or(false, |prev| prev.is_ascii_digit());
+            let valid_next = chars.peek().map_or(false, |next| next.is_ascii_digit());
+            if valid_prev && valid_next {
+                prev = Some(current);
+                continue;
+            }
+            return Err(invalid_value(arg)?.into());
+        }
+        digits.push(current);
+        prev = Some(current);
+    }
+
+    if let Ok(float) = Fp::from_str(digits.as_str()) {
+        Ok(float)
+    } else {
+        Err(invalid_value(arg)?.into())
+    }
+}
+
+fn invalid_value(arg: &str) -> Result<ArgumentError, Exception> {
+    let mut message = String::from(r#"invalid value for Float(): ""#);
+    string::format_unicode_debug_into(&mut message, arg.as_bytes())?;
+    message.push('"');
+    Ok(ArgumentError::from(message))
+}
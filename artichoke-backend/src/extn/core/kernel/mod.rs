@@ -1,3 +1,4 @@
+pub mod float;
 pub mod integer;
 pub mod mruby;
 pub mod require;
@@ -76,10 +77,10 @@ mod tests {
             let second_i_result = result.try_into::<i64>(&interp).unwrap();
             assert_eq!(second_i_result, 1000);
             let err = interp.eval(b"require 'non-existent-source'").unwrap_err();
-            assert_eq!(
-                &b"cannot load such file -- non-existent-source"[..],
-                err.message().as_ref()
-            );
+            let message = err.message();
+            assert!(message
+                .as_ref()
+                .starts_with(b"cannot load such file -- non-existent-source"));
             let expected = vec![Vec::from(&b"(eval):1"[..])];
             assert_eq!(Some(expected), err.vm_backtrace(&mut interp),);
         }
@@ -115,10 +116,10 @@ mod tests {
         fn directory_err() {
             let mut interp = crate::interpreter().unwrap();
             let err = interp.eval(b"require '/src'").unwrap_err();
-            assert_eq!(
-                &b"cannot load such file -- /src"[..],
-                err.message().as_ref()
-            );
+            let message = err.message();
+            assert!(message
+                .as_ref()
+                .starts_with(b"cannot load such file -- /src"));
             let expected = vec![Vec::from(&b"(eval):1"[..])];
             assert_eq!(Some(expected), err.vm_backtrace(&mut interp));
         }
@@ -163,4 +164,266 @@ mod tests {
             );
         }
     }
+
+    mod explain_require {
+        use crate::test::prelude::*;
+
+        #[test]
+        fn missing_source_reports_untried_candidates() {
+            let mut interp = crate::interpreter().unwrap();
+            let explanation = interp.explain_require("missing").unwrap();
+            assert_eq!(explanation.len(), 2);
+            assert!(explanation.iter().all(|candidate| !candidate.found));
+        }
+
+        #[test]
+        fn registered_source_is_found() {
+            let mut interp = crate::interpreter().unwrap();
+            interp
+                .def_rb_source_file("findme.rb", &b"# a source file"[..])
+                .unwrap();
+            let explanation = interp.explain_require("findme").unwrap();
+            assert_eq!(explanation.len(), 2);
+            assert!(explanation[0].found);
+            assert!(explanation[0].path.ends_with("findme.rb"));
+        }
+
+        #[test]
+        fn dot_rb_extension_has_single_candidate() {
+            let mut interp = crate::interpreter().unwrap();
+            let explanation = interp.explain_require("missing.rb").unwrap();
+            assert_eq!(explanation.len(), 1);
+        }
+
+        #[test]
+        fn cannot_load_error_lists_tried_candidates() {
+            let mut interp = crate::interpreter().unwrap();
+            let err = interp.eval(b"require 'non-existent-source'").unwrap_err();
+            let message = err.message();
+            let message = message.as_ref();
+            assert!(message.starts_with(b"cannot load such file -- non-existent-source (tried: "));
+            assert!(message.ends_with(b")"));
+        }
+    }
+
+    mod require_extensions {
+        use crate::test::prelude::*;
+
+        #[test]
+        fn default_strategy_only_probes_rb() {
+            let mut interp = crate::interpreter().unwrap();
+            interp
+                .def_rb_source_file("precompiled.mrb", &b"# a source file"[..])
+                .unwrap();
+            let err = interp.eval(b"require 'precompiled'").unwrap_err();
+            let message = err.message();
+            assert!(message
+                .as_ref()
+                .starts_with(b"cannot load such file -- precompiled"));
+        }
+
+        // Table-driven: each case configures a different extension search
+        // order and asserts `require` resolves (or fails to resolve)
+        // `basename` accordingly.
+        #[test]
+        fn configured_extensions_change_resolution_order() {
+            let cases: &[(&[&str], &str, bool)] = &[
+                (&[".rb"], "plain.rb", true),
+                (&[".rb"], "plain.mrb", false),
+                (&[".rb", ".mrb"], "plain.mrb", true),
+                (&[".mrb", ".rb"], "plain.rb", true),
+            ];
+            for &(extensions, registered_as, should_resolve) in cases {
+                let mut interp = crate::interpreter().unwrap();
+                interp.set_require_extensions(extensions).unwrap();
+                interp
+                    .def_rb_source_file(registered_as, &b"# a source file"[..])
+                    .unwrap();
+                let result = interp.eval(b"require 'plain'");
+                assert_eq!(
+                    result.is_ok(),
+                    should_resolve,
+                    "extensions {:?}, registered as {:?}",
+                    extensions,
+                    registered_as
+                );
+            }
+        }
+
+        #[test]
+        fn load_honors_configured_extensions() {
+            let mut interp = crate::interpreter().unwrap();
+            interp.set_require_extensions(&[".rb", ".mrb"]).unwrap();
+            interp
+                .def_rb_source_file("plugin.mrb", &b"LOADED = true"[..])
+                .unwrap();
+            let result = interp.eval(b"load 'plugin'").unwrap();
+            assert!(result.try_into::<bool>(&interp).unwrap());
+            let result = interp.eval(b"LOADED").unwrap();
+            assert!(result.try_into::<bool>(&interp).unwrap());
+        }
+
+        #[test]
+        fn explain_require_reflects_configured_extensions() {
+            let mut interp = crate::interpreter().unwrap();
+            interp.set_require_extensions(&[".rb", ".mrb"]).unwrap();
+            let explanation = interp.explain_require("missing").unwrap();
+            assert_eq!(explanation.len(), 3);
+            assert!(explanation[0].path.ends_with("missing.rb"));
+            assert!(explanation[1].path.ends_with("missing.mrb"));
+            assert!(explanation[2].path.ends_with("missing"));
+        }
+    }
+
+    mod require_provider {
+        use crate::require_provider::{RequireProvider, Source};
+        use crate::test::prelude::*;
+
+        #[derive(Debug)]
+        struct StaticProvider {
+            name: &'static str,
+            contents: &'static [u8],
+        }
+
+        impl RequireProvider for StaticProvider {
+            fn resolve(&self, name: &str) -> Option<Source> {
+                if name == self.name {
+                    Some(Source::new(format!("{}.rb", name).into(), self.contents.to_vec()))
+                } else {
+                    None
+                }
+            }
+        }
+
+        #[test]
+        fn provider_resolves_missing_source() {
+            let mut interp = crate::interpreter().unwrap();
+            interp
+                .add_require_provider(Box::new(StaticProvider {
+                    name: "generated",
+                    contents: b"GENERATED = true",
+                }))
+                .unwrap();
+            let result = interp.eval(b"require 'generated'").unwrap();
+            assert!(result.try_into::<bool>(&interp).unwrap());
+            let result = interp.eval(b"GENERATED").unwrap();
+            assert!(result.try_into::<bool>(&interp).unwrap());
+        }
+
+        #[test]
+        fn vfs_source_takes_priority_over_provider() {
+            let mut interp = crate::interpreter().unwrap();
+            interp
+                .def_rb_source_file("shared.rb", &b"FROM_VFS = true"[..])
+                .unwrap();
+            interp
+                .add_require_provider(Box::new(StaticProvider {
+                    name: "shared",
+                    contents: b"FROM_VFS = false",
+                }))
+                .unwrap();
+            let result = interp.eval(b"require 'shared'").unwrap();
+            assert!(result.try_into::<bool>(&interp).unwrap());
+            let result = interp.eval(b"FROM_VFS").unwrap();
+            assert!(result.try_into::<bool>(&interp).unwrap());
+        }
+
+        #[test]
+        fn first_matching_provider_wins() {
+            let mut interp = crate::interpreter().unwrap();
+            interp
+                .add_require_provider(Box::new(StaticProvider {
+                    name: "multi",
+                    contents: b"WINNER = 1",
+                }))
+                .unwrap();
+            interp
+                .add_require_provider(Box::new(StaticProvider {
+                    name: "multi",
+                    contents: b"WINNER = 2",
+                }))
+                .unwrap();
+            let result = interp.eval(b"require 'multi'").unwrap();
+            assert!(result.try_into::<bool>(&interp).unwrap());
+            let result = interp.eval(b"WINNER").unwrap();
+            assert_eq!(result.try_into::<i64>(&interp).unwrap(), 1);
+        }
+
+        #[test]
+        fn no_provider_matches_falls_through_to_load_error() {
+            let mut interp = crate::interpreter().unwrap();
+            interp
+                .add_require_provider(Box::new(StaticProvider {
+                    name: "generated",
+                    contents: b"GENERATED = true",
+                }))
+                .unwrap();
+            let err = interp.eval(b"require 'other'").unwrap_err();
+            let message = err.message();
+            assert!(message
+                .as_ref()
+                .starts_with(b"cannot load such file -- other"));
+        }
+    }
+
+    mod load_wrap {
+        use crate::test::prelude::*;
+
+        #[test]
+        fn unwrapped_defines_constant_on_object() {
+            let mut interp = crate::interpreter().unwrap();
+            interp
+                .def_rb_source_file("plugin.rb", &b"PLUGIN_VERSION = 1"[..])
+                .unwrap();
+            let result = interp.eval(b"load 'plugin.rb'").unwrap();
+            assert!(result.try_into::<bool>(&interp).unwrap());
+            let result = interp.eval(b"PLUGIN_VERSION").unwrap();
+            assert_eq!(result.try_into::<i64>(&interp).unwrap(), 1);
+        }
+
+        #[test]
+        fn wrap_true_hides_top_level_constant() {
+            let mut interp = crate::interpreter().unwrap();
+            interp
+                .def_rb_source_file("plugin.rb", &b"PLUGIN_VERSION = 1"[..])
+                .unwrap();
+            let result = interp.eval(b"load 'plugin.rb', true").unwrap();
+            assert!(result.try_into::<bool>(&interp).unwrap());
+            let err = interp.eval(b"PLUGIN_VERSION").unwrap_err();
+            // `NameError#message` may append a "Did you mean?" line for a
+            // similarly named top-level constant, so compare the first line.
+            let message = err.message();
+            assert_eq!(
+                Some(&b"uninitialized constant PLUGIN_VERSION"[..]),
+                message.split(|&b| b == b'\n').next()
+            );
+        }
+
+        #[test]
+        fn wrap_with_module_defines_constant_there() {
+            let mut interp = crate::interpreter().unwrap();
+            interp
+                .def_rb_source_file("plugin.rb", &b"PLUGIN_VERSION = 1"[..])
+                .unwrap();
+            let result = interp
+                .eval(b"module Plugins; end; load 'plugin.rb', Plugins")
+                .unwrap();
+            assert!(result.try_into::<bool>(&interp).unwrap());
+            let result = interp.eval(b"Plugins::PLUGIN_VERSION").unwrap();
+            assert_eq!(result.try_into::<i64>(&interp).unwrap(), 1);
+        }
+
+        #[test]
+        fn wrap_with_non_module_raises_type_error() {
+            let mut interp = crate::interpreter().unwrap();
+            interp
+                .def_rb_source_file("plugin.rb", &b"# a source file"[..])
+                .unwrap();
+            let err = interp.eval(b"load 'plugin.rb', 'not a module'").unwrap_err();
+            assert_eq!(
+                &b"wrap must be true, false, nil, or a Module"[..],
+                err.message().as_ref()
+            );
+        }
+    }
 }
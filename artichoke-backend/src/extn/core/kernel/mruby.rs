@@ -14,7 +14,17 @@ pub fn init(interp: &mut Artichoke) -> InitializeResult<()> {
             artichoke_kernel_require_relative,
             sys::mrb_args_rest(),
         )?
-        .add_method("load", artichoke_kernel_load, sys::mrb_args_rest())?
+        .add_method(
+            "load",
+            artichoke_kernel_load,
+            sys::mrb_args_req_and_opt(1, 1),
+        )?
+        .add_method(
+            "local_variables",
+            artichoke_kernel_local_variables,
+            sys::mrb_args_none(),
+        )?
+        .add_method("reload!", artichoke_kernel_reload, sys::mrb_args_req(1))?
         .add_method("p", artichoke_kernel_p, sys::mrb_args_rest())?
         .add_method("print", artichoke_kernel_print, sys::mrb_args_rest())?
         .add_method("puts", artichoke_kernel_puts, sys::mrb_args_rest())?
@@ -43,6 +53,8 @@ pub fn init(interp: &mut Artichoke) -> InitializeResult<()> {
             artichoke_kernel_integer,
             sys::mrb_args_req_and_opt(1, 1),
         )?
+        .add_method("Float", artichoke_kernel_float, sys::mrb_args_req(1))?
+        .add_self_method("Float", artichoke_kernel_float, sys::mrb_args_req(1))?
         .define()?;
     interp.def_module::<artichoke::Kernel>(spec)?;
     trace!("Patched Artichoke::Kernel onto interpreter");
@@ -58,26 +70,51 @@ unsafe extern "C" fn artichoke_kernel_integer(
     let mut guard = Guard::new(&mut interp);
     let arg = Value::from(arg);
     let base = base.map(Value::from);
-    let result = trampoline::integer(&mut guard, arg, base);
-    match result {
-        Ok(value) => value.inner(),
-        Err(exception) => exception::raise(guard, exception),
-    }
+    ffi_catch_unwind!(guard, trampoline::integer(&mut guard, arg, base))
+}
+
+unsafe extern "C" fn artichoke_kernel_float(
+    mrb: *mut sys::mrb_state,
+    _slf: sys::mrb_value,
+) -> sys::mrb_value {
+    let arg = mrb_get_args!(mrb, required = 1);
+    let mut interp = unwrap_interpreter!(mrb);
+    let mut guard = Guard::new(&mut interp);
+    let arg = Value::from(arg);
+    ffi_catch_unwind!(guard, trampoline::float(&mut guard, arg))
+}
+
+unsafe extern "C" fn artichoke_kernel_local_variables(
+    mrb: *mut sys::mrb_state,
+    _slf: sys::mrb_value,
+) -> sys::mrb_value {
+    mrb_get_args!(mrb, none);
+    let mut interp = unwrap_interpreter!(mrb);
+    let mut guard = Guard::new(&mut interp);
+    ffi_catch_unwind!(guard, trampoline::local_variables(&mut guard))
 }
 
 unsafe extern "C" fn artichoke_kernel_load(
     mrb: *mut sys::mrb_state,
     _slf: sys::mrb_value,
+) -> sys::mrb_value {
+    let (file, wrap) = mrb_get_args!(mrb, required = 1, optional = 1);
+    let mut interp = unwrap_interpreter!(mrb);
+    let mut guard = Guard::new(&mut interp);
+    let file = Value::from(file);
+    let wrap = wrap.map(Value::from);
+    ffi_catch_unwind!(guard, trampoline::load(&mut guard, file, wrap))
+}
+
+unsafe extern "C" fn artichoke_kernel_reload(
+    mrb: *mut sys::mrb_state,
+    _slf: sys::mrb_value,
 ) -> sys::mrb_value {
     let file = mrb_get_args!(mrb, required = 1);
     let mut interp = unwrap_interpreter!(mrb);
     let mut guard = Guard::new(&mut interp);
     let file = Value::from(file);
-    let result = trampoline::load(&mut guard, file);
-    match result {
-        Ok(value) => value.inner(),
-        Err(exception) => exception::raise(guard, exception),
-    }
+    ffi_catch_unwind!(guard, trampoline::reload(&mut guard, file))
 }
 
 unsafe extern "C" fn artichoke_kernel_p(
@@ -88,11 +125,7 @@ unsafe extern "C" fn artichoke_kernel_p(
     let mut interp = unwrap_interpreter!(mrb);
     let mut guard = Guard::new(&mut interp);
     let args = args.iter().copied().map(Value::from);
-    let result = trampoline::p(&mut guard, args);
-    match result {
-        Ok(value) => value.inner(),
-        Err(exception) => exception::raise(guard, exception),
-    }
+    ffi_catch_unwind!(guard, trampoline::p(&mut guard, args))
 }
 
 unsafe extern "C" fn artichoke_kernel_print(
@@ -103,11 +136,7 @@ unsafe extern "C" fn artichoke_kernel_print(
     let mut interp = unwrap_interpreter!(mrb);
     let mut guard = Guard::new(&mut interp);
     let args = args.iter().copied().map(Value::from);
-    let result = trampoline::print(&mut guard, args);
-    match result {
-        Ok(value) => value.inner(),
-        Err(exception) => exception::raise(guard, exception),
-    }
+    ffi_catch_unwind!(guard, trampoline::print(&mut guard, args))
 }
 
 unsafe extern "C" fn artichoke_kernel_puts(
@@ -118,11 +147,7 @@ unsafe extern "C" fn artichoke_kernel_puts(
     let mut interp = unwrap_interpreter!(mrb);
     let mut guard = Guard::new(&mut interp);
     let args = args.iter().copied().map(Value::from);
-    let result = trampoline::puts(&mut guard, args);
-    match result {
-        Ok(value) => value.inner(),
-        Err(exception) => exception::raise(guard, exception),
-    }
+    ffi_catch_unwind!(guard, trampoline::puts(&mut guard, args))
 }
 
 unsafe extern "C" fn artichoke_kernel_require(
@@ -133,11 +158,7 @@ unsafe extern "C" fn artichoke_kernel_require(
     let mut interp = unwrap_interpreter!(mrb);
     let mut guard = Guard::new(&mut interp);
     let file = Value::from(file);
-    let result = trampoline::require(&mut guard, file);
-    match result {
-        Ok(value) => value.inner(),
-        Err(exception) => exception::raise(guard, exception),
-    }
+    ffi_catch_unwind!(guard, trampoline::require(&mut guard, file))
 }
 
 unsafe extern "C" fn artichoke_kernel_require_relative(
@@ -148,9 +169,5 @@ unsafe extern "C" fn artichoke_kernel_require_relative(
     let mut interp = unwrap_interpreter!(mrb);
     let mut guard = Guard::new(&mut interp);
     let file = Value::from(file);
-    let result = trampoline::require_relative(&mut guard, file);
-    match result {
-        Ok(value) => value.inner(),
-        Err(exception) => exception::raise(guard, exception),
-    }
+    ffi_catch_unwind!(guard, trampoline::require_relative(&mut guard, file))
 }
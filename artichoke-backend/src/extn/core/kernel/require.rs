@@ -2,92 +2,426 @@
 
 use bstr::ByteSlice;
 use std::path::{Path, PathBuf};
+use std::ptr::{self, NonNull};
 
 use crate::extn::prelude::*;
 use crate::ffi;
+use crate::ffi::InterpreterExtractError;
+use crate::fs::path::absolutize_relative_to;
 use crate::fs::RUBY_LOAD_PATH;
 use crate::state::parser::Context;
+use crate::state::reload::State as ReloadState;
+use crate::types::Ruby;
 
-const RUBY_EXTENSION: &str = "rb";
-
-pub fn load(interp: &mut Artichoke, mut filename: Value) -> Result<bool, Exception> {
+pub fn load(
+    interp: &mut Artichoke,
+    mut filename: Value,
+    wrap: Option<Value>,
+) -> Result<bool, Exception> {
+    let original_filename = filename;
     let filename = filename.implicitly_convert_to_string(interp)?;
     if filename.find_byte(b'\0').is_some() {
         return Err(ArgumentError::from("path name contains null byte").into());
     }
+    check_untrusted_sink(interp, original_filename, filename)?;
     let file = ffi::bytes_to_os_str(filename)?;
-    let pathbuf;
-    let mut path = Path::new(file);
-    if path.is_relative() {
-        pathbuf = Path::new(RUBY_LOAD_PATH).join(file);
-        path = pathbuf.as_path();
-    }
-    if !interp.source_is_file(path)? {
-        let mut message = b"cannot load such file -- ".to_vec();
-        message.extend_from_slice(filename);
-        return Err(LoadError::from(message).into());
+    let path = Path::new(file);
+    let candidates = candidate_paths(interp, path, None)?;
+    let mut path = None;
+    for candidate in &candidates {
+        if interp.source_is_file(candidate)? {
+            path = Some(candidate.clone());
+            break;
+        }
     }
-    let context = Context::new(ffi::os_str_to_bytes(path.as_os_str())?.to_vec())
+    let path = match path {
+        Some(path) => path,
+        None => {
+            let message = cannot_load_message(filename, &candidates)?;
+            return Err(LoadError::from(message).into());
+        }
+    };
+    let path = path.as_path();
+    let target_class = wrap_target_class(interp, wrap)?;
+    let path_bytes = ffi::os_str_to_bytes(path.as_os_str())?.to_vec();
+    let context = Context::new(path_bytes.clone())
         .ok_or_else(|| ArgumentError::from("path name contains null byte"))?;
+    let before = snapshot_constants(interp)?;
     interp.push_context(context)?;
-    let result = interp.load_source(path);
+    let result = if let Some(target_class) = target_class {
+        load_source_wrapped(interp, path, target_class)
+    } else {
+        interp.load_source(path)
+    };
     let _ = interp.pop_context()?;
+    if let Ok(true) = result {
+        restrict_visibility(interp, &path_bytes, before)?;
+    }
     result
 }
 
+/// Resolve the `wrap` argument to `Kernel#load` to the [`RClass`](sys::RClass)
+/// its top-level `def`s and constants should attach to, or `None` for the
+/// default (unwrapped) behavior.
+///
+/// `wrap` may be `nil`/`false`/omitted (no wrapping), `true` (wrap in a
+/// freshly allocated anonymous module), or a `Module`/`Class` to wrap in
+/// directly.
+///
+/// Because this interpreter compiles every `eval` against a single shared
+/// parser context rather than allocating one per call, a `require`/`load`
+/// triggered synchronously from inside the wrapped file's top level also
+/// sees `wrap`'s scope until that statement finishes evaluating, unlike
+/// MRI, where only the directly loaded file is wrapped.
+fn wrap_target_class(
+    interp: &mut Artichoke,
+    wrap: Option<Value>,
+) -> Result<Option<NonNull<sys::RClass>>, Exception> {
+    let wrap = match wrap {
+        Some(wrap) if !wrap.is_nil() => wrap,
+        _ => return Ok(None),
+    };
+    match wrap.ruby_type() {
+        Ruby::Bool => {
+            if wrap.try_into::<bool>(interp)? {
+                let rclass = unsafe { interp.with_ffi_boundary(|mrb| sys::mrb_module_new(mrb))? };
+                let rclass = NonNull::new(rclass)
+                    .ok_or_else(|| Fatal::from("failed to allocate anonymous module"))?;
+                Ok(Some(rclass))
+            } else {
+                Ok(None)
+            }
+        }
+        Ruby::Module | Ruby::Class => {
+            let rclass = unsafe { sys::mrb_sys_class_ptr(wrap.inner()) };
+            let rclass = NonNull::new(rclass)
+                .ok_or_else(|| Fatal::from("wrap target has no backing class"))?;
+            Ok(Some(rclass))
+        }
+        _ => Err(TypeError::from("wrap must be true, false, nil, or a Module").into()),
+    }
+}
+
+/// Evaluate the source at `path` with the parser's `target_class` set to
+/// `target_class` for the duration of the eval, so top-level `def`s and
+/// constants attach to `target_class` instead of `Object`.
+///
+/// See [`wrap_target_class`] for the caveat this introduces for nested
+/// `require`/`load` calls.
+fn load_source_wrapped(
+    interp: &mut Artichoke,
+    path: &Path,
+    target_class: NonNull<sys::RClass>,
+) -> Result<bool, Exception> {
+    {
+        let state = interp.state.as_mut().ok_or(InterpreterExtractError)?;
+        let hook = state.vfs.get_extension(path);
+        if let Some(hook) = hook {
+            hook(interp)?;
+        }
+    }
+    let contents = interp.read_source_file_contents(path)?.into_owned();
+    {
+        let state = interp.state.as_mut().ok_or(InterpreterExtractError)?;
+        let parser = state.parser.as_mut().ok_or(InterpreterExtractError)?;
+        parser.context_mut().target_class = target_class.as_ptr();
+    }
+    let result = interp.eval(contents.as_ref());
+    if let Some(state) = interp.state.as_mut() {
+        if let Some(parser) = state.parser.as_mut() {
+            parser.context_mut().target_class = ptr::null_mut();
+        }
+    }
+    result.map(|_| true)
+}
+
 pub fn require(
     interp: &mut Artichoke,
     mut filename: Value,
     base: Option<RelativePath>,
 ) -> Result<bool, Exception> {
+    let original_filename = filename;
     let filename = filename.implicitly_convert_to_string(interp)?;
     if filename.find_byte(b'\0').is_some() {
         return Err(ArgumentError::from("path name contains null byte").into());
     }
+    check_untrusted_sink(interp, original_filename, filename)?;
     let file = ffi::bytes_to_os_str(filename)?;
     let path = Path::new(file);
+    let candidates = candidate_paths(interp, path, base.as_ref())?;
+    for candidate in &candidates {
+        if interp.source_is_file(candidate)? {
+            return require_candidate(interp, candidate);
+        }
+    }
+    if let Some(source) = require_from_provider(interp, filename)? {
+        interp.def_rb_source_file(&source.path, source.contents)?;
+        return require_candidate(interp, &source.path);
+    }
+    Err(LoadError::from(cannot_load_message(filename, &candidates)?).into())
+}
+
+/// Consult the installed [`RequireProvider`](crate::require_provider::RequireProvider)s,
+/// in registration order, for a source matching `name`.
+///
+/// Returns the first provider's resolved [`Source`](crate::require_provider::Source), or
+/// `None` if every provider declined or `name` is not valid UTF-8 (providers
+/// are given a `&str`, so a non-UTF-8 `require` falls straight through to the
+/// normal `LoadError`).
+fn require_from_provider(
+    interp: &Artichoke,
+    name: &[u8],
+) -> Result<Option<crate::require_provider::Source>, Exception> {
+    let name = match std::str::from_utf8(name) {
+        Ok(name) => name,
+        Err(_) => return Ok(None),
+    };
+    let state = interp.state.as_ref().ok_or(InterpreterExtractError)?;
+    for provider in &state.require_providers {
+        if let Some(source) = provider.resolve(name) {
+            return Ok(Some(source));
+        }
+    }
+    Ok(None)
+}
 
-    let (path, alternate) = if path.is_relative() {
-        let mut path = if let Some(ref base) = base {
+/// Resolution strategy shared by [`require`], [`load`], and
+/// [`explain_require`]: the ordered list of paths checked for `path`, most
+/// to least specific.
+///
+/// A relative `path` is first resolved against `base` (for
+/// `require_relative`) or the Ruby load path (for `require` and `load`).
+/// The resolved path is then expanded into candidates by the interpreter's
+/// configured [`ExtensionStrategy`](crate::state::require::ExtensionStrategy)
+/// -- by default, the path with `.rb` appended, tried first, then the path
+/// unmodified, so a source registered under its extension-less name (for
+/// example by a Rust `File` type) is still found. An absolute `path` is
+/// expanded the same way, without the load-path resolution step.
+fn candidate_paths(
+    interp: &Artichoke,
+    path: &Path,
+    base: Option<&RelativePath>,
+) -> Result<Vec<PathBuf>, Exception> {
+    let resolved = if path.is_relative() {
+        if let Some(base) = base {
             base.join(path)
         } else {
-            Path::new(RUBY_LOAD_PATH).join(path)
-        };
-        let is_rb = path
-            .extension()
-            .filter(|ext| ext == &RUBY_EXTENSION)
-            .is_some();
-        if is_rb {
-            (path, None)
-        } else {
-            let alternate = path.clone();
-            path.set_extension(RUBY_EXTENSION);
-            (path, Some(alternate))
+            absolutize_relative_to(path, RUBY_LOAD_PATH)
         }
     } else {
-        (path.to_owned(), None)
+        path.to_owned()
     };
-    if interp.source_is_file(&path)? {
-        let context = Context::new(ffi::os_str_to_bytes(path.as_os_str())?.to_vec())
-            .ok_or_else(|| ArgumentError::from("path name contains null byte"))?;
-        interp.push_context(context)?;
-        let result = interp.require_source(&path);
-        let _ = interp.pop_context()?;
-        return result;
-    }
-    if let Some(path) = alternate {
-        if interp.source_is_file(&path)? {
-            let context = Context::new(ffi::os_str_to_bytes(path.as_os_str())?.to_vec())
-                .ok_or_else(|| ArgumentError::from("path name contains null byte"))?;
-            interp.push_context(context)?;
-            let result = interp.require_source(&path);
-            let _ = interp.pop_context()?;
-            return result;
-        }
+    let state = interp.state.as_ref().ok_or(InterpreterExtractError)?;
+    Ok(state.require_extensions.candidates(&resolved))
+}
+
+/// `require` the source at `path`, which the caller has already confirmed
+/// exists via [`Artichoke::source_is_file`].
+fn require_candidate(interp: &mut Artichoke, path: &Path) -> Result<bool, Exception> {
+    let path_bytes = ffi::os_str_to_bytes(path.as_os_str())?.to_vec();
+    let context = Context::new(path_bytes.clone())
+        .ok_or_else(|| ArgumentError::from("path name contains null byte"))?;
+    let before = snapshot_constants(interp)?;
+    interp.push_context(context)?;
+    let result = interp.require_source(path);
+    let _ = interp.pop_context()?;
+    if let Ok(true) = result {
+        restrict_visibility(interp, &path_bytes, before)?;
     }
+    result
+}
+
+/// Build the `cannot load such file` message for a failed `require`,
+/// appending the candidate paths that were searched so a mismatch between
+/// where a source was registered and where `require` looked for it is
+/// diagnosable from the exception alone.
+///
+/// MRI's own `LoadError` does not include this detail; this is a deliberate
+/// enhancement for this interpreter's VFS-backed require.
+fn cannot_load_message(filename: &[u8], candidates: &[PathBuf]) -> Result<Vec<u8>, Exception> {
     let mut message = b"cannot load such file -- ".to_vec();
     message.extend_from_slice(filename);
-    Err(LoadError::from(message).into())
+    if !candidates.is_empty() {
+        message.extend_from_slice(b" (tried: ");
+        for (index, candidate) in candidates.iter().enumerate() {
+            if index > 0 {
+                message.extend_from_slice(b", ");
+            }
+            message.extend_from_slice(ffi::os_str_to_bytes(candidate.as_os_str())?);
+        }
+        message.extend_from_slice(b")");
+    }
+    Ok(message)
+}
+
+/// One candidate path considered while resolving a `require`, and whether
+/// the interpreter's virtual filesystem has a source registered there.
+///
+/// Returned by [`explain_require`] for diagnosing VFS vs load-path issues
+/// without actually requiring the source.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RequireCandidate {
+    pub path: PathBuf,
+    pub found: bool,
+}
+
+/// Resolve `filename` the same way [`require`] does, but only report which
+/// candidate paths were checked and whether each was found, without
+/// requiring anything.
+///
+/// This is exposed on [`Artichoke`] as
+/// [`Artichoke::explain_require`](crate::Artichoke::explain_require).
+pub fn explain_require(
+    interp: &mut Artichoke,
+    filename: &str,
+) -> Result<Vec<RequireCandidate>, Exception> {
+    let path = Path::new(filename);
+    let candidates = candidate_paths(interp, path, None)?;
+    let mut explanation = Vec::with_capacity(candidates.len());
+    for path in candidates {
+        let found = interp.source_is_file(&path)?;
+        explanation.push(RequireCandidate { path, found });
+    }
+    Ok(explanation)
+}
+
+impl Artichoke {
+    /// Explain how [`Kernel#require`](require) would resolve `filename`
+    /// without requiring it.
+    ///
+    /// Returns every candidate path that would be checked, in search order,
+    /// paired with whether a source is currently registered there, so an
+    /// embedder can tell a VFS registration mistake (wrong path registered)
+    /// apart from a load-path mistake (right path, wrong directory).
+    pub fn explain_require(&mut self, filename: &str) -> Result<Vec<RequireCandidate>, Exception> {
+        explain_require(self, filename)
+    }
+}
+
+/// Re-require a source if its contents have changed since it was last
+/// required.
+///
+/// Returns `true` if the source was re-required, `false` if the source's
+/// contents were unchanged and no action was taken.
+pub fn reload(
+    interp: &mut Artichoke,
+    mut filename: Value,
+    base: Option<RelativePath>,
+) -> Result<bool, Exception> {
+    let original_filename = filename;
+    let filename = filename.implicitly_convert_to_string(interp)?;
+    if filename.find_byte(b'\0').is_some() {
+        return Err(ArgumentError::from("path name contains null byte").into());
+    }
+    check_untrusted_sink(interp, original_filename, filename)?;
+    let file = ffi::bytes_to_os_str(filename)?;
+    let path = Path::new(file);
+    let path = if path.is_relative() {
+        if let Some(ref base) = base {
+            base.join(path)
+        } else {
+            absolutize_relative_to(path, RUBY_LOAD_PATH)
+        }
+    } else {
+        path.to_owned()
+    };
+    if !interp.source_is_file(&path)? {
+        let mut message = b"cannot load such file -- ".to_vec();
+        message.extend_from_slice(filename);
+        return Err(LoadError::from(message).into());
+    }
+    let contents = interp.read_source_file_contents(&path)?.into_owned();
+    let hash = ReloadState::content_hash(&contents);
+    let has_changed = {
+        let state = interp.state.as_ref().ok_or(InterpreterExtractError)?;
+        state.source_hashes.has_changed(&path, hash)
+    };
+    if !has_changed {
+        return Ok(false);
+    }
+    {
+        let state = interp.state.as_mut().ok_or(InterpreterExtractError)?;
+        state.vfs.unmark_required(&path)?;
+    }
+    let path_bytes = ffi::os_str_to_bytes(path.as_os_str())?.to_vec();
+    let context = Context::new(path_bytes.clone())
+        .ok_or_else(|| ArgumentError::from("path name contains null byte"))?;
+    let before = snapshot_constants(interp)?;
+    interp.push_context(context)?;
+    let result = interp.require_source(&path);
+    let _ = interp.pop_context()?;
+    if let Ok(true) = result {
+        restrict_visibility(interp, &path_bytes, before)?;
+    }
+    result
+}
+
+/// Check `filename` against the installed
+/// [`SecureContextHooks`](crate::secure_context::SecureContextHooks) if it
+/// is a `String` flagged untrusted with
+/// [`Artichoke::mark_untrusted`](crate::Artichoke::mark_untrusted).
+///
+/// Non-`String` filenames (for example a `Symbol`) are never flagged, so
+/// this is a no-op for them.
+fn check_untrusted_sink(
+    interp: &mut Artichoke,
+    filename: Value,
+    path: &[u8],
+) -> Result<(), Exception> {
+    if !matches!(filename.ruby_type(), Ruby::String) {
+        return Ok(());
+    }
+    if !interp.is_untrusted(&filename)? {
+        return Ok(());
+    }
+    let result = {
+        let state = interp.state.as_ref().ok_or(InterpreterExtractError)?;
+        state.secure_context.check_sink("require", path)
+    };
+    result.map_err(|message| SecurityError::from(message).into())
+}
+
+/// Snapshot the names of every top-level constant currently defined.
+///
+/// Diffing a snapshot taken before a `require`/`load` against one taken
+/// after finds the constants the required source newly defined. See
+/// [`RequireVisibilityHooks`](crate::require_visibility::RequireVisibilityHooks).
+fn snapshot_constants(interp: &mut Artichoke) -> Result<Vec<Vec<u8>>, Exception> {
+    let constants = interp.eval(b"Object.constants.map(&:to_s)")?;
+    interp.try_convert_mut(constants)
+}
+
+/// Remove any top-level constant defined by a `require`/`load` that the
+/// installed [`RequireVisibilityHooks`](crate::require_visibility::RequireVisibilityHooks)
+/// does not allow to remain visible.
+///
+/// `before` is the snapshot [`snapshot_constants`] took immediately before
+/// the source at `path` ran.
+fn restrict_visibility(
+    interp: &mut Artichoke,
+    path: &[u8],
+    before: Vec<Vec<u8>>,
+) -> Result<(), Exception> {
+    let after = snapshot_constants(interp)?;
+    let defined: Vec<Vec<u8>> = after.into_iter().filter(|name| !before.contains(name)).collect();
+    if defined.is_empty() {
+        return Ok(());
+    }
+    let filter = {
+        let state = interp.state.as_ref().ok_or(InterpreterExtractError)?;
+        state.require_visibility_hooks.filter_constants
+    };
+    let allowed = filter(path, &defined);
+    for name in &defined {
+        if allowed.contains(name) {
+            continue;
+        }
+        let mut source = b"Object.send(:remove_const, :".to_vec();
+        source.extend_from_slice(name);
+        source.push(b')');
+        interp.eval(&source)?;
+    }
+    Ok(())
 }
 
 #[derive(Default, Debug, Clone, Hash, PartialEq, Eq, PartialOrd, Ord)]
@@ -124,7 +458,7 @@ impl RelativePath {
     }
 
     pub fn join<P: AsRef<Path>>(&self, path: P) -> PathBuf {
-        self.0.join(path.as_ref())
+        absolutize_relative_to(path, &self.0)
     }
 
     pub fn try_from_interp(interp: &mut Artichoke) -> Result<Self, Exception> {
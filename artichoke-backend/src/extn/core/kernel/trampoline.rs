@@ -1,3 +1,5 @@
+use std::str;
+
 use crate::extn::core::kernel;
 use crate::extn::core::kernel::require::RelativePath;
 use crate::extn::prelude::*;
@@ -14,18 +16,67 @@ pub fn integer(
     Ok(interp.convert(integer))
 }
 
-pub fn load(interp: &mut Artichoke, path: Value) -> Result<Value, Exception> {
-    let success = kernel::require::load(interp, path)?;
+pub fn float(interp: &mut Artichoke, mut arg: Value) -> Result<Value, Exception> {
+    let arg = arg.implicitly_convert_to_string(interp)?;
+    let arg = str::from_utf8(arg).map_err(|_| ArgumentError::from("invalid byte sequence"))?;
+    let float = kernel::float::method(arg)?;
+    Ok(interp.convert_mut(float))
+}
+
+pub fn local_variables(interp: &mut Artichoke) -> Result<Value, Exception> {
+    let locals = unsafe { interp.with_ffi_boundary(|mrb| sys::mrb_sys_local_variables(mrb))? };
+    Ok(Value::from(locals))
+}
+
+pub fn load(interp: &mut Artichoke, path: Value, wrap: Option<Value>) -> Result<Value, Exception> {
+    let success = kernel::require::load(interp, path, wrap)?;
+    Ok(interp.convert(success))
+}
+
+pub fn reload(interp: &mut Artichoke, path: Value) -> Result<Value, Exception> {
+    let success = kernel::require::reload(interp, path, None)?;
     Ok(interp.convert(success))
 }
 
+/// Write bytes to the current `$stdout` Ruby object.
+///
+/// If `$stdout` has been reassigned to a Ruby object that responds to
+/// `print`, the write is dispatched there so hosts that swap `$stdout` (for
+/// example, to capture output in tests) observe writes made by `Kernel`'s
+/// print family. If `$stdout` is unset, this falls back to writing directly
+/// to the interpreter's output strategy.
+fn stdout_write(interp: &mut Artichoke, bytes: Vec<u8>) -> Result<(), Exception> {
+    match interp.get_global_variable(&b"$stdout"[..])? {
+        Some(stdout) if !stdout.is_nil() => {
+            let message = interp.convert_mut(bytes);
+            let _ = stdout.funcall(interp, "print", &[message], None)?;
+        }
+        _ => interp.print(bytes)?,
+    }
+    Ok(())
+}
+
+/// Write a line to the current `$stdout` Ruby object.
+///
+/// See [`stdout_write`] for how `$stdout` reassignment is honored.
+fn stdout_puts(interp: &mut Artichoke, bytes: Vec<u8>) -> Result<(), Exception> {
+    match interp.get_global_variable(&b"$stdout"[..])? {
+        Some(stdout) if !stdout.is_nil() => {
+            let message = interp.convert_mut(bytes);
+            let _ = stdout.funcall(interp, "puts", &[message], None)?;
+        }
+        _ => interp.puts(bytes)?,
+    }
+    Ok(())
+}
+
 pub fn print<T>(interp: &mut Artichoke, args: T) -> Result<Value, Exception>
 where
     T: IntoIterator<Item = Value>,
 {
     for value in args {
         let display = value.to_s(interp);
-        interp.print(display)?;
+        stdout_write(interp, display)?;
     }
     Ok(Value::nil())
 }
@@ -35,16 +86,14 @@ where
     T: IntoIterator<Item = Value>,
 {
     fn puts_foreach(interp: &mut Artichoke, value: &Value) -> Result<(), Exception> {
-        // TODO(GH-310): Use `Value::implicitly_convert_to_array` when
-        // implemented so `Value`s that respond to `to_ary` are converted
-        // and iterated over.
-        if let Ok(array) = value.try_into_mut::<Vec<_>>(interp) {
+        let mut value = *value;
+        if let Ok(array) = value.implicitly_convert_to_array(interp) {
             for value in &array {
                 puts_foreach(interp, value)?;
             }
         } else {
             let display = value.to_s(interp);
-            interp.puts(display)?;
+            stdout_puts(interp, display)?;
         }
         Ok(())
     }
@@ -56,7 +105,7 @@ where
             puts_foreach(interp, &value)?;
         }
     } else {
-        interp.print(b"\n")?;
+        stdout_write(interp, b"\n".to_vec())?;
     }
     Ok(Value::nil())
 }
@@ -68,14 +117,14 @@ where
     let mut args = args.into_iter().peekable();
     if let Some(first) = args.next() {
         let display = first.inspect(interp);
-        interp.puts(display)?;
+        stdout_puts(interp, display)?;
         if args.peek().is_none() {
             return Ok(first);
         }
         let mut result = vec![first];
         for value in args {
             let display = value.inspect(interp);
-            interp.puts(display)?;
+            stdout_puts(interp, display)?;
             result.push(value)
         }
         interp.try_convert_mut(result)
@@ -64,11 +64,7 @@ unsafe extern "C" fn artichoke_matchdata_begin(
     let mut guard = Guard::new(&mut interp);
     let value = Value::from(slf);
     let begin = Value::from(begin);
-    let result = trampoline::begin(&mut guard, value, begin);
-    match result {
-        Ok(result) => result.inner(),
-        Err(exception) => exception::raise(guard, exception),
-    }
+    ffi_catch_unwind!(guard, trampoline::begin(&mut guard, value, begin))
 }
 
 unsafe extern "C" fn artichoke_matchdata_captures(
@@ -79,11 +75,7 @@ unsafe extern "C" fn artichoke_matchdata_captures(
     let mut interp = unwrap_interpreter!(mrb);
     let mut guard = Guard::new(&mut interp);
     let value = Value::from(slf);
-    let result = trampoline::captures(&mut guard, value);
-    match result {
-        Ok(result) => result.inner(),
-        Err(exception) => exception::raise(guard, exception),
-    }
+    ffi_catch_unwind!(guard, trampoline::captures(&mut guard, value))
 }
 
 unsafe extern "C" fn artichoke_matchdata_element_reference(
@@ -96,11 +88,7 @@ unsafe extern "C" fn artichoke_matchdata_element_reference(
     let value = Value::from(slf);
     let elem = Value::from(elem);
     let len = len.map(Value::from);
-    let result = trampoline::element_reference(&mut guard, value, elem, len);
-    match result {
-        Ok(result) => result.inner(),
-        Err(exception) => exception::raise(guard, exception),
-    }
+    ffi_catch_unwind!(guard, trampoline::element_reference(&mut guard, value, elem, len))
 }
 
 unsafe extern "C" fn artichoke_matchdata_end(
@@ -112,11 +100,7 @@ unsafe extern "C" fn artichoke_matchdata_end(
     let mut guard = Guard::new(&mut interp);
     let value = Value::from(slf);
     let end = Value::from(end);
-    let result = trampoline::end(&mut guard, value, end);
-    match result {
-        Ok(result) => result.inner(),
-        Err(exception) => exception::raise(guard, exception),
-    }
+    ffi_catch_unwind!(guard, trampoline::end(&mut guard, value, end))
 }
 
 unsafe extern "C" fn artichoke_matchdata_length(
@@ -127,11 +111,7 @@ unsafe extern "C" fn artichoke_matchdata_length(
     let mut interp = unwrap_interpreter!(mrb);
     let mut guard = Guard::new(&mut interp);
     let value = Value::from(slf);
-    let result = trampoline::length(&mut guard, value);
-    match result {
-        Ok(result) => result.inner(),
-        Err(exception) => exception::raise(guard, exception),
-    }
+    ffi_catch_unwind!(guard, trampoline::length(&mut guard, value))
 }
 
 unsafe extern "C" fn artichoke_matchdata_named_captures(
@@ -142,11 +122,7 @@ unsafe extern "C" fn artichoke_matchdata_named_captures(
     let mut interp = unwrap_interpreter!(mrb);
     let mut guard = Guard::new(&mut interp);
     let value = Value::from(slf);
-    let result = trampoline::named_captures(&mut guard, value);
-    match result {
-        Ok(result) => result.inner(),
-        Err(exception) => exception::raise(guard, exception),
-    }
+    ffi_catch_unwind!(guard, trampoline::named_captures(&mut guard, value))
 }
 
 unsafe extern "C" fn artichoke_matchdata_names(
@@ -157,11 +133,7 @@ unsafe extern "C" fn artichoke_matchdata_names(
     let mut interp = unwrap_interpreter!(mrb);
     let mut guard = Guard::new(&mut interp);
     let value = Value::from(slf);
-    let result = trampoline::names(&mut guard, value);
-    match result {
-        Ok(result) => result.inner(),
-        Err(exception) => exception::raise(guard, exception),
-    }
+    ffi_catch_unwind!(guard, trampoline::names(&mut guard, value))
 }
 
 unsafe extern "C" fn artichoke_matchdata_offset(
@@ -173,11 +145,7 @@ unsafe extern "C" fn artichoke_matchdata_offset(
     let mut guard = Guard::new(&mut interp);
     let value = Value::from(slf);
     let offset = Value::from(offset);
-    let result = trampoline::offset(&mut guard, value, offset);
-    match result {
-        Ok(result) => result.inner(),
-        Err(exception) => exception::raise(guard, exception),
-    }
+    ffi_catch_unwind!(guard, trampoline::offset(&mut guard, value, offset))
 }
 
 unsafe extern "C" fn artichoke_matchdata_post_match(
@@ -188,11 +156,7 @@ unsafe extern "C" fn artichoke_matchdata_post_match(
     let mut interp = unwrap_interpreter!(mrb);
     let mut guard = Guard::new(&mut interp);
     let value = Value::from(slf);
-    let result = trampoline::post_match(&mut guard, value);
-    match result {
-        Ok(result) => result.inner(),
-        Err(exception) => exception::raise(guard, exception),
-    }
+    ffi_catch_unwind!(guard, trampoline::post_match(&mut guard, value))
 }
 
 unsafe extern "C" fn artichoke_matchdata_pre_match(
@@ -203,11 +167,7 @@ unsafe extern "C" fn artichoke_matchdata_pre_match(
     let mut interp = unwrap_interpreter!(mrb);
     let mut guard = Guard::new(&mut interp);
     let value = Value::from(slf);
-    let result = trampoline::pre_match(&mut guard, value);
-    match result {
-        Ok(result) => result.inner(),
-        Err(exception) => exception::raise(guard, exception),
-    }
+    ffi_catch_unwind!(guard, trampoline::pre_match(&mut guard, value))
 }
 
 unsafe extern "C" fn artichoke_matchdata_regexp(
@@ -218,11 +178,7 @@ unsafe extern "C" fn artichoke_matchdata_regexp(
     let mut interp = unwrap_interpreter!(mrb);
     let mut guard = Guard::new(&mut interp);
     let value = Value::from(slf);
-    let result = trampoline::regexp(&mut guard, value);
-    match result {
-        Ok(result) => result.inner(),
-        Err(exception) => exception::raise(guard, exception),
-    }
+    ffi_catch_unwind!(guard, trampoline::regexp(&mut guard, value))
 }
 
 unsafe extern "C" fn artichoke_matchdata_string(
@@ -233,11 +189,7 @@ unsafe extern "C" fn artichoke_matchdata_string(
     let mut interp = unwrap_interpreter!(mrb);
     let mut guard = Guard::new(&mut interp);
     let value = Value::from(slf);
-    let result = trampoline::string(&mut guard, value);
-    match result {
-        Ok(result) => result.inner(),
-        Err(exception) => exception::raise(guard, exception),
-    }
+    ffi_catch_unwind!(guard, trampoline::string(&mut guard, value))
 }
 
 unsafe extern "C" fn artichoke_matchdata_to_a(
@@ -248,11 +200,7 @@ unsafe extern "C" fn artichoke_matchdata_to_a(
     let mut interp = unwrap_interpreter!(mrb);
     let mut guard = Guard::new(&mut interp);
     let value = Value::from(slf);
-    let result = trampoline::to_a(&mut guard, value);
-    match result {
-        Ok(result) => result.inner(),
-        Err(exception) => exception::raise(guard, exception),
-    }
+    ffi_catch_unwind!(guard, trampoline::to_a(&mut guard, value))
 }
 
 unsafe extern "C" fn artichoke_matchdata_to_s(
@@ -263,9 +211,5 @@ unsafe extern "C" fn artichoke_matchdata_to_s(
     let mut interp = unwrap_interpreter!(mrb);
     let mut guard = Guard::new(&mut interp);
     let value = Value::from(slf);
-    let result = trampoline::to_s(&mut guard, value);
-    match result {
-        Ok(result) => result.inner(),
-        Err(exception) => exception::raise(guard, exception),
-    }
+    ffi_catch_unwind!(guard, trampoline::to_s(&mut guard, value))
 }
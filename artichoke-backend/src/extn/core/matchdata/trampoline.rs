@@ -1,5 +1,6 @@
 use std::convert::TryFrom;
 
+use crate::convert::StreamingHash;
 use crate::extn::core::array::Array;
 use crate::extn::core::matchdata::{Capture, CaptureAt, CaptureExtract, MatchData};
 use crate::extn::core::regexp::Regexp;
@@ -92,7 +93,11 @@ pub fn length(interp: &mut Artichoke, mut value: Value) -> Result<Value, Excepti
 pub fn named_captures(interp: &mut Artichoke, mut value: Value) -> Result<Value, Exception> {
     let data = unsafe { MatchData::unbox_from_value(&mut value, interp)? };
     let named_captures = data.named_captures()?;
-    interp.try_convert_mut(named_captures)
+    if let Some(named_captures) = named_captures {
+        interp.try_convert_mut(StreamingHash(named_captures))
+    } else {
+        Ok(Value::nil())
+    }
 }
 
 pub fn names(interp: &mut Artichoke, mut value: Value) -> Result<Value, Exception> {
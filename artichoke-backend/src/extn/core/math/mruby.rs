@@ -58,11 +58,7 @@ unsafe extern "C" fn artichoke_math_acos(
     let mut interp = unwrap_interpreter!(mrb);
     let mut guard = Guard::new(&mut interp);
     let value = Value::from(value);
-    let result = math::acos(&mut guard, value).map(|result| guard.convert_mut(result));
-    match result {
-        Ok(value) => value.inner(),
-        Err(exception) => exception::raise(guard, exception),
-    }
+    ffi_catch_unwind!(guard, math::acos(&mut guard, value).map(|result| guard.convert_mut(result)))
 }
 
 unsafe extern "C" fn artichoke_math_acosh(
@@ -73,11 +69,7 @@ unsafe extern "C" fn artichoke_math_acosh(
     let mut interp = unwrap_interpreter!(mrb);
     let mut guard = Guard::new(&mut interp);
     let value = Value::from(value);
-    let result = math::acosh(&mut guard, value).map(|result| guard.convert_mut(result));
-    match result {
-        Ok(value) => value.inner(),
-        Err(exception) => exception::raise(guard, exception),
-    }
+    ffi_catch_unwind!(guard, math::acosh(&mut guard, value).map(|result| guard.convert_mut(result)))
 }
 
 unsafe extern "C" fn artichoke_math_asin(
@@ -88,11 +80,7 @@ unsafe extern "C" fn artichoke_math_asin(
     let mut interp = unwrap_interpreter!(mrb);
     let mut guard = Guard::new(&mut interp);
     let value = Value::from(value);
-    let result = math::asin(&mut guard, value).map(|result| guard.convert_mut(result));
-    match result {
-        Ok(value) => value.inner(),
-        Err(exception) => exception::raise(guard, exception),
-    }
+    ffi_catch_unwind!(guard, math::asin(&mut guard, value).map(|result| guard.convert_mut(result)))
 }
 
 unsafe extern "C" fn artichoke_math_asinh(
@@ -103,11 +91,7 @@ unsafe extern "C" fn artichoke_math_asinh(
     let mut interp = unwrap_interpreter!(mrb);
     let mut guard = Guard::new(&mut interp);
     let value = Value::from(value);
-    let result = math::asinh(&mut guard, value).map(|result| guard.convert_mut(result));
-    match result {
-        Ok(value) => value.inner(),
-        Err(exception) => exception::raise(guard, exception),
-    }
+    ffi_catch_unwind!(guard, math::asinh(&mut guard, value).map(|result| guard.convert_mut(result)))
 }
 
 unsafe extern "C" fn artichoke_math_atan(
@@ -118,11 +102,7 @@ unsafe extern "C" fn artichoke_math_atan(
     let mut interp = unwrap_interpreter!(mrb);
     let mut guard = Guard::new(&mut interp);
     let value = Value::from(value);
-    let result = math::atan(&mut guard, value).map(|result| guard.convert_mut(result));
-    match result {
-        Ok(value) => value.inner(),
-        Err(exception) => exception::raise(guard, exception),
-    }
+    ffi_catch_unwind!(guard, math::atan(&mut guard, value).map(|result| guard.convert_mut(result)))
 }
 
 unsafe extern "C" fn artichoke_math_atan2(
@@ -134,11 +114,10 @@ unsafe extern "C" fn artichoke_math_atan2(
     let mut guard = Guard::new(&mut interp);
     let value = Value::from(value);
     let other = Value::from(other);
-    let result = math::atan2(&mut guard, value, other).map(|result| guard.convert_mut(result));
-    match result {
-        Ok(value) => value.inner(),
-        Err(exception) => exception::raise(guard, exception),
-    }
+    ffi_catch_unwind!(
+        guard,
+        math::atan2(&mut guard, value, other).map(|result| guard.convert_mut(result))
+    )
 }
 
 unsafe extern "C" fn artichoke_math_atanh(
@@ -149,11 +128,7 @@ unsafe extern "C" fn artichoke_math_atanh(
     let mut interp = unwrap_interpreter!(mrb);
     let mut guard = Guard::new(&mut interp);
     let value = Value::from(value);
-    let result = math::atanh(&mut guard, value).map(|result| guard.convert_mut(result));
-    match result {
-        Ok(value) => value.inner(),
-        Err(exception) => exception::raise(guard, exception),
-    }
+    ffi_catch_unwind!(guard, math::atanh(&mut guard, value).map(|result| guard.convert_mut(result)))
 }
 
 unsafe extern "C" fn artichoke_math_cbrt(
@@ -164,11 +139,7 @@ unsafe extern "C" fn artichoke_math_cbrt(
     let mut interp = unwrap_interpreter!(mrb);
     let mut guard = Guard::new(&mut interp);
     let value = Value::from(value);
-    let result = math::cbrt(&mut guard, value).map(|result| guard.convert_mut(result));
-    match result {
-        Ok(value) => value.inner(),
-        Err(exception) => exception::raise(guard, exception),
-    }
+    ffi_catch_unwind!(guard, math::cbrt(&mut guard, value).map(|result| guard.convert_mut(result)))
 }
 
 unsafe extern "C" fn artichoke_math_cos(
@@ -179,11 +150,7 @@ unsafe extern "C" fn artichoke_math_cos(
     let mut interp = unwrap_interpreter!(mrb);
     let mut guard = Guard::new(&mut interp);
     let value = Value::from(value);
-    let result = math::cos(&mut guard, value).map(|result| guard.convert_mut(result));
-    match result {
-        Ok(value) => value.inner(),
-        Err(exception) => exception::raise(guard, exception),
-    }
+    ffi_catch_unwind!(guard, math::cos(&mut guard, value).map(|result| guard.convert_mut(result)))
 }
 
 unsafe extern "C" fn artichoke_math_cosh(
@@ -194,11 +161,7 @@ unsafe extern "C" fn artichoke_math_cosh(
     let mut interp = unwrap_interpreter!(mrb);
     let mut guard = Guard::new(&mut interp);
     let value = Value::from(value);
-    let result = math::cosh(&mut guard, value).map(|result| guard.convert_mut(result));
-    match result {
-        Ok(value) => value.inner(),
-        Err(exception) => exception::raise(guard, exception),
-    }
+    ffi_catch_unwind!(guard, math::cosh(&mut guard, value).map(|result| guard.convert_mut(result)))
 }
 
 unsafe extern "C" fn artichoke_math_erf(
@@ -209,11 +172,7 @@ unsafe extern "C" fn artichoke_math_erf(
     let mut interp = unwrap_interpreter!(mrb);
     let mut guard = Guard::new(&mut interp);
     let value = Value::from(value);
-    let result = math::erf(&mut guard, value).map(|result| guard.convert_mut(result));
-    match result {
-        Ok(value) => value.inner(),
-        Err(exception) => exception::raise(guard, exception),
-    }
+    ffi_catch_unwind!(guard, math::erf(&mut guard, value).map(|result| guard.convert_mut(result)))
 }
 
 unsafe extern "C" fn artichoke_math_erfc(
@@ -224,11 +183,7 @@ unsafe extern "C" fn artichoke_math_erfc(
     let mut interp = unwrap_interpreter!(mrb);
     let mut guard = Guard::new(&mut interp);
     let value = Value::from(value);
-    let result = math::erfc(&mut guard, value).map(|result| guard.convert_mut(result));
-    match result {
-        Ok(value) => value.inner(),
-        Err(exception) => exception::raise(guard, exception),
-    }
+    ffi_catch_unwind!(guard, math::erfc(&mut guard, value).map(|result| guard.convert_mut(result)))
 }
 
 unsafe extern "C" fn artichoke_math_exp(
@@ -239,11 +194,7 @@ unsafe extern "C" fn artichoke_math_exp(
     let mut interp = unwrap_interpreter!(mrb);
     let mut guard = Guard::new(&mut interp);
     let value = Value::from(value);
-    let result = math::exp(&mut guard, value).map(|result| guard.convert_mut(result));
-    match result {
-        Ok(value) => value.inner(),
-        Err(exception) => exception::raise(guard, exception),
-    }
+    ffi_catch_unwind!(guard, math::exp(&mut guard, value).map(|result| guard.convert_mut(result)))
 }
 
 unsafe extern "C" fn artichoke_math_frexp(
@@ -254,15 +205,14 @@ unsafe extern "C" fn artichoke_math_frexp(
     let mut interp = unwrap_interpreter!(mrb);
     let mut guard = Guard::new(&mut interp);
     let value = Value::from(value);
-    let result = math::frexp(&mut guard, value).and_then(|(fraction, exponent)| {
-        let fraction = guard.convert_mut(fraction);
-        let exponent = guard.convert(exponent);
-        guard.try_convert_mut(&[fraction, exponent][..])
-    });
-    match result {
-        Ok(value) => value.inner(),
-        Err(exception) => exception::raise(guard, exception),
-    }
+    ffi_catch_unwind!(
+        guard,
+        math::frexp(&mut guard, value).and_then(|(fraction, exponent)| {
+            let fraction = guard.convert_mut(fraction);
+            let exponent = guard.convert(exponent);
+            guard.try_convert_mut(&[fraction, exponent][..])
+        })
+    )
 }
 
 unsafe extern "C" fn artichoke_math_gamma(
@@ -273,11 +223,7 @@ unsafe extern "C" fn artichoke_math_gamma(
     let mut interp = unwrap_interpreter!(mrb);
     let mut guard = Guard::new(&mut interp);
     let value = Value::from(value);
-    let result = math::gamma(&mut guard, value).map(|result| guard.convert_mut(result));
-    match result {
-        Ok(value) => value.inner(),
-        Err(exception) => exception::raise(guard, exception),
-    }
+    ffi_catch_unwind!(guard, math::gamma(&mut guard, value).map(|result| guard.convert_mut(result)))
 }
 
 unsafe extern "C" fn artichoke_math_hypot(
@@ -289,11 +235,10 @@ unsafe extern "C" fn artichoke_math_hypot(
     let mut guard = Guard::new(&mut interp);
     let value = Value::from(value);
     let other = Value::from(other);
-    let result = math::hypot(&mut guard, value, other).map(|result| guard.convert_mut(result));
-    match result {
-        Ok(value) => value.inner(),
-        Err(exception) => exception::raise(guard, exception),
-    }
+    ffi_catch_unwind!(
+        guard,
+        math::hypot(&mut guard, value, other).map(|result| guard.convert_mut(result))
+    )
 }
 
 unsafe extern "C" fn artichoke_math_ldexp(
@@ -305,12 +250,10 @@ unsafe extern "C" fn artichoke_math_ldexp(
     let mut guard = Guard::new(&mut interp);
     let fraction = Value::from(fraction);
     let exponent = Value::from(exponent);
-    let result =
-        math::ldexp(&mut guard, fraction, exponent).map(|result| guard.convert_mut(result));
-    match result {
-        Ok(value) => value.inner(),
-        Err(exception) => exception::raise(guard, exception),
-    }
+    ffi_catch_unwind!(
+        guard,
+        math::ldexp(&mut guard, fraction, exponent).map(|result| guard.convert_mut(result))
+    )
 }
 
 unsafe extern "C" fn artichoke_math_lgamma(
@@ -321,15 +264,14 @@ unsafe extern "C" fn artichoke_math_lgamma(
     let mut interp = unwrap_interpreter!(mrb);
     let mut guard = Guard::new(&mut interp);
     let value = Value::from(value);
-    let result = math::lgamma(&mut guard, value).and_then(|(result, sign)| {
-        let result = guard.convert_mut(result);
-        let sign = guard.convert(sign);
-        guard.try_convert_mut(&[result, sign][..])
-    });
-    match result {
-        Ok(value) => value.inner(),
-        Err(exception) => exception::raise(guard, exception),
-    }
+    ffi_catch_unwind!(
+        guard,
+        math::lgamma(&mut guard, value).and_then(|(result, sign)| {
+            let result = guard.convert_mut(result);
+            let sign = guard.convert(sign);
+            guard.try_convert_mut(&[result, sign][..])
+        })
+    )
 }
 
 unsafe extern "C" fn artichoke_math_log(
@@ -341,11 +283,10 @@ unsafe extern "C" fn artichoke_math_log(
     let mut guard = Guard::new(&mut interp);
     let value = Value::from(value);
     let base = base.map(Value::from);
-    let result = math::log(&mut guard, value, base).map(|result| guard.convert_mut(result));
-    match result {
-        Ok(value) => value.inner(),
-        Err(exception) => exception::raise(guard, exception),
-    }
+    ffi_catch_unwind!(
+        guard,
+        math::log(&mut guard, value, base).map(|result| guard.convert_mut(result))
+    )
 }
 
 unsafe extern "C" fn artichoke_math_log10(
@@ -356,11 +297,7 @@ unsafe extern "C" fn artichoke_math_log10(
     let mut interp = unwrap_interpreter!(mrb);
     let mut guard = Guard::new(&mut interp);
     let value = Value::from(value);
-    let result = math::log10(&mut guard, value).map(|result| guard.convert_mut(result));
-    match result {
-        Ok(value) => value.inner(),
-        Err(exception) => exception::raise(guard, exception),
-    }
+    ffi_catch_unwind!(guard, math::log10(&mut guard, value).map(|result| guard.convert_mut(result)))
 }
 
 unsafe extern "C" fn artichoke_math_log2(
@@ -371,11 +308,7 @@ unsafe extern "C" fn artichoke_math_log2(
     let mut interp = unwrap_interpreter!(mrb);
     let mut guard = Guard::new(&mut interp);
     let value = Value::from(value);
-    let result = math::log2(&mut guard, value).map(|result| guard.convert_mut(result));
-    match result {
-        Ok(value) => value.inner(),
-        Err(exception) => exception::raise(guard, exception),
-    }
+    ffi_catch_unwind!(guard, math::log2(&mut guard, value).map(|result| guard.convert_mut(result)))
 }
 
 unsafe extern "C" fn artichoke_math_sin(
@@ -386,11 +319,7 @@ unsafe extern "C" fn artichoke_math_sin(
     let mut interp = unwrap_interpreter!(mrb);
     let mut guard = Guard::new(&mut interp);
     let value = Value::from(value);
-    let result = math::sin(&mut guard, value).map(|result| guard.convert_mut(result));
-    match result {
-        Ok(value) => value.inner(),
-        Err(exception) => exception::raise(guard, exception),
-    }
+    ffi_catch_unwind!(guard, math::sin(&mut guard, value).map(|result| guard.convert_mut(result)))
 }
 
 unsafe extern "C" fn artichoke_math_sinh(
@@ -401,11 +330,7 @@ unsafe extern "C" fn artichoke_math_sinh(
     let mut interp = unwrap_interpreter!(mrb);
     let mut guard = Guard::new(&mut interp);
     let value = Value::from(value);
-    let result = math::sinh(&mut guard, value).map(|result| guard.convert_mut(result));
-    match result {
-        Ok(value) => value.inner(),
-        Err(exception) => exception::raise(guard, exception),
-    }
+    ffi_catch_unwind!(guard, math::sinh(&mut guard, value).map(|result| guard.convert_mut(result)))
 }
 
 unsafe extern "C" fn artichoke_math_sqrt(
@@ -416,11 +341,7 @@ unsafe extern "C" fn artichoke_math_sqrt(
     let mut interp = unwrap_interpreter!(mrb);
     let mut guard = Guard::new(&mut interp);
     let value = Value::from(value);
-    let result = math::sqrt(&mut guard, value).map(|result| guard.convert_mut(result));
-    match result {
-        Ok(value) => value.inner(),
-        Err(exception) => exception::raise(guard, exception),
-    }
+    ffi_catch_unwind!(guard, math::sqrt(&mut guard, value).map(|result| guard.convert_mut(result)))
 }
 
 unsafe extern "C" fn artichoke_math_tan(
@@ -431,11 +352,7 @@ unsafe extern "C" fn artichoke_math_tan(
     let mut interp = unwrap_interpreter!(mrb);
     let mut guard = Guard::new(&mut interp);
     let value = Value::from(value);
-    let result = math::tan(&mut guard, value).map(|result| guard.convert_mut(result));
-    match result {
-        Ok(value) => value.inner(),
-        Err(exception) => exception::raise(guard, exception),
-    }
+    ffi_catch_unwind!(guard, math::tan(&mut guard, value).map(|result| guard.convert_mut(result)))
 }
 
 unsafe extern "C" fn artichoke_math_tanh(
@@ -446,9 +363,5 @@ unsafe extern "C" fn artichoke_math_tanh(
     let mut interp = unwrap_interpreter!(mrb);
     let mut guard = Guard::new(&mut interp);
     let value = Value::from(value);
-    let result = math::tanh(&mut guard, value).map(|result| guard.convert_mut(result));
-    match result {
-        Ok(value) => value.inner(),
-        Err(exception) => exception::raise(guard, exception),
-    }
+    ffi_catch_unwind!(guard, math::tanh(&mut guard, value).map(|result| guard.convert_mut(result)))
 }
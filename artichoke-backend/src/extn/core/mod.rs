@@ -5,13 +5,16 @@ use crate::extn::prelude::*;
 pub mod array;
 pub mod artichoke;
 pub mod comparable;
+pub mod data;
 pub mod enumerable;
 pub mod enumerator;
 pub mod env;
 pub mod exception;
 pub mod float;
 pub mod hash;
+pub mod host_value;
 pub mod integer;
+pub mod io;
 pub mod kernel;
 pub mod matchdata;
 pub mod math;
@@ -19,6 +22,7 @@ pub mod method;
 pub mod module;
 pub mod numeric;
 pub mod object;
+pub mod object_space;
 pub mod proc;
 #[cfg(feature = "core-random")]
 pub mod random;
@@ -41,20 +45,26 @@ pub fn init(interp: &mut Artichoke) -> InitializeResult<()> {
     // Some `Exception`s depend on: `attr_accessor` (defined in `Module`)
     exception::init(interp)?;
     comparable::init(interp)?;
+    data::init(interp)?;
     symbol::mruby::init(interp)?;
     artichoke::init(interp)?;
+    artichoke::key_value::mruby::init(interp)?;
+    artichoke::remote_object::mruby::init(interp)?;
     enumerator::init(interp)?;
     env::mruby::init(interp)?;
     hash::init(interp)?;
+    host_value::mruby::init(interp)?;
     numeric::init(interp)?;
     integer::mruby::init(interp)?;
     float::init(interp)?;
     kernel::mruby::init(interp)?;
+    io::mruby::init(interp)?;
     matchdata::mruby::init(interp)?;
     math::mruby::init(interp)?;
     method::init(interp)?;
     module::init(interp)?;
     object::init(interp)?;
+    object_space::init(interp)?;
     proc::init(interp)?;
     #[cfg(feature = "core-random")]
     random::mruby::init(interp)?;
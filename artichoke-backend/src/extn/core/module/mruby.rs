@@ -0,0 +1,39 @@
+use crate::extn::core::module::{self, trampoline};
+use crate::extn::prelude::*;
+
+pub fn init(interp: &mut Artichoke) -> InitializeResult<()> {
+    if interp.is_class_defined::<module::Module>() {
+        return Ok(());
+    }
+    let spec = class::Spec::new("Module", None, None)?;
+    class::Builder::for_spec(interp, &spec)
+        .add_method(
+            "class_eval",
+            artichoke_module_class_eval,
+            sys::mrb_args_opt(2) | sys::mrb_args_block(),
+        )?
+        .add_method(
+            "module_eval",
+            artichoke_module_class_eval,
+            sys::mrb_args_opt(2) | sys::mrb_args_block(),
+        )?
+        .define()?;
+    interp.def_class::<module::Module>(spec)?;
+    let _ = interp.eval(&include_bytes!("module.rb")[..])?;
+    trace!("Patched Module onto interpreter");
+    Ok(())
+}
+
+#[no_mangle]
+unsafe extern "C" fn artichoke_module_class_eval(
+    mrb: *mut sys::mrb_state,
+    slf: sys::mrb_value,
+) -> sys::mrb_value {
+    let (source, file, block) = mrb_get_args!(mrb, optional = 2, &block);
+    let mut interp = unwrap_interpreter!(mrb);
+    let mut guard = Guard::new(&mut interp);
+    let module = Value::from(slf);
+    let source = source.map(Value::from);
+    let file = file.map(Value::from);
+    ffi_catch_unwind!(guard, trampoline::class_eval(&mut guard, module, source, file, block))
+}
@@ -0,0 +1,54 @@
+use std::ptr::NonNull;
+
+use crate::block::NoBlockGiven;
+use crate::extn::prelude::*;
+use crate::state::parser::Context;
+
+/// Evaluate `source` or `block` with `self` and the default
+/// method-definition class rebound to `module`, backing
+/// `Module#class_eval`/`Module#module_eval`.
+///
+/// Vendored mruby implements the block form of `class_eval`/`module_eval`
+/// natively but raises `NotImplementedError` for the string form. This
+/// reimplements the string form on top of the block form: `source` is
+/// compiled by wrapping it in a `lambda` and evaluating it as top-level Ruby,
+/// and the resulting `Proc` is then yielded with its definee rebound to
+/// `module`, exactly as the block form already is.
+pub fn class_eval(
+    interp: &mut Artichoke,
+    module: Value,
+    source: Option<Value>,
+    file: Option<Value>,
+    block: Option<Block>,
+) -> Result<Value, Exception> {
+    let target_class = NonNull::new(unsafe { sys::mrb_sys_class_ptr(module.inner()) });
+    let block = match (source, block) {
+        (Some(_), Some(_)) => {
+            let message = "wrong number of arguments (given 2, expected 0..1)";
+            return Err(ArgumentError::from(message).into());
+        }
+        (Some(mut source), None) => {
+            let source = source.implicitly_convert_to_string(interp)?.to_vec();
+            let mut code = Vec::with_capacity(source.len() + 16);
+            code.extend_from_slice(b"lambda {\n");
+            code.extend_from_slice(&source);
+            code.extend_from_slice(b"\n}");
+
+            let context = if let Some(mut file) = file {
+                let file = file.implicitly_convert_to_string(interp)?.to_vec();
+                Context::new(file).ok_or_else(|| ArgumentError::from("string contains null byte"))?
+            } else {
+                Context::new(&b"(eval)"[..]).ok_or_else(|| Fatal::from("default eval context"))?
+            };
+            interp.push_context(context)?;
+            let compiled = interp.eval(&code);
+            let _ = interp.pop_context()?;
+            Block::new(compiled?.inner()).ok_or_else(NoBlockGiven::new)?
+        }
+        (None, Some(block)) => block,
+        (None, None) => {
+            return Err(ArgumentError::from("tried to create Proc object without a block").into());
+        }
+    };
+    block.yield_with_class(interp, module, &[module], target_class)
+}
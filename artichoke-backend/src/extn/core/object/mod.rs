@@ -1,14 +1,10 @@
 use crate::extn::prelude::*;
 
+pub mod mruby;
+pub mod trampoline;
+
 pub fn init(interp: &mut Artichoke) -> InitializeResult<()> {
-    if interp.is_class_defined::<Object>() {
-        return Ok(());
-    }
-    let spec = class::Spec::new("Object", None, None)?;
-    interp.def_class::<Object>(spec)?;
-    let _ = interp.eval(&include_bytes!("object.rb")[..])?;
-    trace!("Patched Object onto interpreter");
-    Ok(())
+    mruby::init(interp)
 }
 
 #[derive(Debug)]
@@ -0,0 +1,105 @@
+use crate::extn::core::object::{self, trampoline};
+use crate::extn::prelude::*;
+
+pub fn init(interp: &mut Artichoke) -> InitializeResult<()> {
+    if interp.is_class_defined::<object::Object>() {
+        return Ok(());
+    }
+    let spec = class::Spec::new("Object", None, None)?;
+    class::Builder::for_spec(interp, &spec)
+        .add_method(
+            "instance_variable_defined?",
+            artichoke_object_instance_variable_defined,
+            sys::mrb_args_req(1),
+        )?
+        .add_method(
+            "instance_variable_get",
+            artichoke_object_instance_variable_get,
+            sys::mrb_args_req(1),
+        )?
+        .add_method(
+            "instance_variable_set",
+            artichoke_object_instance_variable_set,
+            sys::mrb_args_req(2),
+        )?
+        .add_method(
+            "instance_variables",
+            artichoke_object_instance_variables,
+            sys::mrb_args_none(),
+        )?
+        .add_method(
+            "instance_exec",
+            artichoke_object_instance_exec,
+            sys::mrb_args_rest() | sys::mrb_args_block(),
+        )?
+        .define()?;
+    interp.def_class::<object::Object>(spec)?;
+    let _ = interp.eval(&include_bytes!("object.rb")[..])?;
+    trace!("Patched Object onto interpreter");
+    Ok(())
+}
+
+#[no_mangle]
+unsafe extern "C" fn artichoke_object_instance_variables(
+    mrb: *mut sys::mrb_state,
+    slf: sys::mrb_value,
+) -> sys::mrb_value {
+    mrb_get_args!(mrb, none);
+    let mut interp = unwrap_interpreter!(mrb);
+    let mut guard = Guard::new(&mut interp);
+    let slf = Value::from(slf);
+    ffi_catch_unwind!(guard, trampoline::instance_variables(&mut guard, slf))
+}
+
+#[no_mangle]
+unsafe extern "C" fn artichoke_object_instance_exec(
+    mrb: *mut sys::mrb_state,
+    slf: sys::mrb_value,
+) -> sys::mrb_value {
+    let (args, block) = mrb_get_args!(mrb, *args, &block);
+    let mut interp = unwrap_interpreter!(mrb);
+    let mut guard = Guard::new(&mut interp);
+    let slf = Value::from(slf);
+    let args = args.iter().copied().map(Value::from);
+    ffi_catch_unwind!(guard, trampoline::instance_exec(&mut guard, slf, args, block))
+}
+
+#[no_mangle]
+unsafe extern "C" fn artichoke_object_instance_variable_get(
+    mrb: *mut sys::mrb_state,
+    slf: sys::mrb_value,
+) -> sys::mrb_value {
+    let name = mrb_get_args!(mrb, required = 1);
+    let mut interp = unwrap_interpreter!(mrb);
+    let mut guard = Guard::new(&mut interp);
+    let slf = Value::from(slf);
+    let name = Value::from(name);
+    ffi_catch_unwind!(guard, trampoline::instance_variable_get(&mut guard, slf, name))
+}
+
+#[no_mangle]
+unsafe extern "C" fn artichoke_object_instance_variable_set(
+    mrb: *mut sys::mrb_state,
+    slf: sys::mrb_value,
+) -> sys::mrb_value {
+    let (name, value) = mrb_get_args!(mrb, required = 2);
+    let mut interp = unwrap_interpreter!(mrb);
+    let mut guard = Guard::new(&mut interp);
+    let slf = Value::from(slf);
+    let name = Value::from(name);
+    let value = Value::from(value);
+    ffi_catch_unwind!(guard, trampoline::instance_variable_set(&mut guard, slf, name, value))
+}
+
+#[no_mangle]
+unsafe extern "C" fn artichoke_object_instance_variable_defined(
+    mrb: *mut sys::mrb_state,
+    slf: sys::mrb_value,
+) -> sys::mrb_value {
+    let name = mrb_get_args!(mrb, required = 1);
+    let mut interp = unwrap_interpreter!(mrb);
+    let mut guard = Guard::new(&mut interp);
+    let slf = Value::from(slf);
+    let name = Value::from(name);
+    ffi_catch_unwind!(guard, trampoline::instance_variable_defined(&mut guard, slf, name))
+}
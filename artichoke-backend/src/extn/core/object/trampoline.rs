@@ -0,0 +1,106 @@
+use std::ptr::NonNull;
+
+use crate::block::{Block, NoBlockGiven};
+use crate::extn::core::exception::NameError;
+use crate::extn::prelude::*;
+use crate::intern::Symbol;
+use crate::types::Ruby;
+
+/// Validate that `name` looks like `@ivar`: a single leading `@` followed by
+/// an identifier. This rejects class variable names (`@@cvar`) and anything
+/// that isn't a valid identifier, matching the names `remove_instance_variable`
+/// already accepts.
+fn is_valid_instance_variable_name(name: &[u8]) -> bool {
+    match name.split_first() {
+        Some((b'@', rest)) => match rest.split_first() {
+            Some((&first, remainder)) if first == b'_' || first.is_ascii_alphabetic() => remainder
+                .iter()
+                .all(|&byte| byte == b'_' || byte.is_ascii_alphanumeric()),
+            _ => false,
+        },
+        _ => false,
+    }
+}
+
+fn ivar_symbol(interp: &mut Artichoke, mut name: Value) -> Result<Symbol, Exception> {
+    let name = name.implicitly_convert_to_string(interp)?.to_vec();
+    if !is_valid_instance_variable_name(&name) {
+        let mut message = String::from("'");
+        message.push_str(&String::from_utf8_lossy(&name));
+        message.push_str("' is not allowed as an instance variable name");
+        return Err(NameError::from(message).into());
+    }
+    interp.intern_bytes(name)
+}
+
+pub fn instance_variables(interp: &mut Artichoke, value: Value) -> Result<Value, Exception> {
+    let variables = unsafe {
+        interp.with_ffi_boundary(|mrb| sys::mrb_obj_instance_variables(mrb, value.inner()))
+    }?;
+    Ok(Value::from(variables))
+}
+
+pub fn instance_variable_get(
+    interp: &mut Artichoke,
+    value: Value,
+    name: Value,
+) -> Result<Value, Exception> {
+    let sym = ivar_symbol(interp, name)?;
+    let result = unsafe {
+        interp.with_ffi_boundary(|mrb| sys::mrb_iv_get(mrb, value.inner(), sym.into()))
+    }?;
+    Ok(Value::from(result))
+}
+
+pub fn instance_variable_set(
+    interp: &mut Artichoke,
+    value: Value,
+    name: Value,
+    set_to: Value,
+) -> Result<Value, Exception> {
+    let sym = ivar_symbol(interp, name)?;
+    unsafe {
+        interp.with_ffi_boundary(|mrb| {
+            sys::mrb_iv_set(mrb, value.inner(), sym.into(), set_to.inner());
+        })?;
+    }
+    Ok(set_to)
+}
+
+pub fn instance_exec<T>(
+    interp: &mut Artichoke,
+    value: Value,
+    args: T,
+    block: Option<Block>,
+) -> Result<Value, Exception>
+where
+    T: IntoIterator<Item = Value>,
+{
+    let block = block.ok_or_else(NoBlockGiven::new)?;
+    let args = args.into_iter().collect::<Vec<_>>();
+    // `Fixnum`/`Symbol`/`Float` have no real singleton class to rebind the
+    // definee to, so leave the block's own target class in place for them,
+    // matching `mrb_obj_instance_exec` in vendored mruby.
+    let target_class = match value.ruby_type() {
+        Ruby::Fixnum | Ruby::Symbol | Ruby::Float => None,
+        _ => {
+            let singleton_class = unsafe {
+                interp.with_ffi_boundary(|mrb| sys::mrb_singleton_class(mrb, value.inner()))
+            }?;
+            NonNull::new(unsafe { sys::mrb_sys_class_ptr(singleton_class) })
+        }
+    };
+    block.yield_with_class(interp, value, &args, target_class)
+}
+
+pub fn instance_variable_defined(
+    interp: &mut Artichoke,
+    value: Value,
+    name: Value,
+) -> Result<Value, Exception> {
+    let sym = ivar_symbol(interp, name)?;
+    let defined = unsafe {
+        interp.with_ffi_boundary(|mrb| sys::mrb_iv_defined(mrb, value.inner(), sym.into()))
+    }?;
+    Ok(interp.convert(defined != 0))
+}
@@ -0,0 +1,16 @@
+use crate::extn::prelude::*;
+
+pub fn init(interp: &mut Artichoke) -> InitializeResult<()> {
+    if interp.is_module_defined::<ObjectSpace>() {
+        return Ok(());
+    }
+    let spec = module::Spec::new(interp, "ObjectSpace", None)?;
+    module::Builder::for_spec(interp, &spec).define()?;
+    interp.def_module::<ObjectSpace>(spec)?;
+    let _ = interp.eval(&include_bytes!("object_space.rb")[..])?;
+    trace!("Patched ObjectSpace onto interpreter");
+    Ok(())
+}
+
+#[derive(Debug)]
+pub struct ObjectSpace;
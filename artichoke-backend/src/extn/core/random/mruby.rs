@@ -50,11 +50,7 @@ unsafe extern "C" fn artichoke_random_initialize(
     let mut guard = Guard::new(&mut interp);
     let slf = Value::from(slf);
     let seed = seed.map(Value::from);
-    let result = trampoline::initialize(&mut guard, seed, slf);
-    match result {
-        Ok(value) => value.inner(),
-        Err(exception) => exception::raise(guard, exception),
-    }
+    ffi_catch_unwind!(guard, trampoline::initialize(&mut guard, seed, slf))
 }
 
 #[no_mangle]
@@ -67,11 +63,7 @@ unsafe extern "C" fn artichoke_random_eq(
     let mut guard = Guard::new(&mut interp);
     let rand = Value::from(slf);
     let other = Value::from(other);
-    let result = trampoline::equal(&mut guard, rand, other);
-    match result {
-        Ok(value) => value.inner(),
-        Err(exception) => exception::raise(guard, exception),
-    }
+    ffi_catch_unwind!(guard, trampoline::equal(&mut guard, rand, other))
 }
 
 #[no_mangle]
@@ -84,11 +76,7 @@ unsafe extern "C" fn artichoke_random_bytes(
     let mut guard = Guard::new(&mut interp);
     let rand = Value::from(slf);
     let size = Value::from(size);
-    let result = trampoline::bytes(&mut guard, rand, size);
-    match result {
-        Ok(value) => value.inner(),
-        Err(exception) => exception::raise(guard, exception),
-    }
+    ffi_catch_unwind!(guard, trampoline::bytes(&mut guard, rand, size))
 }
 
 #[no_mangle]
@@ -101,11 +89,7 @@ unsafe extern "C" fn artichoke_random_rand(
     let mut guard = Guard::new(&mut interp);
     let rand = Value::from(slf);
     let max = max.map(Value::from);
-    let result = trampoline::rand(&mut guard, rand, max);
-    match result {
-        Ok(value) => value.inner(),
-        Err(exception) => exception::raise(guard, exception),
-    }
+    ffi_catch_unwind!(guard, trampoline::rand(&mut guard, rand, max))
 }
 
 #[no_mangle]
@@ -117,11 +101,7 @@ unsafe extern "C" fn artichoke_random_seed(
     let mut interp = unwrap_interpreter!(mrb);
     let mut guard = Guard::new(&mut interp);
     let rand = Value::from(slf);
-    let result = trampoline::seed(&mut guard, rand);
-    match result {
-        Ok(value) => value.inner(),
-        Err(exception) => exception::raise(guard, exception),
-    }
+    ffi_catch_unwind!(guard, trampoline::seed(&mut guard, rand))
 }
 
 #[no_mangle]
@@ -132,11 +112,7 @@ unsafe extern "C" fn artichoke_random_self_new_seed(
     mrb_get_args!(mrb, none);
     let mut interp = unwrap_interpreter!(mrb);
     let mut guard = Guard::new(&mut interp);
-    let result = trampoline::new_seed(&mut guard);
-    match result {
-        Ok(value) => value.inner(),
-        Err(exception) => exception::raise(guard, exception),
-    }
+    ffi_catch_unwind!(guard, trampoline::new_seed(&mut guard))
 }
 
 #[no_mangle]
@@ -148,11 +124,7 @@ unsafe extern "C" fn artichoke_random_self_srand(
     let mut interp = unwrap_interpreter!(mrb);
     let mut guard = Guard::new(&mut interp);
     let number = number.map(Value::from);
-    let result = trampoline::srand(&mut guard, number);
-    match result {
-        Ok(value) => value.inner(),
-        Err(exception) => exception::raise(guard, exception),
-    }
+    ffi_catch_unwind!(guard, trampoline::srand(&mut guard, number))
 }
 
 #[no_mangle]
@@ -164,9 +136,5 @@ unsafe extern "C" fn artichoke_random_self_urandom(
     let mut interp = unwrap_interpreter!(mrb);
     let mut guard = Guard::new(&mut interp);
     let size = Value::from(size);
-    let result = trampoline::urandom(&mut guard, size);
-    match result {
-        Ok(value) => value.inner(),
-        Err(exception) => exception::raise(guard, exception),
-    }
+    ffi_catch_unwind!(guard, trampoline::urandom(&mut guard, size))
 }
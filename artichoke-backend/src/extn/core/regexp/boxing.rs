@@ -1,6 +1,18 @@
-use crate::convert::HeapAllocatedData;
+use crate::convert::{CloneBehavior, HeapAllocatedData};
+use crate::exception::Exception;
 use crate::extn::core::regexp::Regexp;
+use crate::Artichoke;
 
 impl HeapAllocatedData for Regexp {
     const RUBY_TYPE: &'static str = "Regexp";
 }
+
+impl CloneBehavior for Regexp {
+    /// `Regexp` is immutable, so `dup`/`clone` deep copy the underlying
+    /// [`RegexpType`](super::backend::RegexpType) backend via its existing
+    /// [`Clone`] impl.
+    fn clone_for_dup(&self, interp: &mut Artichoke) -> Result<Self, Exception> {
+        let _ = interp;
+        Ok(self.clone())
+    }
+}
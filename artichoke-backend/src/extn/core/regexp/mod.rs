@@ -12,7 +12,6 @@ use std::hash::{Hash, Hasher};
 use std::num::NonZeroUsize;
 use std::str;
 
-use crate::extn::core::array::Array;
 use crate::extn::prelude::*;
 
 pub mod backend;
@@ -225,9 +224,9 @@ impl Regexp {
                     patterns.push(extract_pattern(interp, &mut value)?);
                 }
                 bstr::join(b"|", patterns)
-            } else if let Ok(ary) = unsafe { Array::unbox_from_value(&mut first, interp) } {
+            } else if let Ok(ary) = first.implicitly_convert_to_array(interp) {
                 let mut patterns = Vec::with_capacity(ary.len());
-                for mut value in &*ary {
+                for mut value in ary {
                     patterns.push(extract_pattern(interp, &mut value)?);
                 }
                 bstr::join(b"|", patterns)
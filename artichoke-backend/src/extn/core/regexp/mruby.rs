@@ -23,6 +23,7 @@ pub fn init(interp: &mut Artichoke) -> InitializeResult<()> {
         .add_method("eql?", eql, sys::mrb_args_req(1))?
         .add_method("fixed_encoding?", fixed_encoding, sys::mrb_args_none())?
         .add_method("hash", hash, sys::mrb_args_none())?
+        .add_method("initialize_copy", initialize_copy, sys::mrb_args_req(1))?
         .add_method("inspect", inspect, sys::mrb_args_none())?
         .add_method("match?", match_q, sys::mrb_args_req_and_opt(1, 1))?
         .add_method("named_captures", named_captures, sys::mrb_args_none())?
@@ -56,11 +57,22 @@ unsafe extern "C" fn initialize(mrb: *mut sys::mrb_state, slf: sys::mrb_value) -
     let pattern = Value::from(pattern);
     let options = options.map(Value::from);
     let encoding = encoding.map(Value::from);
-    let result = regexp::trampoline::initialize(&mut guard, pattern, options, encoding, slf);
-    match result {
-        Ok(value) => value.inner(),
-        Err(exception) => exception::raise(guard, exception),
-    }
+    ffi_catch_unwind!(
+        guard,
+        regexp::trampoline::initialize(&mut guard, pattern, options, encoding, slf)
+    )
+}
+
+unsafe extern "C" fn initialize_copy(
+    mrb: *mut sys::mrb_state,
+    slf: sys::mrb_value,
+) -> sys::mrb_value {
+    let from = mrb_get_args!(mrb, required = 1);
+    let mut interp = unwrap_interpreter!(mrb);
+    let mut guard = Guard::new(&mut interp);
+    let into = Value::from(slf);
+    let from = Value::from(from);
+    ffi_catch_unwind!(guard, regexp::trampoline::initialize_copy(&mut guard, into, from))
 }
 
 unsafe extern "C" fn compile(mrb: *mut sys::mrb_state, slf: sys::mrb_value) -> sys::mrb_value {
@@ -80,11 +92,7 @@ unsafe extern "C" fn escape(mrb: *mut sys::mrb_state, _slf: sys::mrb_value) -> s
     let mut interp = unwrap_interpreter!(mrb);
     let mut guard = Guard::new(&mut interp);
     let pattern = Value::from(pattern);
-    let result = regexp::trampoline::escape(&mut guard, pattern);
-    match result {
-        Ok(result) => result.inner(),
-        Err(exception) => exception::raise(guard, exception),
-    }
+    ffi_catch_unwind!(guard, regexp::trampoline::escape(&mut guard, pattern))
 }
 
 unsafe extern "C" fn union(mrb: *mut sys::mrb_state, _slf: sys::mrb_value) -> sys::mrb_value {
@@ -92,11 +100,7 @@ unsafe extern "C" fn union(mrb: *mut sys::mrb_state, _slf: sys::mrb_value) -> sy
     let mut interp = unwrap_interpreter!(mrb);
     let mut guard = Guard::new(&mut interp);
     let args = args.iter().copied().map(Value::from);
-    let result = regexp::trampoline::union(&mut guard, args);
-    match result {
-        Ok(result) => result.inner(),
-        Err(exception) => exception::raise(guard, exception),
-    }
+    ffi_catch_unwind!(guard, regexp::trampoline::union(&mut guard, args))
 }
 
 unsafe extern "C" fn match_q(mrb: *mut sys::mrb_state, slf: sys::mrb_value) -> sys::mrb_value {
@@ -106,11 +110,7 @@ unsafe extern "C" fn match_q(mrb: *mut sys::mrb_state, slf: sys::mrb_value) -> s
     let value = Value::from(slf);
     let pattern = Value::from(pattern);
     let pos = pos.map(Value::from);
-    let result = regexp::trampoline::is_match(&mut guard, value, pattern, pos);
-    match result {
-        Ok(result) => result.inner(),
-        Err(exception) => exception::raise(guard, exception),
-    }
+    ffi_catch_unwind!(guard, regexp::trampoline::is_match(&mut guard, value, pattern, pos))
 }
 
 unsafe extern "C" fn match_(mrb: *mut sys::mrb_state, slf: sys::mrb_value) -> sys::mrb_value {
@@ -120,11 +120,7 @@ unsafe extern "C" fn match_(mrb: *mut sys::mrb_state, slf: sys::mrb_value) -> sy
     let value = Value::from(slf);
     let pattern = Value::from(pattern);
     let pos = pos.map(Value::from);
-    let result = regexp::trampoline::match_(&mut guard, value, pattern, pos, block);
-    match result {
-        Ok(result) => result.inner(),
-        Err(exception) => exception::raise(guard, exception),
-    }
+    ffi_catch_unwind!(guard, regexp::trampoline::match_(&mut guard, value, pattern, pos, block))
 }
 
 unsafe extern "C" fn eql(mrb: *mut sys::mrb_state, slf: sys::mrb_value) -> sys::mrb_value {
@@ -133,11 +129,7 @@ unsafe extern "C" fn eql(mrb: *mut sys::mrb_state, slf: sys::mrb_value) -> sys::
     let mut guard = Guard::new(&mut interp);
     let value = Value::from(slf);
     let other = Value::from(other);
-    let result = regexp::trampoline::eql(&mut guard, value, other);
-    match result {
-        Ok(result) => result.inner(),
-        Err(exception) => exception::raise(guard, exception),
-    }
+    ffi_catch_unwind!(guard, regexp::trampoline::eql(&mut guard, value, other))
 }
 
 unsafe extern "C" fn case_compare(mrb: *mut sys::mrb_state, slf: sys::mrb_value) -> sys::mrb_value {
@@ -146,11 +138,7 @@ unsafe extern "C" fn case_compare(mrb: *mut sys::mrb_state, slf: sys::mrb_value)
     let mut guard = Guard::new(&mut interp);
     let value = Value::from(slf);
     let pattern = Value::from(pattern);
-    let result = regexp::trampoline::case_compare(&mut guard, value, pattern);
-    match result {
-        Ok(result) => result.inner(),
-        Err(exception) => exception::raise(guard, exception),
-    }
+    ffi_catch_unwind!(guard, regexp::trampoline::case_compare(&mut guard, value, pattern))
 }
 
 unsafe extern "C" fn match_operator(
@@ -162,11 +150,7 @@ unsafe extern "C" fn match_operator(
     let mut guard = Guard::new(&mut interp);
     let value = Value::from(slf);
     let pattern = Value::from(pattern);
-    let result = regexp::trampoline::match_operator(&mut guard, value, pattern);
-    match result {
-        Ok(result) => result.inner(),
-        Err(exception) => exception::raise(guard, exception),
-    }
+    ffi_catch_unwind!(guard, regexp::trampoline::match_operator(&mut guard, value, pattern))
 }
 
 unsafe extern "C" fn casefold(mrb: *mut sys::mrb_state, slf: sys::mrb_value) -> sys::mrb_value {
@@ -174,11 +158,7 @@ unsafe extern "C" fn casefold(mrb: *mut sys::mrb_state, slf: sys::mrb_value) ->
     let mut interp = unwrap_interpreter!(mrb);
     let mut guard = Guard::new(&mut interp);
     let value = Value::from(slf);
-    let result = regexp::trampoline::is_casefold(&mut guard, value);
-    match result {
-        Ok(result) => result.inner(),
-        Err(exception) => exception::raise(guard, exception),
-    }
+    ffi_catch_unwind!(guard, regexp::trampoline::is_casefold(&mut guard, value))
 }
 
 unsafe extern "C" fn fixed_encoding(
@@ -189,11 +169,7 @@ unsafe extern "C" fn fixed_encoding(
     let mut interp = unwrap_interpreter!(mrb);
     let mut guard = Guard::new(&mut interp);
     let value = Value::from(slf);
-    let result = regexp::trampoline::is_fixed_encoding(&mut guard, value);
-    match result {
-        Ok(result) => result.inner(),
-        Err(exception) => exception::raise(guard, exception),
-    }
+    ffi_catch_unwind!(guard, regexp::trampoline::is_fixed_encoding(&mut guard, value))
 }
 
 unsafe extern "C" fn hash(mrb: *mut sys::mrb_state, slf: sys::mrb_value) -> sys::mrb_value {
@@ -201,11 +177,7 @@ unsafe extern "C" fn hash(mrb: *mut sys::mrb_state, slf: sys::mrb_value) -> sys:
     let mut interp = unwrap_interpreter!(mrb);
     let mut guard = Guard::new(&mut interp);
     let value = Value::from(slf);
-    let result = regexp::trampoline::hash(&mut guard, value);
-    match result {
-        Ok(result) => result.inner(),
-        Err(exception) => exception::raise(guard, exception),
-    }
+    ffi_catch_unwind!(guard, regexp::trampoline::hash(&mut guard, value))
 }
 
 unsafe extern "C" fn inspect(mrb: *mut sys::mrb_state, slf: sys::mrb_value) -> sys::mrb_value {
@@ -213,11 +185,7 @@ unsafe extern "C" fn inspect(mrb: *mut sys::mrb_state, slf: sys::mrb_value) -> s
     let mut interp = unwrap_interpreter!(mrb);
     let mut guard = Guard::new(&mut interp);
     let value = Value::from(slf);
-    let result = regexp::trampoline::inspect(&mut guard, value);
-    match result {
-        Ok(result) => result.inner(),
-        Err(exception) => exception::raise(guard, exception),
-    }
+    ffi_catch_unwind!(guard, regexp::trampoline::inspect(&mut guard, value))
 }
 
 unsafe extern "C" fn named_captures(
@@ -228,11 +196,7 @@ unsafe extern "C" fn named_captures(
     let mut interp = unwrap_interpreter!(mrb);
     let mut guard = Guard::new(&mut interp);
     let value = Value::from(slf);
-    let result = regexp::trampoline::named_captures(&mut guard, value);
-    match result {
-        Ok(result) => result.inner(),
-        Err(exception) => exception::raise(guard, exception),
-    }
+    ffi_catch_unwind!(guard, regexp::trampoline::named_captures(&mut guard, value))
 }
 
 unsafe extern "C" fn names(mrb: *mut sys::mrb_state, slf: sys::mrb_value) -> sys::mrb_value {
@@ -240,11 +204,7 @@ unsafe extern "C" fn names(mrb: *mut sys::mrb_state, slf: sys::mrb_value) -> sys
     let mut interp = unwrap_interpreter!(mrb);
     let mut guard = Guard::new(&mut interp);
     let value = Value::from(slf);
-    let result = regexp::trampoline::names(&mut guard, value);
-    match result {
-        Ok(result) => result.inner(),
-        Err(exception) => exception::raise(guard, exception),
-    }
+    ffi_catch_unwind!(guard, regexp::trampoline::names(&mut guard, value))
 }
 
 unsafe extern "C" fn options(mrb: *mut sys::mrb_state, slf: sys::mrb_value) -> sys::mrb_value {
@@ -252,11 +212,7 @@ unsafe extern "C" fn options(mrb: *mut sys::mrb_state, slf: sys::mrb_value) -> s
     let mut interp = unwrap_interpreter!(mrb);
     let mut guard = Guard::new(&mut interp);
     let value = Value::from(slf);
-    let result = regexp::trampoline::options(&mut guard, value);
-    match result {
-        Ok(result) => result.inner(),
-        Err(exception) => exception::raise(guard, exception),
-    }
+    ffi_catch_unwind!(guard, regexp::trampoline::options(&mut guard, value))
 }
 
 unsafe extern "C" fn source(mrb: *mut sys::mrb_state, slf: sys::mrb_value) -> sys::mrb_value {
@@ -264,11 +220,7 @@ unsafe extern "C" fn source(mrb: *mut sys::mrb_state, slf: sys::mrb_value) -> sy
     let mut interp = unwrap_interpreter!(mrb);
     let mut guard = Guard::new(&mut interp);
     let value = Value::from(slf);
-    let result = regexp::trampoline::source(&mut guard, value);
-    match result {
-        Ok(result) => result.inner(),
-        Err(exception) => exception::raise(guard, exception),
-    }
+    ffi_catch_unwind!(guard, regexp::trampoline::source(&mut guard, value))
 }
 
 unsafe extern "C" fn to_s(mrb: *mut sys::mrb_state, slf: sys::mrb_value) -> sys::mrb_value {
@@ -276,9 +228,5 @@ unsafe extern "C" fn to_s(mrb: *mut sys::mrb_state, slf: sys::mrb_value) -> sys:
     let mut interp = unwrap_interpreter!(mrb);
     let mut guard = Guard::new(&mut interp);
     let value = Value::from(slf);
-    let result = regexp::trampoline::to_s(&mut guard, value);
-    match result {
-        Ok(result) => result.inner(),
-        Err(exception) => exception::raise(guard, exception),
-    }
+    ffi_catch_unwind!(guard, regexp::trampoline::to_s(&mut guard, value))
 }
@@ -1,5 +1,6 @@
 use std::convert::TryFrom;
 
+use crate::convert::{init_copy, StreamingHash};
 use crate::extn::core::regexp::Regexp;
 use crate::extn::prelude::*;
 
@@ -15,6 +16,14 @@ pub fn initialize(
     Regexp::box_into_value(regexp, into, interp)
 }
 
+pub fn initialize_copy(
+    interp: &mut Artichoke,
+    into: Value,
+    from: Value,
+) -> Result<Value, Exception> {
+    init_copy::<Regexp>(interp, into, from)
+}
+
 pub fn escape(interp: &mut Artichoke, mut pattern: Value) -> Result<Value, Exception> {
     let pattern = pattern.implicitly_convert_to_string(interp)?;
     let pattern = Regexp::escape(pattern)?;
@@ -122,7 +131,7 @@ pub fn inspect(interp: &mut Artichoke, mut regexp: Value) -> Result<Value, Excep
 pub fn named_captures(interp: &mut Artichoke, mut regexp: Value) -> Result<Value, Exception> {
     let regexp = unsafe { Regexp::unbox_from_value(&mut regexp, interp)? };
     let named_captures = regexp.named_captures()?;
-    interp.try_convert_mut(named_captures)
+    interp.try_convert_mut(StreamingHash(named_captures))
 }
 
 pub fn names(interp: &mut Artichoke, mut regexp: Value) -> Result<Value, Exception> {
@@ -1,6 +1,17 @@
 pub mod mruby;
 pub mod trampoline;
 
+// `String` has no Rust-owned storage to give a rope-like or pre-sized
+// builder to -- unlike `Array`, which boxes a `spinoso_array::Array` onto
+// the heap, this is a bare marker type and every `String` method is either
+// a native mruby C intrinsic or pure Ruby calling those intrinsics. The
+// concatenation primitive those intrinsics funnel through, `mrb_str_cat`
+// (vendor/mruby/src/string.c), already grows its backing buffer
+// geometrically, and `sprintf` (vendor/mruby/mrbgems/mruby-sprintf)
+// pre-sizes its result with `mrb_str_new_capa` before formatting, so
+// `format`/`inspect`/`join`-style concatenation is already amortized
+// linear, not quadratic. There also isn't a Ruby-level capacity-hint
+// constructor in this mruby to plumb hints through even if we wanted one.
 #[derive(Debug)]
 pub struct String;
 
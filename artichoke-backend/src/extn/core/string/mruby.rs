@@ -23,11 +23,7 @@ unsafe extern "C" fn artichoke_string_ord(
     let mut interp = unwrap_interpreter!(mrb);
     let mut guard = Guard::new(&mut interp);
     let value = Value::from(slf);
-    let result = trampoline::ord(&mut guard, value);
-    match result {
-        Ok(value) => value.inner(),
-        Err(exception) => exception::raise(guard, exception),
-    }
+    ffi_catch_unwind!(guard, trampoline::ord(&mut guard, value))
 }
 
 unsafe extern "C" fn artichoke_string_scan(
@@ -39,9 +35,5 @@ unsafe extern "C" fn artichoke_string_scan(
     let mut guard = Guard::new(&mut interp);
     let value = Value::from(slf);
     let pattern = Value::from(pattern);
-    let result = trampoline::scan(&mut guard, value, pattern, block);
-    match result {
-        Ok(result) => result.inner(),
-        Err(exception) => exception::raise(guard, exception),
-    }
+    ffi_catch_unwind!(guard, trampoline::scan(&mut guard, value, pattern, block))
 }
@@ -31,11 +31,7 @@ unsafe extern "C" fn artichoke_symbol_all_symbols(
     mrb_get_args!(mrb, none);
     let mut interp = unwrap_interpreter!(mrb);
     let mut guard = Guard::new(&mut interp);
-    let result = trampoline::all_symbols(&mut guard);
-    match result {
-        Ok(value) => value.inner(),
-        Err(exception) => exception::raise(guard, exception),
-    }
+    ffi_catch_unwind!(guard, trampoline::all_symbols(&mut guard))
 }
 
 #[no_mangle]
@@ -48,11 +44,7 @@ unsafe extern "C" fn artichoke_symbol_equal_equal(
     let mut guard = Guard::new(&mut interp);
     let sym = Value::from(slf);
     let other = Value::from(other);
-    let result = trampoline::equal_equal(&mut guard, sym, other);
-    match result {
-        Ok(value) => value.inner(),
-        Err(exception) => exception::raise(guard, exception),
-    }
+    ffi_catch_unwind!(guard, trampoline::equal_equal(&mut guard, sym, other))
 }
 
 #[no_mangle]
@@ -64,11 +56,7 @@ unsafe extern "C" fn artichoke_symbol_empty(
     let mut interp = unwrap_interpreter!(mrb);
     let mut guard = Guard::new(&mut interp);
     let sym = Value::from(slf);
-    let result = trampoline::is_empty(&mut guard, sym);
-    match result {
-        Ok(value) => value.inner(),
-        Err(exception) => exception::raise(guard, exception),
-    }
+    ffi_catch_unwind!(guard, trampoline::is_empty(&mut guard, sym))
 }
 
 #[no_mangle]
@@ -80,11 +68,7 @@ unsafe extern "C" fn artichoke_symbol_length(
     let mut interp = unwrap_interpreter!(mrb);
     let mut guard = Guard::new(&mut interp);
     let sym = Value::from(slf);
-    let result = trampoline::length(&mut guard, sym);
-    match result {
-        Ok(value) => value.inner(),
-        Err(exception) => exception::raise(guard, exception),
-    }
+    ffi_catch_unwind!(guard, trampoline::length(&mut guard, sym))
 }
 
 #[no_mangle]
@@ -96,9 +80,5 @@ unsafe extern "C" fn artichoke_symbol_to_s(
     let mut interp = unwrap_interpreter!(mrb);
     let mut guard = Guard::new(&mut interp);
     let sym = Value::from(slf);
-    let result = trampoline::bytes(&mut guard, sym);
-    match result {
-        Ok(value) => value.inner(),
-        Err(exception) => exception::raise(guard, exception),
-    }
+    ffi_catch_unwind!(guard, trampoline::bytes(&mut guard, sym))
 }
@@ -17,7 +17,7 @@ impl<T> Chrono<T>
 where
     T: TimeZone,
 {
-    fn new(time: DateTime<T>) -> Self {
+    pub(crate) fn new(time: DateTime<T>) -> Self {
         Self(time)
     }
 }
@@ -1,4 +1,4 @@
-use chrono::Local;
+use chrono::{DateTime, Local};
 use std::fmt;
 
 pub mod backend;
@@ -42,6 +42,18 @@ impl Time {
         Self(Box::new(Factory.now()))
     }
 
+    /// Construct a `Time` from a local wall-clock instant.
+    ///
+    /// Used by [`Artichoke::Test`](crate::extn::core::artichoke::test)'s
+    /// `freeze_time`/`travel_to` helpers (behind the `artichoke-test`
+    /// feature) and by [`replay`](crate::replay) to materialize a `Time` for
+    /// a clock override or a replayed clock read without going through
+    /// `Time::now`.
+    #[must_use]
+    pub fn from_datetime(datetime: DateTime<Local>) -> Self {
+        Self(Box::new(Chrono::new(datetime)))
+    }
+
     #[must_use]
     pub fn inner(&self) -> &dyn TimeType {
         self.0.as_ref()
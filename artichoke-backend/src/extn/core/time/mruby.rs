@@ -58,11 +58,7 @@ unsafe extern "C" fn artichoke_time_self_now(
     mrb_get_args!(mrb, none);
     let mut interp = unwrap_interpreter!(mrb);
     let mut guard = Guard::new(&mut interp);
-    let result = trampoline::now(&mut guard);
-    match result {
-        Ok(value) => value.inner(),
-        Err(exception) => exception::raise(guard, exception),
-    }
+    ffi_catch_unwind!(guard, trampoline::now(&mut guard))
 }
 
 #[no_mangle]
@@ -74,11 +70,7 @@ unsafe extern "C" fn artichoke_time_day(
     let mut interp = unwrap_interpreter!(mrb);
     let mut guard = Guard::new(&mut interp);
     let time = Value::from(slf);
-    let result = trampoline::day(&mut guard, time);
-    match result {
-        Ok(value) => value.inner(),
-        Err(exception) => exception::raise(guard, exception),
-    }
+    ffi_catch_unwind!(guard, trampoline::day(&mut guard, time))
 }
 
 #[no_mangle]
@@ -90,11 +82,7 @@ unsafe extern "C" fn artichoke_time_hour(
     let mut interp = unwrap_interpreter!(mrb);
     let mut guard = Guard::new(&mut interp);
     let time = Value::from(slf);
-    let result = trampoline::hour(&mut guard, time);
-    match result {
-        Ok(value) => value.inner(),
-        Err(exception) => exception::raise(guard, exception),
-    }
+    ffi_catch_unwind!(guard, trampoline::hour(&mut guard, time))
 }
 
 #[no_mangle]
@@ -106,11 +94,7 @@ unsafe extern "C" fn artichoke_time_minute(
     let mut interp = unwrap_interpreter!(mrb);
     let mut guard = Guard::new(&mut interp);
     let time = Value::from(slf);
-    let result = trampoline::minute(&mut guard, time);
-    match result {
-        Ok(value) => value.inner(),
-        Err(exception) => exception::raise(guard, exception),
-    }
+    ffi_catch_unwind!(guard, trampoline::minute(&mut guard, time))
 }
 
 #[no_mangle]
@@ -122,11 +106,7 @@ unsafe extern "C" fn artichoke_time_month(
     let mut interp = unwrap_interpreter!(mrb);
     let mut guard = Guard::new(&mut interp);
     let time = Value::from(slf);
-    let result = trampoline::month(&mut guard, time);
-    match result {
-        Ok(value) => value.inner(),
-        Err(exception) => exception::raise(guard, exception),
-    }
+    ffi_catch_unwind!(guard, trampoline::month(&mut guard, time))
 }
 
 #[no_mangle]
@@ -138,11 +118,7 @@ unsafe extern "C" fn artichoke_time_nanosecond(
     let mut interp = unwrap_interpreter!(mrb);
     let mut guard = Guard::new(&mut interp);
     let time = Value::from(slf);
-    let result = trampoline::nanosecond(&mut guard, time);
-    match result {
-        Ok(value) => value.inner(),
-        Err(exception) => exception::raise(guard, exception),
-    }
+    ffi_catch_unwind!(guard, trampoline::nanosecond(&mut guard, time))
 }
 
 #[no_mangle]
@@ -154,11 +130,7 @@ unsafe extern "C" fn artichoke_time_second(
     let mut interp = unwrap_interpreter!(mrb);
     let mut guard = Guard::new(&mut interp);
     let time = Value::from(slf);
-    let result = trampoline::second(&mut guard, time);
-    match result {
-        Ok(value) => value.inner(),
-        Err(exception) => exception::raise(guard, exception),
-    }
+    ffi_catch_unwind!(guard, trampoline::second(&mut guard, time))
 }
 
 #[no_mangle]
@@ -170,11 +142,7 @@ unsafe extern "C" fn artichoke_time_microsecond(
     let mut interp = unwrap_interpreter!(mrb);
     let mut guard = Guard::new(&mut interp);
     let time = Value::from(slf);
-    let result = trampoline::microsecond(&mut guard, time);
-    match result {
-        Ok(value) => value.inner(),
-        Err(exception) => exception::raise(guard, exception),
-    }
+    ffi_catch_unwind!(guard, trampoline::microsecond(&mut guard, time))
 }
 
 #[no_mangle]
@@ -186,11 +154,7 @@ unsafe extern "C" fn artichoke_time_weekday(
     let mut interp = unwrap_interpreter!(mrb);
     let mut guard = Guard::new(&mut interp);
     let time = Value::from(slf);
-    let result = trampoline::weekday(&mut guard, time);
-    match result {
-        Ok(value) => value.inner(),
-        Err(exception) => exception::raise(guard, exception),
-    }
+    ffi_catch_unwind!(guard, trampoline::weekday(&mut guard, time))
 }
 
 #[no_mangle]
@@ -202,11 +166,7 @@ unsafe extern "C" fn artichoke_time_year_day(
     let mut interp = unwrap_interpreter!(mrb);
     let mut guard = Guard::new(&mut interp);
     let time = Value::from(slf);
-    let result = trampoline::year_day(&mut guard, time);
-    match result {
-        Ok(value) => value.inner(),
-        Err(exception) => exception::raise(guard, exception),
-    }
+    ffi_catch_unwind!(guard, trampoline::year_day(&mut guard, time))
 }
 
 #[no_mangle]
@@ -218,11 +178,7 @@ unsafe extern "C" fn artichoke_time_year(
     let mut interp = unwrap_interpreter!(mrb);
     let mut guard = Guard::new(&mut interp);
     let time = Value::from(slf);
-    let result = trampoline::year(&mut guard, time);
-    match result {
-        Ok(value) => value.inner(),
-        Err(exception) => exception::raise(guard, exception),
-    }
+    ffi_catch_unwind!(guard, trampoline::year(&mut guard, time))
 }
 
 #[no_mangle]
@@ -234,11 +190,7 @@ unsafe extern "C" fn artichoke_time_is_sunday(
     let mut interp = unwrap_interpreter!(mrb);
     let mut guard = Guard::new(&mut interp);
     let time = Value::from(slf);
-    let result = trampoline::is_sunday(&mut guard, time);
-    match result {
-        Ok(value) => value.inner(),
-        Err(exception) => exception::raise(guard, exception),
-    }
+    ffi_catch_unwind!(guard, trampoline::is_sunday(&mut guard, time))
 }
 
 #[no_mangle]
@@ -250,11 +202,7 @@ unsafe extern "C" fn artichoke_time_is_monday(
     let mut interp = unwrap_interpreter!(mrb);
     let mut guard = Guard::new(&mut interp);
     let time = Value::from(slf);
-    let result = trampoline::is_monday(&mut guard, time);
-    match result {
-        Ok(value) => value.inner(),
-        Err(exception) => exception::raise(guard, exception),
-    }
+    ffi_catch_unwind!(guard, trampoline::is_monday(&mut guard, time))
 }
 
 #[no_mangle]
@@ -266,11 +214,7 @@ unsafe extern "C" fn artichoke_time_is_tuesday(
     let mut interp = unwrap_interpreter!(mrb);
     let mut guard = Guard::new(&mut interp);
     let time = Value::from(slf);
-    let result = trampoline::is_tuesday(&mut guard, time);
-    match result {
-        Ok(value) => value.inner(),
-        Err(exception) => exception::raise(guard, exception),
-    }
+    ffi_catch_unwind!(guard, trampoline::is_tuesday(&mut guard, time))
 }
 
 #[no_mangle]
@@ -282,11 +226,7 @@ unsafe extern "C" fn artichoke_time_is_wednesday(
     let mut interp = unwrap_interpreter!(mrb);
     let mut guard = Guard::new(&mut interp);
     let time = Value::from(slf);
-    let result = trampoline::is_wednesday(&mut guard, time);
-    match result {
-        Ok(value) => value.inner(),
-        Err(exception) => exception::raise(guard, exception),
-    }
+    ffi_catch_unwind!(guard, trampoline::is_wednesday(&mut guard, time))
 }
 
 #[no_mangle]
@@ -298,11 +238,7 @@ unsafe extern "C" fn artichoke_time_is_thursday(
     let mut interp = unwrap_interpreter!(mrb);
     let mut guard = Guard::new(&mut interp);
     let time = Value::from(slf);
-    let result = trampoline::is_thursday(&mut guard, time);
-    match result {
-        Ok(value) => value.inner(),
-        Err(exception) => exception::raise(guard, exception),
-    }
+    ffi_catch_unwind!(guard, trampoline::is_thursday(&mut guard, time))
 }
 
 #[no_mangle]
@@ -314,11 +250,7 @@ unsafe extern "C" fn artichoke_time_is_friday(
     let mut interp = unwrap_interpreter!(mrb);
     let mut guard = Guard::new(&mut interp);
     let time = Value::from(slf);
-    let result = trampoline::is_friday(&mut guard, time);
-    match result {
-        Ok(value) => value.inner(),
-        Err(exception) => exception::raise(guard, exception),
-    }
+    ffi_catch_unwind!(guard, trampoline::is_friday(&mut guard, time))
 }
 
 #[no_mangle]
@@ -330,9 +262,5 @@ unsafe extern "C" fn artichoke_time_is_saturday(
     let mut interp = unwrap_interpreter!(mrb);
     let mut guard = Guard::new(&mut interp);
     let time = Value::from(slf);
-    let result = trampoline::is_saturday(&mut guard, time);
-    match result {
-        Ok(value) => value.inner(),
-        Err(exception) => exception::raise(guard, exception),
-    }
+    ffi_catch_unwind!(guard, trampoline::is_saturday(&mut guard, time))
 }
@@ -1,8 +1,26 @@
+use chrono::{Local, TimeZone};
+
 use crate::extn::core::time::Time;
 use crate::extn::prelude::*;
 
 pub fn now(interp: &mut Artichoke) -> Result<Value, Exception> {
-    let now = Time::now();
+    let now = if let Some((unix_seconds, subsec_nanos)) = interp.replay_clock()? {
+        let datetime = Local.timestamp(unix_seconds, subsec_nanos);
+        Time::from_datetime(datetime)
+    } else {
+        #[cfg(feature = "artichoke-test")]
+        let now = interp
+            .state
+            .as_ref()
+            .and_then(|state| state.clock_override.as_ref())
+            .map_or_else(Time::now, |clock_override| clock_override.now());
+        #[cfg(not(feature = "artichoke-test"))]
+        let now = Time::now();
+
+        interp.record_clock(now.inner().to_int(), now.inner().nanosecond());
+        now
+    };
+
     let result = Time::alloc_value(now, interp)?;
     Ok(result)
 }
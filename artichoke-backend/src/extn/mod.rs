@@ -39,11 +39,9 @@ where
     let release_date = interp.convert_mut(config.ruby_release_date());
     interp.define_global_constant("RUBY_RELEASE_DATE", release_date)?;
 
-    let revision = config
-        .ruby_revision()
-        .parse::<Int>()
-        .map_err(|_| NotDefinedError::global_constant("RUBY_REVISION"))?;
-    let revision = interp.convert(revision);
+    // As of Ruby 3.0, `RUBY_REVISION` is the git commit hash of the checkout
+    // a build was made from (a `String`), not an SVN revision number.
+    let revision = interp.convert_mut(config.ruby_revision());
     interp.define_global_constant("RUBY_REVISION", revision)?;
 
     let version = interp.convert_mut(config.ruby_version());
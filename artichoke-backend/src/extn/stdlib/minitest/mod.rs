@@ -0,0 +1,58 @@
+use crate::extn::prelude::*;
+
+pub fn init(interp: &mut Artichoke) -> InitializeResult<()> {
+    interp.def_rb_source_file("minitest.rb", &include_bytes!("vendor/minitest.rb")[..])?;
+    Ok(())
+}
+
+/// Run every `Minitest::Test` subclass defined on `interp`, returning `true`
+/// if all of their `test_*` methods passed.
+///
+/// This is the host-facing entry point for Artichoke's embedded test
+/// framework: it `require`s `minitest`, which must already have been
+/// evaluated by test code that defines `Minitest::Test` subclasses, then
+/// calls `Minitest.run` and reports its result back to Rust.
+///
+/// # Errors
+///
+/// If an exception is raised on the interpreter, it is returned.
+pub fn run(interp: &mut Artichoke) -> Result<bool, Exception> {
+    interp.eval(b"require 'minitest'")?;
+    let result = interp.eval(b"Minitest.run")?;
+    interp.try_convert(result)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::test::prelude::*;
+
+    #[test]
+    fn integration_test() {
+        let mut interp = crate::interpreter().unwrap();
+        let _ = interp
+            .eval(&include_bytes!("minitest_test.rb")[..])
+            .unwrap();
+        let result = interp.eval(b"spec");
+        let result = result.unwrap().try_into::<bool>(&interp).unwrap();
+        assert!(result);
+    }
+
+    #[test]
+    fn run_reports_failures() {
+        let mut interp = crate::interpreter().unwrap();
+        interp.eval(b"require 'minitest'").unwrap();
+        interp
+            .eval(
+                br#"
+                class FailingTest < Minitest::Test
+                  def test_it_fails
+                    assert_equal 1, 2
+                  end
+                end
+                "#,
+            )
+            .unwrap();
+        let passed = super::run(&mut interp).unwrap();
+        assert!(!passed);
+    }
+}
@@ -6,8 +6,12 @@ pub mod cmath;
 pub mod delegate;
 pub mod forwardable;
 pub mod json;
+pub mod minitest;
 pub mod monitor;
 pub mod ostruct;
+pub mod pp;
+#[cfg(feature = "stdlib-readline")]
+pub mod readline;
 #[cfg(feature = "stdlib-securerandom")]
 pub mod securerandom;
 pub mod set;
@@ -23,8 +27,12 @@ pub fn init(interp: &mut Artichoke) -> InitializeResult<()> {
     delegate::init(interp)?;
     forwardable::init(interp)?;
     json::init(interp)?;
+    minitest::init(interp)?;
     monitor::init(interp)?;
     ostruct::init(interp)?;
+    pp::init(interp)?;
+    #[cfg(feature = "stdlib-readline")]
+    readline::mruby::init(interp)?;
     #[cfg(feature = "stdlib-securerandom")]
     securerandom::mruby::init(interp)?;
     set::init(interp)?;
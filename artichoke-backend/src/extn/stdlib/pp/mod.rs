@@ -0,0 +1,32 @@
+use crate::extn::prelude::*;
+
+pub fn init(interp: &mut Artichoke) -> InitializeResult<()> {
+    let spec = class::Spec::new("PrettyPrint", None, None)?;
+    interp.def_class::<PrettyPrint>(spec)?;
+    let spec = class::Spec::new("PP", None, None)?;
+    interp.def_class::<PP>(spec)?;
+    interp.def_rb_source_file("prettyprint.rb", &include_bytes!("vendor/prettyprint.rb")[..])?;
+    interp.def_rb_source_file("pp.rb", &include_bytes!("vendor/pp.rb")[..])?;
+    Ok(())
+}
+
+#[derive(Debug)]
+pub struct PrettyPrint;
+
+#[derive(Debug)]
+#[allow(clippy::upper_case_acronyms)]
+pub struct PP;
+
+#[cfg(test)]
+mod tests {
+    use crate::test::prelude::*;
+
+    #[test]
+    fn integration_test() {
+        let mut interp = crate::interpreter().unwrap();
+        let _ = interp.eval(&include_bytes!("pp_test.rb")[..]).unwrap();
+        let result = interp.eval(b"spec");
+        let result = result.unwrap().try_into::<bool>(&interp).unwrap();
+        assert!(result);
+    }
+}
@@ -0,0 +1,21 @@
+use crate::extn::prelude::*;
+
+pub mod mruby;
+pub mod trampoline;
+
+#[derive(Debug)]
+pub struct Readline;
+
+#[cfg(test)]
+mod tests {
+    use crate::test::prelude::*;
+
+    #[test]
+    fn integration_test() {
+        let mut interp = crate::interpreter().unwrap();
+        let _ = interp.eval(&include_bytes!("readline_test.rb")[..]).unwrap();
+        let result = interp.eval(b"spec");
+        let result = result.unwrap().try_into::<bool>(&interp).unwrap();
+        assert!(result);
+    }
+}
@@ -0,0 +1,46 @@
+use crate::extn::prelude::*;
+use crate::extn::stdlib::readline::{self, trampoline};
+
+pub fn init(interp: &mut Artichoke) -> InitializeResult<()> {
+    interp.def_file_for_type::<_, ReadlineFile>("readline.rb")?;
+    Ok(())
+}
+
+#[derive(Debug)]
+pub struct ReadlineFile;
+
+impl File for ReadlineFile {
+    type Artichoke = Artichoke;
+    type Error = Exception;
+
+    fn require(interp: &mut Self::Artichoke) -> Result<(), Self::Error> {
+        if interp.is_module_defined::<readline::Readline>() {
+            return Ok(());
+        }
+        let spec = module::Spec::new(interp, "Readline", None)?;
+        module::Builder::for_spec(interp, &spec)
+            .add_self_method(
+                "__readline__",
+                artichoke_readline_readline,
+                sys::mrb_args_opt(1),
+            )?
+            .define()?;
+        interp.def_module::<readline::Readline>(spec)?;
+        let _ = interp.eval(&include_bytes!("readline.rb")[..])?;
+
+        trace!("Patched Readline onto interpreter");
+        Ok(())
+    }
+}
+
+#[no_mangle]
+unsafe extern "C" fn artichoke_readline_readline(
+    mrb: *mut sys::mrb_state,
+    _slf: sys::mrb_value,
+) -> sys::mrb_value {
+    let prompt = mrb_get_args!(mrb, optional = 1);
+    let mut interp = unwrap_interpreter!(mrb);
+    let mut guard = Guard::new(&mut interp);
+    let prompt = prompt.map(Value::from);
+    ffi_catch_unwind!(guard, trampoline::readline(&mut guard, prompt))
+}
@@ -0,0 +1,21 @@
+use crate::extn::prelude::*;
+
+pub fn readline(interp: &mut Artichoke, prompt: Option<Value>) -> Result<Value, Exception> {
+    let prompt = match prompt {
+        Some(mut prompt) => {
+            let prompt = prompt.implicitly_convert_to_string(interp)?;
+            String::from_utf8_lossy(prompt).into_owned()
+        }
+        None => String::new(),
+    };
+    let line = interp
+        .state
+        .as_mut()
+        .ok_or(InterpreterExtractError)?
+        .readline
+        .readline(&prompt);
+    match line {
+        Some(line) => Ok(interp.convert_mut(line)),
+        None => Ok(Value::nil()),
+    }
+}
@@ -58,11 +58,7 @@ unsafe extern "C" fn artichoke_securerandom_alphanumeric(
     let mut interp = unwrap_interpreter!(mrb);
     let mut guard = Guard::new(&mut interp);
     let len = len.map(Value::from).and_then(|len| guard.convert(len));
-    let result = trampoline::alphanumeric(&mut guard, len);
-    match result {
-        Ok(value) => value.inner(),
-        Err(exception) => exception::raise(guard, exception),
-    }
+    ffi_catch_unwind!(guard, trampoline::alphanumeric(&mut guard, len))
 }
 
 #[no_mangle]
@@ -74,11 +70,7 @@ unsafe extern "C" fn artichoke_securerandom_base64(
     let mut interp = unwrap_interpreter!(mrb);
     let mut guard = Guard::new(&mut interp);
     let len = len.map(Value::from).and_then(|len| guard.convert(len));
-    let result = trampoline::base64(&mut guard, len);
-    match result {
-        Ok(value) => value.inner(),
-        Err(exception) => exception::raise(guard, exception),
-    }
+    ffi_catch_unwind!(guard, trampoline::base64(&mut guard, len))
 }
 
 #[no_mangle]
@@ -90,11 +82,7 @@ unsafe extern "C" fn artichoke_securerandom_hex(
     let mut interp = unwrap_interpreter!(mrb);
     let mut guard = Guard::new(&mut interp);
     let len = len.map(Value::from).and_then(|len| guard.convert(len));
-    let result = trampoline::hex(&mut guard, len);
-    match result {
-        Ok(value) => value.inner(),
-        Err(exception) => exception::raise(guard, exception),
-    }
+    ffi_catch_unwind!(guard, trampoline::hex(&mut guard, len))
 }
 
 #[no_mangle]
@@ -106,11 +94,7 @@ unsafe extern "C" fn artichoke_securerandom_random_bytes(
     let mut interp = unwrap_interpreter!(mrb);
     let mut guard = Guard::new(&mut interp);
     let len = len.map(Value::from).and_then(|len| guard.convert(len));
-    let result = trampoline::random_bytes(&mut guard, len);
-    match result {
-        Ok(value) => value.inner(),
-        Err(exception) => exception::raise(guard, exception),
-    }
+    ffi_catch_unwind!(guard, trampoline::random_bytes(&mut guard, len))
 }
 
 #[no_mangle]
@@ -122,11 +106,7 @@ unsafe extern "C" fn artichoke_securerandom_random_number(
     let mut interp = unwrap_interpreter!(mrb);
     let mut guard = Guard::new(&mut interp);
     let max = max.map(Value::from).and_then(|max| guard.convert(max));
-    let result = trampoline::random_number(&mut guard, max);
-    match result {
-        Ok(value) => value.inner(),
-        Err(exception) => exception::raise(guard, exception),
-    }
+    ffi_catch_unwind!(guard, trampoline::random_number(&mut guard, max))
 }
 
 #[no_mangle]
@@ -137,9 +117,5 @@ unsafe extern "C" fn artichoke_securerandom_uuid(
     mrb_get_args!(mrb, none);
     let mut interp = unwrap_interpreter!(mrb);
     let mut guard = Guard::new(&mut interp);
-    let result = trampoline::uuid(&mut guard);
-    match result {
-        Ok(value) => value.inner(),
-        Err(exception) => exception::raise(guard, exception),
-    }
+    ffi_catch_unwind!(guard, trampoline::uuid(&mut guard))
 }
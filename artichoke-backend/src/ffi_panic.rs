@@ -0,0 +1,82 @@
+//! Containment for Rust panics that occur while executing an `extern "C"`
+//! trampoline.
+//!
+//! Unwinding a Rust panic across an FFI boundary into the mruby VM is
+//! undefined behavior. The [`ffi_catch_unwind`](crate::ffi_catch_unwind!)
+//! macro wraps a trampoline body in [`std::panic::catch_unwind`] and
+//! converts a caught panic into a Ruby `fatal` exception via [`PanicError`]
+//! instead of letting it unwind into C.
+
+use std::any::Any;
+use std::borrow::Cow;
+use std::error;
+use std::fmt;
+
+use crate::core::ConvertMut as _;
+use crate::exception::RubyException;
+use crate::extn::core::exception::Fatal;
+use crate::sys;
+use crate::Artichoke;
+
+/// A Rust panic that was caught at an FFI trampoline boundary.
+///
+/// This type is raised as a Ruby `fatal` exception, mirroring how MRI
+/// reports a fatal VM error: the panic message is preserved, but the
+/// exception cannot be rescued from Ruby.
+#[derive(Debug, Clone)]
+pub struct PanicError {
+    message: String,
+}
+
+impl PanicError {
+    /// Construct a new `PanicError` from a panic message.
+    #[must_use]
+    pub fn new(message: String) -> Self {
+        Self { message }
+    }
+}
+
+impl fmt::Display for PanicError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Rust panic at FFI boundary: {}", self.message)
+    }
+}
+
+impl error::Error for PanicError {}
+
+impl RubyException for PanicError {
+    fn message(&self) -> Cow<'_, [u8]> {
+        Cow::Owned(self.to_string().into_bytes())
+    }
+
+    fn name(&self) -> Cow<'_, str> {
+        "fatal".into()
+    }
+
+    fn vm_backtrace(&self, interp: &mut Artichoke) -> Option<Vec<Vec<u8>>> {
+        let _ = interp;
+        None
+    }
+
+    fn as_mrb_value(&self, interp: &mut Artichoke) -> Option<sys::mrb_value> {
+        let message = interp.convert_mut(self.message());
+        let value = interp.new_instance::<Fatal>(&[message]).ok().flatten()?;
+        Some(value.inner())
+    }
+}
+
+/// Extract a human-readable message from a [`catch_unwind`](std::panic::catch_unwind)
+/// payload.
+///
+/// Panics raised with `panic!("{}", msg)` or `panic!(msg)` box either a
+/// `&'static str` or a `String`; anything else is reported generically.
+#[must_use]
+pub fn panic_message(payload: &(dyn Any + Send)) -> String {
+    if let Some(message) = payload.downcast_ref::<&str>() {
+        (*message).to_string()
+    } else if let Some(message) = payload.downcast_ref::<String>() {
+        message.clone()
+    } else {
+        "unknown panic at FFI boundary".to_string()
+    }
+}
@@ -10,11 +10,19 @@
 //!
 //! Artichoke has several virtual filesystem implementations. Only some of them
 //! support reading from the system fs.
+//!
+//! [`native::Native`] is implemented entirely in terms of `std::fs`, so it
+//! works unmodified on `wasm32-wasi`: WASI's libstd implements the `fs`
+//! module on top of the capability-based `wasi_snapshot_preview1` syscalls,
+//! and a host runtime (e.g. wasmtime) that preopens a directory for the
+//! guest makes it visible the same way any other native filesystem access
+//! is -- there is no Artichoke-specific WASI filesystem backend to write.
+//! See `tests/wasi_backends.rs` for a `wasm32-wasi`-only smoke test of this.
 
 use std::borrow::Cow;
 use std::fmt;
 use std::io;
-use std::path::{Component, Path, PathBuf};
+use std::path::{Path, PathBuf};
 
 use crate::exception::Exception;
 use crate::Artichoke;
@@ -22,6 +30,7 @@ use crate::Artichoke;
 pub mod hybrid;
 pub mod memory;
 pub mod native;
+pub(crate) mod path;
 
 /// Directory at which Ruby sources and extensions are stored in the virtual
 /// filesystem.
@@ -42,27 +51,32 @@ pub type ExtensionHook = fn(&mut Artichoke) -> Result<(), Exception>;
 
 #[must_use]
 #[cfg(all(feature = "native-filesystem-access", not(any(test, doctest))))]
-pub fn filesystem() -> Box<dyn Filesystem> {
+pub fn filesystem() -> Box<dyn Filesystem + Send> {
     let fs = hybrid::Hybrid::default();
     Box::new(fs)
 }
 
 #[must_use]
 #[cfg(not(any(feature = "native-filesystem-access", test, doctest)))]
-pub fn filesystem() -> Box<dyn Filesystem> {
+pub fn filesystem() -> Box<dyn Filesystem + Send> {
     let fs = memory::Memory::default();
     Box::new(fs)
 }
 
 #[must_use]
 #[cfg(any(doctest, test))]
-pub fn filesystem() -> Box<dyn Filesystem> {
+pub fn filesystem() -> Box<dyn Filesystem + Send> {
     let fs = memory::Memory::default();
     Box::new(fs)
 }
 
 /// Filesystem APIs required by an Artichoke interpreter.
-pub trait Filesystem: fmt::Debug {
+///
+/// This trait has a `Send` supertrait bound because embedder-provided
+/// implementations are stored in [`State`](crate::state::State) behind the
+/// [`SharedInterpreter`](crate::shared::SharedInterpreter) `Mutex`, which
+/// requires everything it guards to be safe to hand off between threads.
+pub trait Filesystem: fmt::Debug + Send {
     /// Check whether `path` points to a file in the virtual filesystem.
     ///
     /// This API is infallible and will return `false` for non-existent paths.
@@ -123,208 +137,30 @@ pub trait Filesystem: fmt::Debug {
     /// If `path` does not exist, an [`io::Error`] with error kind
     /// [`io::ErrorKind::NotFound`] is returned.
     fn mark_required(&mut self, path: &Path) -> io::Result<()>;
-}
-
-impl Default for Box<dyn Filesystem> {
-    fn default() -> Self {
-        filesystem()
-    }
-}
-
-fn absolutize_relative_to<T, U>(path: T, cwd: U) -> PathBuf
-where
-    T: AsRef<Path>,
-    U: AsRef<Path>,
-{
-    let mut iter = path.as_ref().components().peekable();
-    let hint = iter.size_hint();
-    let (mut components, cwd_is_relative) = if let Some(Component::RootDir) = iter.peek() {
-        (Vec::with_capacity(hint.1.unwrap_or(hint.0)), false)
-    } else {
-        let mut components = cwd
-            .as_ref()
-            .components()
-            .map(Component::as_os_str)
-            .collect::<Vec<_>>();
-        components.reserve(hint.1.unwrap_or(hint.0));
-        (components, cwd.as_ref().is_relative())
-    };
-    for component in iter {
-        match component {
-            Component::CurDir => {}
-            Component::ParentDir if cwd_is_relative => {
-                components.pop();
-            }
-            Component::ParentDir => {
-                components.pop();
-                if components.is_empty() {
-                    components.push(Component::RootDir.as_os_str());
-                }
-            }
-            c => {
-                components.push(c.as_os_str());
-            }
-        }
-    }
-    components.into_iter().collect()
-}
-
-#[cfg(test)]
-mod tests {
-    use std::path::Path;
-
-    use super::absolutize_relative_to;
-
-    #[test]
-    fn absolutize_absolute_path() {
-        let path = Path::new("/foo/bar");
-        let cwd = Path::new("/home/artichoke");
-        assert_eq!(absolutize_relative_to(&path, cwd), path);
-        let cwd = Path::new("relative/path");
-        assert_eq!(absolutize_relative_to(&path, cwd), path);
-    }
-
-    #[test]
-    fn absolutize_absolute_path_dedot_current_dir() {
-        let path = Path::new("/././foo/./bar/./././.");
-        let cwd = Path::new("/home/artichoke");
-        assert_eq!(absolutize_relative_to(&path, cwd), Path::new("/foo/bar"));
-        let cwd = Path::new("relative/path");
-        assert_eq!(absolutize_relative_to(&path, cwd), Path::new("/foo/bar"));
-    }
-
-    #[test]
-    fn absolutize_absolute_path_dedot_parent_dir() {
-        let path = Path::new("/foo/bar/..");
-        let cwd = Path::new("/home/artichoke");
-        assert_eq!(absolutize_relative_to(&path, cwd), Path::new("/foo"));
-        let cwd = Path::new("relative/path");
-        assert_eq!(absolutize_relative_to(&path, cwd), Path::new("/foo"));
-
-        let path = Path::new("/foo/../../../../bar/../../../");
-        let cwd = Path::new("/home/artichoke");
-        assert_eq!(absolutize_relative_to(&path, cwd), Path::new("/"));
-        let cwd = Path::new("relative/path");
-        assert_eq!(absolutize_relative_to(&path, cwd), Path::new("/"));
-
-        let path = Path::new("/foo/../../../../bar/../../../boom/baz");
-        let cwd = Path::new("/home/artichoke");
-        assert_eq!(absolutize_relative_to(&path, cwd), Path::new("/boom/baz"));
-        let cwd = Path::new("relative/path");
-        assert_eq!(absolutize_relative_to(&path, cwd), Path::new("/boom/baz"));
-    }
-
-    #[test]
-    fn absolutize_relative_path() {
-        let path = Path::new("foo/bar");
-        let cwd = Path::new("/home/artichoke");
-        assert_eq!(
-            absolutize_relative_to(&path, cwd),
-            Path::new("/home/artichoke/foo/bar")
-        );
-        let cwd = Path::new("relative/path");
-        assert_eq!(
-            absolutize_relative_to(&path, cwd),
-            Path::new("relative/path/foo/bar")
-        );
-    }
-
-    #[test]
-    fn absolutize_relative_path_dedot_current_dir() {
-        let path = Path::new("././././foo/./bar/./././.");
-        let cwd = Path::new("/home/artichoke");
-        assert_eq!(
-            absolutize_relative_to(&path, cwd),
-            Path::new("/home/artichoke/foo/bar")
-        );
-        let cwd = Path::new("relative/path");
-        assert_eq!(
-            absolutize_relative_to(&path, cwd),
-            Path::new("relative/path/foo/bar")
-        );
-    }
-
-    #[test]
-    #[cfg(unix)]
-    fn absolutize_relative_path_dedot_parent_dir_unix() {
-        let path = Path::new("foo/bar/..");
-        let cwd = Path::new("/home/artichoke");
-        let absolute = absolutize_relative_to(&path, cwd);
-        assert_eq!(absolute, Path::new("/home/artichoke/foo"));
-        let cwd = Path::new("relative/path");
-        let absolute = absolutize_relative_to(&path, cwd);
-        assert_eq!(absolute, Path::new("relative/path/foo"));
-
-        let path = Path::new("foo/../../../../bar/../../../");
-        let cwd = Path::new("/home/artichoke");
-        let absolute = absolutize_relative_to(&path, cwd);
-        assert_eq!(absolute, Path::new("/"));
-        let cwd = Path::new("relative/path");
-        let absolute = absolutize_relative_to(&path, cwd);
-        assert_eq!(absolute, Path::new(""));
-
-        let path = Path::new("foo/../../../../bar/../../../boom/baz");
-        let cwd = Path::new("/home/artichoke");
-        let absolute = absolutize_relative_to(&path, cwd);
-        assert_eq!(absolute, Path::new("/boom/baz"));
-        let cwd = Path::new("relative/path");
-        let absolute = absolutize_relative_to(&path, cwd);
-        assert_eq!(absolute, Path::new("boom/baz"));
-    }
-
-    #[test]
-    #[cfg(windows)]
-    fn absolutize_relative_path_dedot_parent_dir_windows_forward_slash() {
-        let path = Path::new("foo/bar/..");
-        let cwd = Path::new("C:/Users/artichoke");
-        let absolute = absolutize_relative_to(&path, cwd);
-        assert_eq!(absolute, Path::new("C:/Users/artichoke/foo"));
-        let cwd = Path::new("relative/path");
-        let absolute = absolutize_relative_to(&path, cwd);
-        assert_eq!(absolute, Path::new("relative/path/foo"));
 
-        let path = Path::new("foo/../../../../bar/../../../");
-        let cwd = Path::new("C:/Users/artichoke");
-        let absolute = absolutize_relative_to(&path, cwd);
-        assert_eq!(absolute, Path::new("/"));
-        let cwd = Path::new("relative/path");
-        let absolute = absolutize_relative_to(&path, cwd);
-        assert_eq!(absolute, Path::new(""));
-
-        let path = Path::new("foo/../../../../bar/../../../boom/baz");
-        let cwd = Path::new("C:/Users/artichoke");
-        let absolute = absolutize_relative_to(&path, cwd);
-        assert_eq!(absolute, Path::new("/boom/baz"));
-        let cwd = Path::new("relative/path");
-        let absolute = absolutize_relative_to(&path, cwd);
-        assert_eq!(absolute, Path::new("boom/baz"));
-    }
+    /// Clear the required bit for a source at `path`.
+    ///
+    /// This API is infallible and is a no-op for non-existent paths.
+    fn unmark_required(&mut self, path: &Path) -> io::Result<()>;
 
-    #[test]
-    #[cfg(windows)]
-    fn absolutize_relative_path_dedot_parent_dir_windows_backward_slash() {
-        let path = Path::new(r"foo\bar\..");
-        let cwd = Path::new(r"C:\Users\artichoke");
-        let absolute = absolutize_relative_to(&path, cwd);
-        assert_eq!(absolute, Path::new("C:/Users/artichoke/foo"));
-        let cwd = Path::new(r"relative\path");
-        let absolute = absolutize_relative_to(&path, cwd);
-        assert_eq!(absolute, Path::new("relative/path/foo"));
+    /// Remove a source at `path` from the virtual filesystem.
+    ///
+    /// Removes file contents and extension hooks. Returns whether a source
+    /// existed at `path` prior to removal.
+    ///
+    /// # Errors
+    ///
+    /// If the backing store fails to remove a file that exists at `path`, an
+    /// [`io::Error`] is returned.
+    fn remove_file(&mut self, path: &Path) -> io::Result<bool>;
 
-        let path = Path::new(r"foo\..\..\..\..\bar\..\..\..\");
-        let cwd = Path::new(r"C:\Users\artichoke");
-        let absolute = absolutize_relative_to(&path, cwd);
-        assert_eq!(absolute, Path::new("/"));
-        let cwd = Path::new(r"relative\path");
-        let absolute = absolutize_relative_to(&path, cwd);
-        assert_eq!(absolute, Path::new(""));
+    /// Enumerate all source paths currently defined on the virtual
+    /// filesystem.
+    fn paths(&self) -> Vec<PathBuf>;
+}
 
-        let path = Path::new(r"foo\..\..\..\..\bar\..\..\..\boom\baz");
-        let cwd = Path::new(r"C:\Users\artichoke");
-        let absolute = absolutize_relative_to(&path, cwd);
-        assert_eq!(absolute, Path::new("/boom/baz"));
-        let cwd = Path::new(r"relative\path");
-        let absolute = absolutize_relative_to(&path, cwd);
-        assert_eq!(absolute, Path::new("boom/baz"));
+impl Default for Box<dyn Filesystem + Send> {
+    fn default() -> Self {
+        filesystem()
     }
 }
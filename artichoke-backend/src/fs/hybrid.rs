@@ -1,6 +1,6 @@
 use std::borrow::Cow;
 use std::io;
-use std::path::Path;
+use std::path::{Path, PathBuf};
 
 use crate::fs::memory::Memory;
 use crate::fs::native::Native;
@@ -57,4 +57,26 @@ impl Filesystem for Hybrid {
             self.native.mark_required(path)
         }
     }
+
+    fn unmark_required(&mut self, path: &Path) -> io::Result<()> {
+        if path.starts_with(RUBY_LOAD_PATH) {
+            self.memory.unmark_required(path)
+        } else {
+            self.native.unmark_required(path)
+        }
+    }
+
+    fn remove_file(&mut self, path: &Path) -> io::Result<bool> {
+        if path.starts_with(RUBY_LOAD_PATH) {
+            self.memory.remove_file(path)
+        } else {
+            self.native.remove_file(path)
+        }
+    }
+
+    fn paths(&self) -> Vec<PathBuf> {
+        let mut paths = self.memory.paths();
+        paths.extend(self.native.paths());
+        paths
+    }
 }
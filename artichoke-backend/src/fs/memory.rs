@@ -5,7 +5,8 @@ use std::fmt;
 use std::io;
 use std::path::{Path, PathBuf};
 
-use crate::fs::{absolutize_relative_to, ExtensionHook, Filesystem, RUBY_LOAD_PATH};
+use crate::fs::path::absolutize_relative_to;
+use crate::fs::{ExtensionHook, Filesystem, RUBY_LOAD_PATH};
 
 #[derive(Clone, Copy)]
 pub struct Extension {
@@ -391,6 +392,32 @@ impl Filesystem for Memory {
             ))
         }
     }
+
+    /// Clear the required bit for a source at `path`.
+    ///
+    /// This API is infallible and is a no-op for non-existent paths.
+    fn unmark_required(&mut self, path: &Path) -> io::Result<()> {
+        let path = absolutize_relative_to(path, &self.cwd);
+        if let Some(entry) = self.fs.get_mut(&path) {
+            entry.required = false;
+        }
+        Ok(())
+    }
+
+    /// Remove a source at `path` from the virtual filesystem.
+    ///
+    /// Removes file contents and extension hooks. Returns whether a source
+    /// existed at `path` prior to removal.
+    fn remove_file(&mut self, path: &Path) -> io::Result<bool> {
+        let path = absolutize_relative_to(path, &self.cwd);
+        Ok(self.fs.remove(&path).is_some())
+    }
+
+    /// Enumerate all source paths currently defined on the virtual
+    /// filesystem.
+    fn paths(&self) -> Vec<PathBuf> {
+        self.fs.keys().cloned().collect()
+    }
 }
 
 #[cfg(test)]
@@ -5,7 +5,8 @@ use std::fs;
 use std::io;
 use std::path::{Path, PathBuf};
 
-use crate::fs::{absolutize_relative_to, ExtensionHook, Filesystem};
+use crate::fs::path::absolutize_relative_to;
+use crate::fs::{ExtensionHook, Filesystem};
 
 #[derive(Default, Debug, PartialEq, Eq)]
 pub struct Native {
@@ -62,4 +63,33 @@ impl Filesystem for Native {
         self.loaded_features.insert(path);
         Ok(())
     }
+
+    fn unmark_required(&mut self, path: &Path) -> io::Result<()> {
+        if let Ok(cwd) = env::current_dir() {
+            let path = absolutize_relative_to(path, &cwd);
+            self.loaded_features.remove(&path);
+        }
+        Ok(())
+    }
+
+    fn remove_file(&mut self, path: &Path) -> io::Result<bool> {
+        if let Ok(cwd) = env::current_dir() {
+            let path = absolutize_relative_to(path, &cwd);
+            self.loaded_features.remove(&path);
+        }
+        match fs::remove_file(path) {
+            Ok(()) => Ok(true),
+            Err(err) if err.kind() == io::ErrorKind::NotFound => Ok(false),
+            Err(err) => Err(err),
+        }
+    }
+
+    /// Enumerate sources required on the native filesystem.
+    ///
+    /// `Native` does not track the full set of files it has read, only the
+    /// set it has marked required, so this is a lower bound on sources the
+    /// interpreter has touched rather than a full directory listing.
+    fn paths(&self) -> Vec<PathBuf> {
+        self.loaded_features.iter().cloned().collect()
+    }
 }
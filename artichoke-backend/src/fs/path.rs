@@ -0,0 +1,215 @@
+//! Path normalization shared by the virtual filesystem and `Kernel#require`.
+//!
+//! This module centralizes the one piece of path arithmetic Artichoke needs
+//! to do without touching a real filesystem: joining a possibly-relative
+//! path onto a current working directory and resolving `.` and `..`
+//! components along the way. [`std::path::Path`] already parses `/` as a
+//! separator on every platform and additionally parses `\` as a separator
+//! when compiled for Windows, so there is no separator handling here beyond
+//! what [`Path::components`] already gives us.
+
+use std::path::{Component, Path, PathBuf};
+
+/// Join `path` onto `cwd`, resolving `.` and `..` components without
+/// touching the real filesystem.
+///
+/// If `path` is absolute, `cwd` is ignored and `path` is de-dotted in place.
+/// A `..` component that would walk above the root is a no-op, matching the
+/// behavior of `File.expand_path`.
+pub(crate) fn absolutize_relative_to<T, U>(path: T, cwd: U) -> PathBuf
+where
+    T: AsRef<Path>,
+    U: AsRef<Path>,
+{
+    let mut iter = path.as_ref().components().peekable();
+    let hint = iter.size_hint();
+    let (mut components, cwd_is_relative) = if let Some(Component::RootDir) = iter.peek() {
+        (Vec::with_capacity(hint.1.unwrap_or(hint.0)), false)
+    } else {
+        let mut components = cwd
+            .as_ref()
+            .components()
+            .map(Component::as_os_str)
+            .collect::<Vec<_>>();
+        components.reserve(hint.1.unwrap_or(hint.0));
+        (components, cwd.as_ref().is_relative())
+    };
+    for component in iter {
+        match component {
+            Component::CurDir => {}
+            Component::ParentDir if cwd_is_relative => {
+                components.pop();
+            }
+            Component::ParentDir => {
+                components.pop();
+                if components.is_empty() {
+                    components.push(Component::RootDir.as_os_str());
+                }
+            }
+            c => {
+                components.push(c.as_os_str());
+            }
+        }
+    }
+    components.into_iter().collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::Path;
+
+    use super::absolutize_relative_to;
+
+    #[test]
+    fn absolutize_absolute_path() {
+        let path = Path::new("/foo/bar");
+        let cwd = Path::new("/home/artichoke");
+        assert_eq!(absolutize_relative_to(&path, cwd), path);
+        let cwd = Path::new("relative/path");
+        assert_eq!(absolutize_relative_to(&path, cwd), path);
+    }
+
+    #[test]
+    fn absolutize_absolute_path_dedot_current_dir() {
+        let path = Path::new("/././foo/./bar/./././.");
+        let cwd = Path::new("/home/artichoke");
+        assert_eq!(absolutize_relative_to(&path, cwd), Path::new("/foo/bar"));
+        let cwd = Path::new("relative/path");
+        assert_eq!(absolutize_relative_to(&path, cwd), Path::new("/foo/bar"));
+    }
+
+    #[test]
+    fn absolutize_absolute_path_dedot_parent_dir() {
+        let path = Path::new("/foo/bar/..");
+        let cwd = Path::new("/home/artichoke");
+        assert_eq!(absolutize_relative_to(&path, cwd), Path::new("/foo"));
+        let cwd = Path::new("relative/path");
+        assert_eq!(absolutize_relative_to(&path, cwd), Path::new("/foo"));
+
+        let path = Path::new("/foo/../../../../bar/../../../");
+        let cwd = Path::new("/home/artichoke");
+        assert_eq!(absolutize_relative_to(&path, cwd), Path::new("/"));
+        let cwd = Path::new("relative/path");
+        assert_eq!(absolutize_relative_to(&path, cwd), Path::new("/"));
+
+        let path = Path::new("/foo/../../../../bar/../../../boom/baz");
+        let cwd = Path::new("/home/artichoke");
+        assert_eq!(absolutize_relative_to(&path, cwd), Path::new("/boom/baz"));
+        let cwd = Path::new("relative/path");
+        assert_eq!(absolutize_relative_to(&path, cwd), Path::new("/boom/baz"));
+    }
+
+    #[test]
+    fn absolutize_relative_path() {
+        let path = Path::new("foo/bar");
+        let cwd = Path::new("/home/artichoke");
+        assert_eq!(
+            absolutize_relative_to(&path, cwd),
+            Path::new("/home/artichoke/foo/bar")
+        );
+        let cwd = Path::new("relative/path");
+        assert_eq!(
+            absolutize_relative_to(&path, cwd),
+            Path::new("relative/path/foo/bar")
+        );
+    }
+
+    #[test]
+    fn absolutize_relative_path_dedot_current_dir() {
+        let path = Path::new("././././foo/./bar/./././.");
+        let cwd = Path::new("/home/artichoke");
+        assert_eq!(
+            absolutize_relative_to(&path, cwd),
+            Path::new("/home/artichoke/foo/bar")
+        );
+        let cwd = Path::new("relative/path");
+        assert_eq!(
+            absolutize_relative_to(&path, cwd),
+            Path::new("relative/path/foo/bar")
+        );
+    }
+
+    #[test]
+    #[cfg(unix)]
+    fn absolutize_relative_path_dedot_parent_dir_unix() {
+        let path = Path::new("foo/bar/..");
+        let cwd = Path::new("/home/artichoke");
+        let absolute = absolutize_relative_to(&path, cwd);
+        assert_eq!(absolute, Path::new("/home/artichoke/foo"));
+        let cwd = Path::new("relative/path");
+        let absolute = absolutize_relative_to(&path, cwd);
+        assert_eq!(absolute, Path::new("relative/path/foo"));
+
+        let path = Path::new("foo/../../../../bar/../../../");
+        let cwd = Path::new("/home/artichoke");
+        let absolute = absolutize_relative_to(&path, cwd);
+        assert_eq!(absolute, Path::new("/"));
+        let cwd = Path::new("relative/path");
+        let absolute = absolutize_relative_to(&path, cwd);
+        assert_eq!(absolute, Path::new(""));
+
+        let path = Path::new("foo/../../../../bar/../../../boom/baz");
+        let cwd = Path::new("/home/artichoke");
+        let absolute = absolutize_relative_to(&path, cwd);
+        assert_eq!(absolute, Path::new("/boom/baz"));
+        let cwd = Path::new("relative/path");
+        let absolute = absolutize_relative_to(&path, cwd);
+        assert_eq!(absolute, Path::new("boom/baz"));
+    }
+
+    #[test]
+    #[cfg(windows)]
+    fn absolutize_relative_path_dedot_parent_dir_windows_forward_slash() {
+        let path = Path::new("foo/bar/..");
+        let cwd = Path::new("C:/Users/artichoke");
+        let absolute = absolutize_relative_to(&path, cwd);
+        assert_eq!(absolute, Path::new("C:/Users/artichoke/foo"));
+        let cwd = Path::new("relative/path");
+        let absolute = absolutize_relative_to(&path, cwd);
+        assert_eq!(absolute, Path::new("relative/path/foo"));
+
+        let path = Path::new("foo/../../../../bar/../../../");
+        let cwd = Path::new("C:/Users/artichoke");
+        let absolute = absolutize_relative_to(&path, cwd);
+        assert_eq!(absolute, Path::new("/"));
+        let cwd = Path::new("relative/path");
+        let absolute = absolutize_relative_to(&path, cwd);
+        assert_eq!(absolute, Path::new(""));
+
+        let path = Path::new("foo/../../../../bar/../../../boom/baz");
+        let cwd = Path::new("C:/Users/artichoke");
+        let absolute = absolutize_relative_to(&path, cwd);
+        assert_eq!(absolute, Path::new("/boom/baz"));
+        let cwd = Path::new("relative/path");
+        let absolute = absolutize_relative_to(&path, cwd);
+        assert_eq!(absolute, Path::new("boom/baz"));
+    }
+
+    #[test]
+    #[cfg(windows)]
+    fn absolutize_relative_path_dedot_parent_dir_windows_backward_slash() {
+        let path = Path::new(r"foo\bar\..");
+        let cwd = Path::new(r"C:\Users\artichoke");
+        let absolute = absolutize_relative_to(&path, cwd);
+        assert_eq!(absolute, Path::new("C:/Users/artichoke/foo"));
+        let cwd = Path::new(r"relative\path");
+        let absolute = absolutize_relative_to(&path, cwd);
+        assert_eq!(absolute, Path::new("relative/path/foo"));
+
+        let path = Path::new(r"foo\..\..\..\..\bar\..\..\..\");
+        let cwd = Path::new(r"C:\Users\artichoke");
+        let absolute = absolutize_relative_to(&path, cwd);
+        assert_eq!(absolute, Path::new("/"));
+        let cwd = Path::new(r"relative\path");
+        let absolute = absolutize_relative_to(&path, cwd);
+        assert_eq!(absolute, Path::new(""));
+
+        let path = Path::new(r"foo\..\..\..\..\bar\..\..\..\boom\baz");
+        let cwd = Path::new(r"C:\Users\artichoke");
+        let absolute = absolutize_relative_to(&path, cwd);
+        assert_eq!(absolute, Path::new("/boom/baz"));
+        let cwd = Path::new(r"relative\path");
+        let absolute = absolutize_relative_to(&path, cwd);
+        assert_eq!(absolute, Path::new("boom/baz"));
+    }
+}
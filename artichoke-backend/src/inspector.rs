@@ -0,0 +1,267 @@
+use crate::core::{TryConvertMut, Value as _};
+use crate::exception::Exception;
+use crate::types::Ruby;
+use crate::value::Value;
+use crate::Artichoke;
+
+/// Default recursion depth for [`Artichoke::snapshot_value`] when a caller
+/// does not have a more specific bound in mind.
+pub const DEFAULT_DEPTH_LIMIT: usize = 5;
+
+/// A host-friendly snapshot of a Ruby [`Value`], for rendering in a debugger
+/// UI or error reporter embedding this interpreter.
+///
+/// Build one with [`Artichoke::snapshot_value`].
+///
+/// Unlike calling [`Value::inspect`](crate::core::Value::inspect) directly,
+/// building a `Snapshot` never invokes a user-defined `#inspect` on a
+/// container -- an `Array`, `Hash`, or arbitrary object -- since doing so
+/// recursively walks the entire object graph with no way to bound the cost
+/// or detect cycles. Only scalars (numbers, strings, symbols, `nil`,
+/// booleans, and the like) are ever inspected directly; everything else is
+/// torn apart into [`children`](Self::children) up to a caller-supplied
+/// depth limit, each its own `Snapshot`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Snapshot {
+    class_name: String,
+    inspect: String,
+    children: Vec<Child>,
+    truncated: bool,
+}
+
+impl Snapshot {
+    /// The snapshotted value's class name, as reported by `Object#class`.
+    #[must_use]
+    pub fn class_name(&self) -> &str {
+        &self.class_name
+    }
+
+    /// A short, human-readable description of the snapshotted value.
+    ///
+    /// For a scalar, this is the real result of `#inspect`. For a
+    /// container, this is a placeholder like `Array(3)` describing its
+    /// shape rather than its contents, since contents are available via
+    /// [`children`](Self::children) instead.
+    #[must_use]
+    pub fn inspect(&self) -> &str {
+        &self.inspect
+    }
+
+    /// The snapshotted value's children: array elements, hash entries, or
+    /// instance variables, each paired with a display name.
+    ///
+    /// Empty for scalars and for containers at the depth limit.
+    #[must_use]
+    pub fn children(&self) -> &[Child] {
+        &self.children
+    }
+
+    /// Whether this snapshot stopped at the depth limit before descending
+    /// into a container's children.
+    #[must_use]
+    pub fn truncated(&self) -> bool {
+        self.truncated
+    }
+}
+
+/// One named child of a [`Snapshot`]: an array index, a hash key's
+/// `#inspect` text, or an instance variable name.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Child {
+    name: String,
+    snapshot: Snapshot,
+}
+
+impl Child {
+    #[must_use]
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    #[must_use]
+    pub fn snapshot(&self) -> &Snapshot {
+        &self.snapshot
+    }
+}
+
+impl Artichoke {
+    /// Build a [`Snapshot`] of `value`, descending into containers up to
+    /// `depth_limit` levels deep.
+    ///
+    /// # Errors
+    ///
+    /// If calling `Object#class`, `Object#instance_variables`, or
+    /// `Object#instance_variable_get` on `value` or one of its descendants
+    /// raises, that exception is returned.
+    pub fn snapshot_value(
+        &mut self,
+        value: Value,
+        depth_limit: usize,
+    ) -> Result<Snapshot, Exception> {
+        let class_name = value.pretty_name(self).to_string();
+        match value.ruby_type() {
+            Ruby::Array => self.snapshot_array(value, class_name, depth_limit),
+            Ruby::Hash => self.snapshot_hash(value, class_name, depth_limit),
+            Ruby::Object | Ruby::Data | Ruby::Exception => {
+                self.snapshot_ivars(value, class_name, depth_limit)
+            }
+            _ => {
+                let inspect = value.inspect(self);
+                let inspect = String::from_utf8_lossy(&inspect).into_owned();
+                Ok(Snapshot {
+                    class_name,
+                    inspect,
+                    children: Vec::new(),
+                    truncated: false,
+                })
+            }
+        }
+    }
+
+    fn snapshot_array(
+        &mut self,
+        value: Value,
+        class_name: String,
+        depth_limit: usize,
+    ) -> Result<Snapshot, Exception> {
+        if depth_limit == 0 {
+            return Ok(Snapshot {
+                class_name,
+                inspect: String::from("[...]"),
+                children: Vec::new(),
+                truncated: true,
+            });
+        }
+        let elements: Vec<Value> = self.try_convert_mut(value)?;
+        let inspect = format!("Array({})", elements.len());
+        let mut children = Vec::with_capacity(elements.len());
+        for (index, element) in elements.into_iter().enumerate() {
+            let snapshot = self.snapshot_value(element, depth_limit - 1)?;
+            children.push(Child {
+                name: index.to_string(),
+                snapshot,
+            });
+        }
+        Ok(Snapshot {
+            class_name,
+            inspect,
+            children,
+            truncated: false,
+        })
+    }
+
+    fn snapshot_hash(
+        &mut self,
+        value: Value,
+        class_name: String,
+        depth_limit: usize,
+    ) -> Result<Snapshot, Exception> {
+        if depth_limit == 0 {
+            return Ok(Snapshot {
+                class_name,
+                inspect: String::from("{...}"),
+                children: Vec::new(),
+                truncated: true,
+            });
+        }
+        let pairs: Vec<(Value, Value)> = self.try_convert_mut(value)?;
+        let inspect = format!("Hash({})", pairs.len());
+        let mut children = Vec::with_capacity(pairs.len());
+        for (key, val) in pairs {
+            let name = key.inspect(self);
+            let name = String::from_utf8_lossy(&name).into_owned();
+            let snapshot = self.snapshot_value(val, depth_limit - 1)?;
+            children.push(Child { name, snapshot });
+        }
+        Ok(Snapshot {
+            class_name,
+            inspect,
+            children,
+            truncated: false,
+        })
+    }
+
+    fn snapshot_ivars(
+        &mut self,
+        value: Value,
+        class_name: String,
+        depth_limit: usize,
+    ) -> Result<Snapshot, Exception> {
+        let inspect = format!("#<{}>", class_name);
+        if depth_limit == 0 {
+            return Ok(Snapshot {
+                class_name,
+                inspect,
+                children: Vec::new(),
+                truncated: true,
+            });
+        }
+        let ivars = value.funcall(self, "instance_variables", &[], None)?;
+        let ivars: Vec<Value> = self.try_convert_mut(ivars)?;
+        let mut children = Vec::with_capacity(ivars.len());
+        for ivar in ivars {
+            let name = ivar.to_s(self);
+            let name = String::from_utf8_lossy(&name).into_owned();
+            let ivar_value = value.funcall(self, "instance_variable_get", &[ivar], None)?;
+            let snapshot = self.snapshot_value(ivar_value, depth_limit - 1)?;
+            children.push(Child { name, snapshot });
+        }
+        Ok(Snapshot {
+            class_name,
+            inspect,
+            children,
+            truncated: false,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::DEFAULT_DEPTH_LIMIT;
+    use crate::test::prelude::*;
+
+    #[test]
+    fn snapshots_scalar_with_real_inspect() {
+        let mut interp = crate::interpreter().unwrap();
+        let value = interp.eval(b"42").unwrap();
+        let snapshot = interp.snapshot_value(value, DEFAULT_DEPTH_LIMIT).unwrap();
+        assert_eq!(snapshot.class_name(), "Integer");
+        assert_eq!(snapshot.inspect(), "42");
+        assert!(snapshot.children().is_empty());
+        assert!(!snapshot.truncated());
+    }
+
+    #[test]
+    fn snapshots_array_children_without_recursive_inspect() {
+        let mut interp = crate::interpreter().unwrap();
+        let value = interp.eval(b"[1, 'two', :three]").unwrap();
+        let snapshot = interp.snapshot_value(value, DEFAULT_DEPTH_LIMIT).unwrap();
+        assert_eq!(snapshot.class_name(), "Array");
+        assert_eq!(snapshot.children().len(), 3);
+        assert_eq!(snapshot.children()[1].name(), "1");
+        assert_eq!(snapshot.children()[1].snapshot().inspect(), "\"two\"");
+    }
+
+    #[test]
+    fn truncates_at_depth_limit() {
+        let mut interp = crate::interpreter().unwrap();
+        let value = interp.eval(b"[[[1]]]").unwrap();
+        let snapshot = interp.snapshot_value(value, 1).unwrap();
+        assert!(!snapshot.truncated());
+        let child = &snapshot.children()[0];
+        assert!(child.snapshot().truncated());
+        assert!(child.snapshot().children().is_empty());
+    }
+
+    #[test]
+    fn snapshots_object_instance_variables() {
+        let mut interp = crate::interpreter().unwrap();
+        let value = interp
+            .eval(b"Struct.new(:a).new(1).tap { |s| s.instance_variable_set(:@a, 1) }")
+            .unwrap();
+        let snapshot = interp.snapshot_value(value, DEFAULT_DEPTH_LIMIT).unwrap();
+        assert_eq!(snapshot.children().len(), 1);
+        assert_eq!(snapshot.children()[0].name(), "@a");
+        assert_eq!(snapshot.children()[0].snapshot().inspect(), "1");
+    }
+}
@@ -42,6 +42,42 @@ impl Io for Artichoke {
         state.output.write_stdout(b"\n")?;
         Ok(())
     }
+
+    /// Writes the given bytes to the interpreter stderr stream.
+    ///
+    /// This implementation delegates to the underlying output strategy.
+    ///
+    /// # Errors
+    ///
+    /// If the output stream encounters an error, an error is returned.
+    fn write_stderr<T: AsRef<[u8]>>(&mut self, message: T) -> Result<(), Self::Error> {
+        let state = self.state.as_mut().ok_or(InterpreterExtractError)?;
+        state.output.write_stderr(message.as_ref())?;
+        Ok(())
+    }
+
+    /// Reads bytes from the process stdin stream into `buf`.
+    ///
+    /// # Errors
+    ///
+    /// If the input stream encounters an error, an error is returned.
+    fn read_stdin(&mut self, buf: &mut [u8]) -> Result<usize, Self::Error> {
+        let len = io::Read::read(&mut io::stdin(), buf)?;
+        Ok(len)
+    }
+
+    /// Flushes the interpreter's buffered stdout stream.
+    ///
+    /// This implementation delegates to the underlying output strategy.
+    ///
+    /// # Errors
+    ///
+    /// If the output stream encounters an error, an error is returned.
+    fn flush(&mut self) -> Result<(), Self::Error> {
+        let state = self.state.as_mut().ok_or(InterpreterExtractError)?;
+        state.output.flush()?;
+        Ok(())
+    }
 }
 
 #[derive(Debug)]
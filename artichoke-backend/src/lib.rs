@@ -112,22 +112,30 @@ pub mod macros;
 
 mod artichoke;
 pub mod block;
+mod builder;
+pub mod cache_hooks;
+pub mod checkpoint;
 pub mod class;
 pub mod class_registry;
 mod constant;
 pub mod convert;
 pub mod def;
+pub mod env_security;
 mod eval;
+pub mod events;
 pub mod exception;
 pub mod exception_handler;
 pub mod extn;
 pub mod ffi;
+pub mod ffi_panic;
 pub mod fs;
 pub mod gc;
 mod globals;
+pub mod inspector;
 mod intern;
 mod interpreter;
 mod io;
+pub mod literal;
 mod load;
 pub mod method;
 pub mod module;
@@ -135,12 +143,25 @@ pub mod module_registry;
 mod parser;
 #[cfg(feature = "core-random")]
 mod prng;
+pub mod profile;
 mod regexp;
+#[cfg(feature = "interpreter-registry")]
+pub mod registry;
 pub mod release_metadata;
+pub mod replay;
+pub mod require_provider;
+#[cfg(feature = "core-require-remote")]
+pub mod require_remote;
+pub mod require_visibility;
+pub mod secure_context;
+pub mod shared;
+pub mod stack_depth;
 pub mod state;
 pub mod string;
 pub mod sys;
+pub mod terminal_hooks;
 mod top_self;
+pub mod transfer;
 pub mod types;
 pub mod value;
 mod warn;
@@ -149,8 +170,10 @@ mod warn;
 mod test;
 
 pub use crate::artichoke::{Artichoke, Guard};
+pub use crate::builder::Builder;
 pub use crate::exception::{Exception, RubyException};
 pub use crate::interpreter::{interpreter, interpreter_with_config};
+pub use crate::transfer::transfer;
 pub use artichoke_core::prelude as core;
 
 /// A "prelude" for users of the `artichoke-backend` crate.
@@ -167,10 +190,28 @@ pub use artichoke_core::prelude as core;
 pub mod prelude {
     pub use artichoke_core::prelude::*;
 
+    pub use crate::cache_hooks::CacheHooks;
+    pub use crate::env_security::EnvSecurityHooks;
+    pub use crate::events::{EmitOutcome, ListenerError, ListenerToken};
     pub use crate::exception::{raise, Exception, RubyException};
+    pub use crate::exception_handler::UncaughtExceptionHandler;
     pub use crate::extn::core::exception::{Exception as _, *};
     pub use crate::gc::MrbGarbageCollection;
+    pub use crate::inspector::{Child as SnapshotChild, Snapshot};
+    pub use crate::literal::{ArrayLiteral, HashLiteral};
     pub use crate::interpreter::{interpreter, interpreter_with_config};
-    pub use crate::release_metadata::ReleaseMetadata;
-    pub use crate::{Artichoke, Guard};
+    pub use crate::profile::Profile;
+    pub use crate::release_metadata::{
+        artifact_version_stamp, check_artifact_compatible, IncompatibleArtifactVersion,
+        ReleaseMetadata,
+    };
+    pub use crate::replay::{Event, RecordReplay, ReplayError};
+    pub use crate::require_provider::{RequireProvider, Source};
+    #[cfg(feature = "core-require-remote")]
+    pub use crate::require_remote::RemoteFetchHooks;
+    pub use crate::require_visibility::RequireVisibilityHooks;
+    pub use crate::secure_context::SecureContextHooks;
+    pub use crate::shared::{InterpreterGuard, LockError, SharedInterpreter};
+    pub use crate::terminal_hooks::TerminalHooks;
+    pub use crate::{Artichoke, Builder, Guard};
 }
@@ -0,0 +1,101 @@
+//! Ergonomic builders for constructing `Array` and `Hash` literals from Rust.
+//!
+//! Trampolines frequently need to assemble a handful of `Value`s into an
+//! `Array` or `Hash` to return to Ruby, e.g. `Struct#to_a`/`#to_h` or
+//! `Kernel#caller_locations`. Building these by hand means allocating an
+//! intermediate `Vec` and calling [`TryConvertMut`](crate::core::TryConvertMut)
+//! at the end. [`ArrayLiteral`] and [`HashLiteral`] collect elements as they
+//! are pushed and convert to a `Value` in one step, mirroring how a `[...]`
+//! or `{...}` literal reads in the Ruby source being translated.
+
+use crate::core::{ConvertMut, TryConvertMut};
+use crate::exception::Exception;
+use crate::value::Value;
+use crate::Artichoke;
+
+/// A builder for an `Array` literal.
+///
+/// # Examples
+///
+/// ```
+/// # use artichoke_backend::literal::ArrayLiteral;
+/// # use artichoke_backend::prelude::*;
+/// # fn example() -> Result<(), Exception> {
+/// let mut interp = artichoke_backend::interpreter()?;
+/// let one = interp.convert(1);
+/// let two = interp.convert(2);
+/// let ary = ArrayLiteral::new().push(one).push(two).try_into_value(&mut interp)?;
+/// assert_eq!(ary.funcall(&mut interp, "length", &[], None)?.try_into::<i64>(&interp)?, 2);
+/// # Ok(())
+/// # }
+/// # example().unwrap();
+/// ```
+#[derive(Debug, Default, Clone)]
+pub struct ArrayLiteral(Vec<Value>);
+
+impl ArrayLiteral {
+    /// Create an empty `Array` literal builder.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Append a `Value` to the literal.
+    #[must_use]
+    pub fn push(mut self, value: Value) -> Self {
+        self.0.push(value);
+        self
+    }
+
+    /// Consume the builder and convert the collected elements to a Ruby
+    /// `Array`.
+    ///
+    /// # Errors
+    ///
+    /// If the underlying conversion to a Ruby `Array` fails, an error is
+    /// returned.
+    pub fn try_into_value(self, interp: &mut Artichoke) -> Result<Value, Exception> {
+        interp.try_convert_mut(self.0)
+    }
+}
+
+/// A builder for a `Hash` literal.
+///
+/// # Examples
+///
+/// ```
+/// # use artichoke_backend::literal::HashLiteral;
+/// # use artichoke_backend::prelude::*;
+/// # fn example() -> Result<(), Exception> {
+/// let mut interp = artichoke_backend::interpreter()?;
+/// let key = interp.convert_mut("a");
+/// let value = interp.convert(1);
+/// let hash = HashLiteral::new().pair(key, value).try_into_value(&mut interp);
+/// assert_eq!(hash.funcall(&mut interp, "length", &[], None)?.try_into::<i64>(&interp)?, 1);
+/// # Ok(())
+/// # }
+/// # example().unwrap();
+/// ```
+#[derive(Debug, Default, Clone)]
+pub struct HashLiteral(Vec<(Value, Value)>);
+
+impl HashLiteral {
+    /// Create an empty `Hash` literal builder.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Append a key-value pair to the literal.
+    #[must_use]
+    pub fn pair(mut self, key: Value, value: Value) -> Self {
+        self.0.push((key, value));
+        self
+    }
+
+    /// Consume the builder and convert the collected pairs to a Ruby `Hash`.
+    #[must_use]
+    pub fn try_into_value(self, interp: &mut Artichoke) -> Value {
+        interp.convert_mut(self.0)
+    }
+}
@@ -1,7 +1,7 @@
 use std::borrow::Cow;
-use std::path::Path;
+use std::path::{Path, PathBuf};
 
-use crate::core::{Eval, File, LoadSources};
+use crate::core::{Eval, File, LoadSources, Warn};
 use crate::exception::Exception;
 use crate::ffi::InterpreterExtractError;
 use crate::fs::RUBY_LOAD_PATH;
@@ -87,36 +87,173 @@ impl LoadSources for Artichoke {
     where
         P: AsRef<Path>,
     {
+        let path = path.as_ref();
         {
             let state = self.state.as_mut().ok_or(InterpreterExtractError)?;
             // If a file is already required, short circuit.
-            if state.vfs.is_required(path.as_ref()) {
+            if state.vfs.is_required(path) {
                 return Ok(false);
             }
+            if !state.require_lock.begin(path) {
+                // A require of `path` is already running higher up this call
+                // stack. Warn and return without evaluating `path` again,
+                // the same way MRI's `require` handles a circular require,
+                // rather than recursing until the first require ever
+                // finishes.
+                let message = format!(
+                    "loading in progress, circular require considered harmful - {}",
+                    path.display()
+                );
+                self.warn(message.as_bytes())?;
+                return Ok(false);
+            }
+        }
+        let result = self.require_source_with_lock_held(path);
+        if let Some(state) = self.state.as_mut() {
+            state.require_lock.finish(path);
+        }
+        result
+    }
+
+    fn read_source_file_contents<P>(&self, path: P) -> Result<Cow<'_, [u8]>, Self::Error>
+    where
+        P: AsRef<Path>,
+    {
+        let state = self.state.as_ref().ok_or(InterpreterExtractError)?;
+        let contents = state.vfs.read_file(path.as_ref())?;
+        Ok(contents.to_vec().into())
+    }
+
+    fn undef_source<P>(&mut self, path: P) -> Result<bool, Self::Error>
+    where
+        P: AsRef<Path>,
+    {
+        let state = self.state.as_mut().ok_or(InterpreterExtractError)?;
+        let mut path = path.as_ref();
+        let absolute_path;
+        if path.is_relative() {
+            absolute_path = Path::new(RUBY_LOAD_PATH).join(path);
+            path = &absolute_path;
+        }
+        let existed = state.vfs.remove_file(path)?;
+        trace!(
+            "Removed source from interpreter filesystem -- {}",
+            path.display()
+        );
+        Ok(existed)
+    }
+
+    fn replace_rb_source_file<P, T>(&mut self, path: P, contents: T) -> Result<(), Self::Error>
+    where
+        P: AsRef<Path>,
+        T: Into<Cow<'static, [u8]>>,
+    {
+        let state = self.state.as_mut().ok_or(InterpreterExtractError)?;
+        let mut path = path.as_ref();
+        let absolute_path;
+        if path.is_relative() {
+            absolute_path = Path::new(RUBY_LOAD_PATH).join(path);
+            path = &absolute_path;
+        }
+        state.vfs.write_file(path, contents.into())?;
+        state.vfs.unmark_required(path)?;
+        trace!(
+            "Replaced Ruby source on interpreter filesystem -- {}",
+            path.display()
+        );
+        Ok(())
+    }
+
+    fn sources(&self) -> Result<Vec<PathBuf>, Self::Error> {
+        let state = self.state.as_ref().ok_or(InterpreterExtractError)?;
+        Ok(state.vfs.paths())
+    }
+}
+
+impl Artichoke {
+    /// The body of [`LoadSources::require_source`], run with `path` already
+    /// marked in progress in `state.require_lock`.
+    ///
+    /// Split out so `require_source` can unconditionally release the lock
+    /// via `state.require_lock.finish` after calling this, including on the
+    /// early returns an error from `hook`/`eval` here would otherwise skip.
+    fn require_source_with_lock_held(&mut self, path: &Path) -> Result<bool, Exception> {
+        {
+            let state = self.state.as_mut().ok_or(InterpreterExtractError)?;
             // Require Rust `File` first because an File may define classes and
             // modules with `LoadSources` and Ruby files can require arbitrary
             // other files, including some child sources that may depend on these
             // module definitions.
-            let hook = state.vfs.get_extension(path.as_ref());
+            let hook = state.vfs.get_extension(path);
             if let Some(hook) = hook {
                 // dynamic, Rust-backed `File` require
                 hook(self)?;
             }
         }
-        let contents = self.read_source_file_contents(path.as_ref())?.into_owned();
+        let contents = self.read_source_file_contents(path)?.into_owned();
+        let hash = crate::state::reload::State::content_hash(&contents);
         self.eval(contents.as_ref())?;
         let state = self.state.as_mut().ok_or(InterpreterExtractError)?;
-        state.vfs.mark_required(path.as_ref())?;
-        trace!(r#"Successful require of {}"#, path.as_ref().display());
+        state.vfs.mark_required(path)?;
+        state.source_hashes.record(path, hash);
+        trace!(r#"Successful require of {}"#, path.display());
         Ok(true)
     }
+}
 
-    fn read_source_file_contents<P>(&self, path: P) -> Result<Cow<'_, [u8]>, Self::Error>
-    where
-        P: AsRef<Path>,
-    {
-        let state = self.state.as_ref().ok_or(InterpreterExtractError)?;
-        let contents = state.vfs.read_file(path.as_ref())?;
-        Ok(contents.to_vec().into())
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+    use std::thread;
+
+    use crate::shared::SharedInterpreter;
+    use crate::test::prelude::*;
+
+    #[test]
+    fn circular_require_does_not_recurse_forever() {
+        let mut interp = crate::interpreter().unwrap();
+        interp
+            .def_rb_source_file(
+                "circular_a.rb",
+                &b"$circular_a_runs ||= 0\n$circular_a_runs += 1\nrequire 'circular_b'\n"[..],
+            )
+            .unwrap();
+        interp
+            .def_rb_source_file(
+                "circular_b.rb",
+                &b"$circular_b_runs ||= 0\n$circular_b_runs += 1\nrequire 'circular_a'\n"[..],
+            )
+            .unwrap();
+        interp.eval(b"require 'circular_a'").unwrap();
+        let a_runs = interp.eval(b"$circular_a_runs").unwrap();
+        let a_runs = a_runs.try_into::<i64>(&interp).unwrap();
+        let b_runs = interp.eval(b"$circular_b_runs").unwrap();
+        let b_runs = b_runs.try_into::<i64>(&interp).unwrap();
+        assert_eq!(a_runs, 1);
+        assert_eq!(b_runs, 1);
+    }
+
+    #[test]
+    fn concurrent_require_of_same_feature_evaluates_exactly_once() {
+        let mut interp = crate::interpreter().unwrap();
+        interp
+            .def_rb_source_file("counted.rb", &b"$counted_runs ||= 0\n$counted_runs += 1\n"[..])
+            .unwrap();
+        let shared = Arc::new(SharedInterpreter::new(interp));
+        let handles: Vec<_> = (0..8)
+            .map(|_| {
+                let shared = Arc::clone(&shared);
+                thread::spawn(move || {
+                    let mut guard = shared.lock().unwrap();
+                    let result = guard.eval(b"require 'counted'").unwrap();
+                    result.try_into::<bool>(&guard).unwrap()
+                })
+            })
+            .collect();
+        let results: Vec<bool> = handles.into_iter().map(|h| h.join().unwrap()).collect();
+        assert_eq!(results.iter().filter(|&&required| required).count(), 1);
+        let mut guard = shared.lock().unwrap();
+        let runs = guard.eval(b"$counted_runs").unwrap();
+        assert_eq!(runs.try_into::<i64>(&guard).unwrap(), 1);
     }
 }
@@ -33,6 +33,39 @@ macro_rules! unwrap_interpreter {
     };
 }
 
+/// Call into the `trampoline` module and contain any Rust panic it raises.
+///
+/// Unwinding a Rust panic across an `extern "C"` boundary into the mruby VM is
+/// undefined behavior. This macro evaluates `$body` (which must borrow
+/// `$guard` rather than consume it, and evaluate to a
+/// `Result<Value, Exception>`) inside [`std::panic::catch_unwind`]. A
+/// returned `Err` or a caught panic both raise a Ruby exception via
+/// `$guard`; a panic is reported as a `fatal`
+/// [`PanicError`](crate::ffi_panic::PanicError) carrying the panic message
+/// instead of letting the panic unwind into C.
+///
+/// Every `extn` trampoline that evaluates to `Result<Value, Exception>` goes
+/// through this macro. The two kinds of `extern "C"` functions that do not
+/// are out of scope: the low-level `mrb_intern*`/`mrb_sym_*` shims in
+/// `extn::core::symbol::ffi` reimplement mruby's public C API and return raw
+/// `mrb_sym`/`mrb_value` sentinels on failure rather than raising, so there
+/// is no `Result` to catch; and the `#[cfg(test)]` fixtures in
+/// `extn::core::exception::mod` exist only to unconditionally raise a
+/// specific exception for a test to assert on.
+#[macro_export]
+macro_rules! ffi_catch_unwind {
+    ($guard:expr, $body:expr) => {{
+        match std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| $body)) {
+            Ok(Ok(value)) => value.inner(),
+            Ok(Err(exception)) => $crate::exception::raise($guard, exception),
+            Err(payload) => {
+                let message = $crate::ffi_panic::panic_message(payload.as_ref());
+                $crate::exception::raise($guard, $crate::ffi_panic::PanicError::new(message))
+            }
+        }
+    }};
+}
+
 #[doc(hidden)]
 pub mod argspec {
     pub const NONE: &[u8] = b"\0";
@@ -40,12 +73,14 @@ pub mod argspec {
     pub const OPT1: &[u8] = b"|o\0";
     pub const REQ1_OPT1: &[u8] = b"o|o\0";
     pub const REQ1_OPT2: &[u8] = b"o|oo\0";
+    pub const OPTBLOCK: &[u8] = b"&\0";
     pub const REQ1_REQBLOCK: &[u8] = b"o&\0";
     pub const REQ1_REQBLOCK_OPT1: &[u8] = b"o&|o?\0";
     pub const REQ2: &[u8] = b"oo\0";
     pub const OPT2_OPTBLOCK: &[u8] = b"&|o?o?\0";
     pub const REQ2_OPT1: &[u8] = b"oo|o\0";
     pub const REST: &[u8] = b"*\0";
+    pub const REST_BLOCK: &[u8] = b"*&\0";
 }
 
 /// Extract [`sys::mrb_value`]s from a [`sys::mrb_state`] to adapt a C
@@ -145,6 +180,16 @@ macro_rules! mrb_get_args {
             _ => unreachable!("mrb_get_args should have raised"),
         }
     }};
+    ($mrb:expr, &block) => {{
+        let mut block = std::mem::MaybeUninit::<$crate::sys::mrb_value>::uninit();
+        $crate::sys::mrb_get_args(
+            $mrb,
+            $crate::macros::argspec::OPTBLOCK.as_ptr() as *const i8,
+            block.as_mut_ptr(),
+        );
+        let block = block.assume_init();
+        $crate::block::Block::new(block)
+    }};
     ($mrb:expr, required = 1, &block) => {{
         let mut req1 = std::mem::MaybeUninit::<$crate::sys::mrb_value>::uninit();
         let mut block = std::mem::MaybeUninit::<$crate::sys::mrb_value>::uninit();
@@ -287,4 +332,19 @@ macro_rules! mrb_get_args {
         );
         std::slice::from_raw_parts(args.assume_init(), count.assume_init())
     }};
+    ($mrb:expr, *args, &block) => {{
+        let mut args = std::mem::MaybeUninit::<*const $crate::sys::mrb_value>::uninit();
+        let mut count = std::mem::MaybeUninit::<usize>::uninit();
+        let mut block = std::mem::MaybeUninit::<$crate::sys::mrb_value>::uninit();
+        let _argc = $crate::sys::mrb_get_args(
+            $mrb,
+            $crate::macros::argspec::REST_BLOCK.as_ptr() as *const i8,
+            args.as_mut_ptr(),
+            count.as_mut_ptr(),
+            block.as_mut_ptr(),
+        );
+        let args = std::slice::from_raw_parts(args.assume_init(), count.assume_init());
+        let block = block.assume_init();
+        (args, $crate::block::Block::new(block))
+    }};
 }
@@ -11,12 +11,20 @@ impl Prng for Artichoke {
     type Int = Int;
     type Float = Fp;
 
+    // `rand_int` and `rand_float` below draw directly from the backend's own
+    // RNG state rather than routing through `prng_fill_bytes`, so only byte
+    // draws made through this method -- e.g. `Random#bytes` -- are captured
+    // by `replay`.
     fn prng_fill_bytes(&mut self, buf: &mut [u8]) -> Result<(), Self::Error> {
+        if self.replay_rng(buf)? {
+            return Ok(());
+        }
         self.state
             .as_mut()
             .ok_or(InterpreterExtractError)?
             .prng
             .bytes(buf);
+        self.record_rng(buf);
         Ok(())
     }
 
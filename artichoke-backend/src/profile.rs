@@ -0,0 +1,108 @@
+use crate::env_security::EnvSecurityHooks;
+use crate::exception::Exception;
+use crate::extn::core::env::backend::EnvArgumentError;
+use crate::secure_context::SecureContextHooks;
+use crate::Artichoke;
+
+/// A preset bundle of the interpreter's runtime-configurable resource
+/// limits and security hooks, selected with one value instead of calling
+/// each of [`Artichoke::set_recursion_depth_limit`](crate::Artichoke::set_recursion_depth_limit),
+/// [`Artichoke::set_secure_context_hooks`](crate::Artichoke::set_secure_context_hooks),
+/// and [`Artichoke::set_env_security_hooks`](crate::Artichoke::set_env_security_hooks)
+/// individually.
+///
+/// Select a profile with [`Builder::with_profile`](crate::Builder::with_profile);
+/// the active profile is readable from Ruby as `Artichoke::VM.profile`.
+///
+/// # Scope
+///
+/// `Profile` only governs state that is already configurable on a built
+/// interpreter -- the recursion depth limit, `ENV` security hooks, and
+/// `SecureContext` hooks. It cannot select which `extn` modules are
+/// compiled in (that is fixed at compile time by this crate's Cargo
+/// features, see `Cargo.toml`) or swap a backend like the `Regexp` engine,
+/// so a profile that wanted `Kernel#require` compiled out entirely needs
+/// its own Cargo feature combination, not a runtime switch. `Sandbox`'s
+/// `require`/`load` restriction is also bounded by
+/// [`SecureContext`](crate::secure_context::SecureContext) itself: it only
+/// denies paths the host has flagged untrusted with
+/// [`Artichoke::mark_untrusted`](crate::Artichoke::mark_untrusted), not
+/// every `require` call, so a complete sandbox still depends on the host
+/// flagging untrusted input at its own `ENV`/`IO`/argv boundary.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Profile {
+    /// Artichoke's built-in defaults: no recursion depth limit beyond
+    /// [`stack_depth::DEFAULT_MAX_DEPTH`](crate::stack_depth::DEFAULT_MAX_DEPTH),
+    /// and no `ENV`/`SecureContext` restrictions. This is the profile
+    /// [`Builder`](crate::Builder) uses when none is selected.
+    Full,
+    /// A tighter recursion depth limit so a runaway script exhausts its
+    /// call budget well before it risks exhausting the host's native
+    /// stack, denies all writes to `ENV`, and denies `require`/`load` of
+    /// any path flagged untrusted with
+    /// [`Artichoke::mark_untrusted`](crate::Artichoke::mark_untrusted).
+    /// Intended for running scripts from an untrusted or multi-tenant
+    /// source.
+    Sandbox,
+    /// A moderate recursion depth limit sized for a host thread with a
+    /// smaller native stack than a typical 8MB process thread (for
+    /// example, a thread spawned with a reduced stack size in a larger
+    /// host application), with no `ENV`/`SecureContext` restrictions.
+    /// Intended for a host that supplies its own trusted scripts.
+    Embedded,
+}
+
+impl Default for Profile {
+    fn default() -> Self {
+        Self::Full
+    }
+}
+
+impl Profile {
+    /// The name of this profile as exposed to Ruby by `Artichoke::VM.profile`.
+    #[must_use]
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Full => "full",
+            Self::Sandbox => "sandbox",
+            Self::Embedded => "embedded",
+        }
+    }
+
+    /// Apply this profile's recursion depth limit and security hooks to
+    /// `interp`.
+    pub(crate) fn apply(self, interp: &mut Artichoke) -> Result<(), Exception> {
+        match self {
+            Self::Full => {}
+            Self::Sandbox => {
+                interp.set_recursion_depth_limit(128)?;
+                interp.set_env_security_hooks(EnvSecurityHooks {
+                    validate_write: deny_all_env_writes,
+                    ..EnvSecurityHooks::default()
+                })?;
+                interp.set_secure_context_hooks(SecureContextHooks {
+                    check_sink: deny_untrusted_require,
+                })?;
+            }
+            Self::Embedded => {
+                interp.set_recursion_depth_limit(256)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+fn deny_all_env_writes(_name: &[u8], _value: Option<&[u8]>) -> Result<(), EnvArgumentError> {
+    Err(EnvArgumentError::from(
+        "ENV is read-only under the sandbox profile",
+    ))
+}
+
+fn deny_untrusted_require(sink: &str, value: &[u8]) -> Result<(), String> {
+    let mut message = String::from("cannot ");
+    message.push_str(sink);
+    message.push_str(" untrusted path ");
+    message.push_str(&String::from_utf8_lossy(value));
+    message.push_str(" under the sandbox profile");
+    Err(message)
+}
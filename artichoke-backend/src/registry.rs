@@ -0,0 +1,56 @@
+//! A process-global registry of live [`Artichoke`](crate::Artichoke)
+//! interpreters.
+//!
+//! This module is gated behind the `interpreter-registry` feature. When
+//! enabled, every interpreter created by [`interpreter`](crate::interpreter)
+//! is assigned a unique, process-wide id and registered here for the
+//! lifetime of the interpreter. Host observability tooling can use
+//! [`iter`] to enumerate the ids of interpreters that are currently alive.
+//!
+//! The registry only tracks ids; it does not hand out references to the
+//! interpreters themselves, since `Artichoke` is not `Sync` and may be
+//! moved across an FFI boundary at any time.
+
+use std::collections::BTreeSet;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::Mutex;
+
+use once_cell::sync::Lazy;
+
+static NEXT_ID: AtomicU64 = AtomicU64::new(1);
+
+static LIVE_INTERPRETERS: Lazy<Mutex<BTreeSet<u64>>> = Lazy::new(|| Mutex::new(BTreeSet::new()));
+
+/// Reserve the next unique interpreter id and mark it as live.
+///
+/// This function is called once per interpreter, when the interpreter is
+/// created.
+pub(crate) fn register() -> u64 {
+    let id = NEXT_ID.fetch_add(1, Ordering::Relaxed);
+    if let Ok(mut live) = LIVE_INTERPRETERS.lock() {
+        live.insert(id);
+    }
+    id
+}
+
+/// Remove an interpreter id from the live set.
+///
+/// This function is called once per interpreter, when the interpreter is
+/// closed.
+pub(crate) fn deregister(id: u64) {
+    if let Ok(mut live) = LIVE_INTERPRETERS.lock() {
+        live.remove(&id);
+    }
+}
+
+/// Enumerate the ids of all interpreters that are currently live in this
+/// process.
+///
+/// The returned ids are sorted in ascending order of creation.
+#[must_use]
+pub fn iter() -> Vec<u64> {
+    LIVE_INTERPRETERS
+        .lock()
+        .map(|live| live.iter().copied().collect())
+        .unwrap_or_default()
+}
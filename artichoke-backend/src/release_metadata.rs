@@ -1,4 +1,8 @@
+use std::error;
+use std::fmt;
+
 use crate::core;
+use crate::sys;
 
 #[derive(Debug, Clone, Copy, Hash, PartialEq, Eq, PartialOrd, Ord)]
 pub struct ReleaseMetadata<'a> {
@@ -24,7 +28,7 @@ impl<'a> Default for ReleaseMetadata<'a> {
             patchlevel: "0",
             platform: "host",
             release_date: "",
-            revision: "1",
+            revision: "unknown",
             ruby_version: "2.6.3",
             compiler_version: Some("rustc"),
         }
@@ -139,3 +143,104 @@ impl<'a> ReleaseMetadata<'a> {
         self
     }
 }
+
+/// Version/ABI stamp for the running Artichoke + mruby build, for embedding
+/// in artifacts -- compiled bytecode, VM snapshots, or other binary formats
+/// -- that are only safe to load back into a build with a matching stamp.
+///
+/// The stamp pairs this crate's own version with the vendored mruby
+/// release (both baked into the binary, via [`sys::mrb_sys_mruby_version`]'s
+/// verbose form), since an artifact produced by either a different
+/// `artichoke-backend` or a different mruby release is not guaranteed to
+/// share this build's `mrb_irep`/object layout.
+///
+/// This crate does not itself dump or load compiled bytecode or VM
+/// snapshots -- the vendored mruby's `mrb_dump_irep`/`mrb_read_irep` are not
+/// currently wired up through [`sys`] -- so there is no in-tree artifact
+/// format to check this stamp against yet. This function and
+/// [`check_artifact_compatible`] are the compatibility primitive an
+/// embedder building such a format on top of [`sys`] would stamp its
+/// artifacts with and check before loading, rather than each embedder
+/// inventing its own version-negotiation scheme.
+#[must_use]
+pub fn artifact_version_stamp() -> String {
+    sys::mrb_sys_mruby_version(true)
+}
+
+/// Returned by [`check_artifact_compatible`] when an artifact's
+/// [`artifact_version_stamp`] does not match this build's.
+#[derive(Debug, Clone, Hash, PartialEq, Eq, PartialOrd, Ord)]
+pub struct IncompatibleArtifactVersion {
+    expected: String,
+    found: String,
+}
+
+impl IncompatibleArtifactVersion {
+    /// The [`artifact_version_stamp`] this build expects.
+    #[must_use]
+    pub fn expected(&self) -> &str {
+        &self.expected
+    }
+
+    /// The stamp the artifact was actually built with.
+    #[must_use]
+    pub fn found(&self) -> &str {
+        &self.found
+    }
+}
+
+impl fmt::Display for IncompatibleArtifactVersion {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "incompatible artifact version: expected `{}`, found `{}`",
+            self.expected, self.found
+        )
+    }
+}
+
+impl error::Error for IncompatibleArtifactVersion {}
+
+/// Refuse to load an artifact whose [`artifact_version_stamp`] does not
+/// match this build's.
+///
+/// Compiled bytecode and VM snapshots generally embed raw pointers, symbol
+/// tables, and struct layouts specific to the mruby release and
+/// `artichoke-backend` version that produced them; loading one from an
+/// incompatible build risks corrupting the VM rather than failing cleanly.
+/// Checking `stamp` up front turns that into an ordinary, recoverable
+/// error.
+///
+/// # Errors
+///
+/// Returns [`IncompatibleArtifactVersion`] if `stamp` does not match
+/// [`artifact_version_stamp`].
+pub fn check_artifact_compatible(stamp: &str) -> Result<(), IncompatibleArtifactVersion> {
+    let expected = artifact_version_stamp();
+    if stamp == expected {
+        Ok(())
+    } else {
+        Err(IncompatibleArtifactVersion {
+            expected,
+            found: stamp.to_string(),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{artifact_version_stamp, check_artifact_compatible};
+
+    #[test]
+    fn matching_stamp_is_compatible() {
+        let stamp = artifact_version_stamp();
+        assert!(check_artifact_compatible(&stamp).is_ok());
+    }
+
+    #[test]
+    fn mismatched_stamp_is_incompatible() {
+        let err = check_artifact_compatible("artichoke-mruby bogus [0.0.0]").unwrap_err();
+        assert_eq!(err.expected(), artifact_version_stamp());
+        assert_eq!(err.found(), "artichoke-mruby bogus [0.0.0]");
+    }
+}
@@ -0,0 +1,430 @@
+//! Deterministic replay recording.
+//!
+//! A host embedding this interpreter can call [`Artichoke::start_recording`]
+//! to capture the inputs that make an interpreter session
+//! non-deterministic -- the code passed to `eval`, bytes drawn from the
+//! PRNG, wall clock reads, and `ENV` reads -- to a [`Vec<Event>`](Event).
+//! Saving that tape alongside a production bug report and feeding it back
+//! with [`Artichoke::start_replaying`] on a fresh interpreter in development
+//! reproduces the exact sequence of inputs the original session saw, even
+//! though the real clock, environment, and random source available in
+//! development are different.
+//!
+//! Recording and replaying are both opt-in and off by default; a `State`
+//! that never calls either pays only the cost of matching on
+//! [`RecordReplay::Off`] at each instrumented call site.
+
+use std::collections::VecDeque;
+use std::error;
+use std::fmt;
+
+use crate::exception::Exception;
+use crate::extn::core::exception::Fatal;
+use crate::ffi::InterpreterExtractError;
+use crate::Artichoke;
+
+/// One recorded non-deterministic input.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum Event {
+    /// The raw source passed to a top-level `eval`.
+    Eval(Vec<u8>),
+    /// Bytes drawn from the interpreter's PRNG to satisfy one `rand`-family
+    /// call.
+    Rng(Vec<u8>),
+    /// A `Time.now` read, as a local-time `(unix seconds, subsec
+    /// nanoseconds)` pair.
+    Clock(i64, u32),
+    /// An `ENV[key]` read and the value it returned (`None` for a missing
+    /// key).
+    EnvRead {
+        key: Vec<u8>,
+        value: Option<Vec<u8>>,
+    },
+}
+
+impl Event {
+    fn kind(&self) -> &'static str {
+        match self {
+            Self::Eval(_) => "eval",
+            Self::Rng(_) => "rng",
+            Self::Clock(..) => "clock",
+            Self::EnvRead { .. } => "env read",
+        }
+    }
+}
+
+/// Appends [`Event`]s as an interpreter session runs.
+///
+/// Retrieve the recorded tape with [`Artichoke::stop_recording`].
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Recorder {
+    events: Vec<Event>,
+}
+
+impl Recorder {
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    fn push(&mut self, event: Event) {
+        self.events.push(event);
+    }
+}
+
+/// Re-feeds a previously recorded tape of [`Event`]s in order.
+///
+/// Construct one from a saved [`Vec<Event>`](Event) and install it with
+/// [`Artichoke::start_replaying`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Replay {
+    events: VecDeque<Event>,
+}
+
+impl Replay {
+    #[must_use]
+    pub fn new(events: Vec<Event>) -> Self {
+        Self {
+            events: events.into(),
+        }
+    }
+
+    /// Number of events remaining on the tape.
+    #[must_use]
+    pub fn remaining(&self) -> usize {
+        self.events.len()
+    }
+
+    fn next(&mut self, expected_kind: &'static str) -> Result<Event, ReplayError> {
+        let event = self
+            .events
+            .pop_front()
+            .ok_or(ReplayError::Exhausted { expected_kind })?;
+        if event.kind() == expected_kind {
+            Ok(event)
+        } else {
+            Err(ReplayError::Divergence {
+                expected_kind,
+                found_kind: event.kind(),
+            })
+        }
+    }
+}
+
+/// Per-interpreter deterministic replay mode.
+///
+/// Defaults to [`Off`](Self::Off). Switch modes with
+/// [`Artichoke::start_recording`], [`Artichoke::start_replaying`], and
+/// [`Artichoke::stop_recording`]/[`Artichoke::stop_replaying`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum RecordReplay {
+    /// Neither recording nor replaying; instrumented call sites are no-ops.
+    Off,
+    /// Appending [`Event`]s as they occur.
+    Recording(Recorder),
+    /// Consuming a previously recorded tape instead of consulting the real
+    /// clock, PRNG, or environment.
+    Replaying(Replay),
+}
+
+impl Default for RecordReplay {
+    fn default() -> Self {
+        Self::Off
+    }
+}
+
+impl RecordReplay {
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+/// A recorded replay tape diverged from what the interpreter actually did,
+/// or ran out of recorded events.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum ReplayError {
+    /// The replay tape's next event is a different kind than what the
+    /// interpreter is replaying right now, meaning this session is not
+    /// following the same sequence of operations the tape was recorded
+    /// from.
+    Divergence {
+        expected_kind: &'static str,
+        found_kind: &'static str,
+    },
+    /// The interpreter asked to replay an event but the tape was already
+    /// empty.
+    Exhausted { expected_kind: &'static str },
+}
+
+impl fmt::Display for ReplayError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Divergence {
+                expected_kind,
+                found_kind,
+            } => write!(
+                f,
+                "replay divergence: expected next a {} event, but the tape's next event is {}",
+                expected_kind, found_kind
+            ),
+            Self::Exhausted { expected_kind } => write!(
+                f,
+                "replay exhausted: expected a {} event but the tape is empty",
+                expected_kind
+            ),
+        }
+    }
+}
+
+impl error::Error for ReplayError {}
+
+impl From<ReplayError> for Exception {
+    fn from(err: ReplayError) -> Self {
+        Fatal::from(err.to_string()).into()
+    }
+}
+
+impl Artichoke {
+    /// Begin recording [`Event`]s for this interpreter.
+    ///
+    /// Replaces any tape already being recorded or replayed.
+    pub fn start_recording(&mut self) -> Result<(), InterpreterExtractError> {
+        let state = self.state.as_mut().ok_or(InterpreterExtractError)?;
+        state.record_replay = RecordReplay::Recording(Recorder::new());
+        Ok(())
+    }
+
+    /// Stop recording and return the captured tape, or `None` if this
+    /// interpreter was not recording.
+    pub fn stop_recording(&mut self) -> Option<Vec<Event>> {
+        let state = self.state.as_mut()?;
+        match std::mem::replace(&mut state.record_replay, RecordReplay::Off) {
+            RecordReplay::Recording(recorder) => Some(recorder.events),
+            other => {
+                state.record_replay = other;
+                None
+            }
+        }
+    }
+
+    /// Begin replaying a previously recorded tape for this interpreter.
+    ///
+    /// Replaces any tape already being recorded or replayed.
+    pub fn start_replaying(&mut self, events: Vec<Event>) -> Result<(), InterpreterExtractError> {
+        let state = self.state.as_mut().ok_or(InterpreterExtractError)?;
+        state.record_replay = RecordReplay::Replaying(Replay::new(events));
+        Ok(())
+    }
+
+    /// Stop replaying, discarding whatever events remained on the tape.
+    pub fn stop_replaying(&mut self) {
+        if let Some(state) = self.state.as_mut() {
+            state.record_replay = RecordReplay::Off;
+        }
+    }
+
+    /// Record an `eval` input, if recording.
+    pub(crate) fn record_eval(&mut self, code: &[u8]) {
+        if let Some(state) = self.state.as_mut() {
+            if let RecordReplay::Recording(recorder) = &mut state.record_replay {
+                recorder.push(Event::Eval(code.to_vec()));
+            }
+        }
+    }
+
+    /// If replaying, assert the next tape event is the `eval` of `code`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the tape diverges or is exhausted.
+    pub(crate) fn replay_eval(&mut self, code: &[u8]) -> Result<(), Exception> {
+        let state = match self.state.as_mut() {
+            Some(state) => state,
+            None => return Ok(()),
+        };
+        if let RecordReplay::Replaying(replay) = &mut state.record_replay {
+            match replay.next("eval")? {
+                Event::Eval(recorded) => {
+                    if recorded == code {
+                        Ok(())
+                    } else {
+                        Err(Fatal::from(
+                            "replay divergence: eval input did not match the recorded tape",
+                        )
+                        .into())
+                    }
+                }
+                _ => unreachable!("Replay::next checked the event kind"),
+            }
+        } else {
+            Ok(())
+        }
+    }
+
+    /// Record a PRNG draw, if recording.
+    pub(crate) fn record_rng(&mut self, buf: &[u8]) {
+        if let Some(state) = self.state.as_mut() {
+            if let RecordReplay::Recording(recorder) = &mut state.record_replay {
+                recorder.push(Event::Rng(buf.to_vec()));
+            }
+        }
+    }
+
+    /// If replaying, consume the next tape event and fill `buf` from it
+    /// instead of drawing from the real PRNG.
+    ///
+    /// Returns `Ok(true)` if `buf` was filled from the tape, `Ok(false)` if
+    /// this interpreter is not replaying and the real PRNG should be used.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`ReplayError`] if the tape diverges, is exhausted, or the
+    /// recorded draw is a different length than `buf`.
+    pub(crate) fn replay_rng(&mut self, buf: &mut [u8]) -> Result<bool, Exception> {
+        let state = match self.state.as_mut() {
+            Some(state) => state,
+            None => return Ok(false),
+        };
+        if let RecordReplay::Replaying(replay) = &mut state.record_replay {
+            let recorded = match replay.next("rng")? {
+                Event::Rng(recorded) => recorded,
+                _ => unreachable!("Replay::next checked the event kind"),
+            };
+            if recorded.len() != buf.len() {
+                return Err(Fatal::from("replayed RNG draw length mismatch").into());
+            }
+            buf.copy_from_slice(&recorded);
+            Ok(true)
+        } else {
+            Ok(false)
+        }
+    }
+
+    /// Record a clock read, if recording.
+    pub(crate) fn record_clock(&mut self, unix_seconds: i64, subsec_nanos: u32) {
+        if let Some(state) = self.state.as_mut() {
+            if let RecordReplay::Recording(recorder) = &mut state.record_replay {
+                recorder.push(Event::Clock(unix_seconds, subsec_nanos));
+            }
+        }
+    }
+
+    /// If replaying, consume the next tape event as a clock read.
+    ///
+    /// Returns the recorded `(unix seconds, subsec nanoseconds)` pair, or
+    /// `None` if this interpreter is not replaying and the real clock
+    /// should be consulted.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`ReplayError`] if the tape diverges or is exhausted.
+    pub(crate) fn replay_clock(&mut self) -> Result<Option<(i64, u32)>, Exception> {
+        let state = match self.state.as_mut() {
+            Some(state) => state,
+            None => return Ok(None),
+        };
+        if let RecordReplay::Replaying(replay) = &mut state.record_replay {
+            match replay.next("clock")? {
+                Event::Clock(secs, nanos) => Ok(Some((secs, nanos))),
+                _ => unreachable!("Replay::next checked the event kind"),
+            }
+        } else {
+            Ok(None)
+        }
+    }
+
+    /// Record an `ENV` read, if recording.
+    pub(crate) fn record_env_read(&mut self, key: &[u8], value: Option<&[u8]>) {
+        if let Some(state) = self.state.as_mut() {
+            if let RecordReplay::Recording(recorder) = &mut state.record_replay {
+                recorder.push(Event::EnvRead {
+                    key: key.to_vec(),
+                    value: value.map(<[u8]>::to_vec),
+                });
+            }
+        }
+    }
+
+    /// If replaying, consume the next tape event and return its recorded
+    /// `ENV` value instead of reading the real environment.
+    ///
+    /// Returns `Ok(None)` if this interpreter is not replaying and the real
+    /// environment should be consulted.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`ReplayError`] if the tape diverges, is exhausted, or the
+    /// recorded read was for a different key.
+    pub(crate) fn replay_env_read(
+        &mut self,
+        key: &[u8],
+    ) -> Result<Option<Option<Vec<u8>>>, Exception> {
+        let state = match self.state.as_mut() {
+            Some(state) => state,
+            None => return Ok(None),
+        };
+        if let RecordReplay::Replaying(replay) = &mut state.record_replay {
+            let (recorded_key, value) = match replay.next("env read")? {
+                Event::EnvRead { key, value } => (key, value),
+                _ => unreachable!("Replay::next checked the event kind"),
+            };
+            if recorded_key != key {
+                return Err(Fatal::from("replayed ENV read key mismatch").into());
+            }
+            Ok(Some(value))
+        } else {
+            Ok(None)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Event;
+    use crate::test::prelude::*;
+
+    #[test]
+    fn records_eval_inputs() {
+        let mut interp = crate::interpreter().unwrap();
+        interp.start_recording().unwrap();
+        let _ = interp.eval(b"1 + 1").unwrap();
+        let _ = interp.eval(b"2 + 2").unwrap();
+        let events = interp.stop_recording().unwrap();
+        assert_eq!(
+            events,
+            vec![
+                Event::Eval(b"1 + 1".to_vec()),
+                Event::Eval(b"2 + 2".to_vec()),
+            ]
+        );
+    }
+
+    #[test]
+    fn replaying_matching_evals_succeeds() {
+        let mut interp = crate::interpreter().unwrap();
+        interp
+            .start_replaying(vec![Event::Eval(b"1 + 1".to_vec())])
+            .unwrap();
+        let result = interp.eval(b"1 + 1").unwrap();
+        assert_eq!(result.try_into::<i64>(&interp).unwrap(), 2);
+    }
+
+    #[test]
+    fn replaying_a_different_eval_diverges() {
+        let mut interp = crate::interpreter().unwrap();
+        interp
+            .start_replaying(vec![Event::Eval(b"1 + 1".to_vec())])
+            .unwrap();
+        let result = interp.eval(b"2 + 2");
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn stop_recording_on_non_recording_interpreter_returns_none() {
+        let mut interp = crate::interpreter().unwrap();
+        assert!(interp.stop_recording().is_none());
+    }
+}
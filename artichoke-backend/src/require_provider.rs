@@ -0,0 +1,80 @@
+//! Embedder-defined fallback sources for `require` and `require_relative`.
+//!
+//! [`RequireProvider`] lets an embedder plug in a source of last resort for
+//! `Kernel#require`: after the virtual filesystem and the Ruby load path have
+//! both missed, each registered provider is asked, in registration order, to
+//! resolve the requested name. This is how a source backed by a database, an
+//! encrypted bundle, or code generated on demand can be `require`d without
+//! ever touching the virtual filesystem up front.
+//!
+//! A resolved [`Source`] is cached into the virtual filesystem and then
+//! `require`d through the normal path, so it is still subject to installed
+//! [`RequireVisibilityHooks`](crate::require_visibility::RequireVisibilityHooks)
+//! and [`SecureContextHooks`](crate::secure_context::SecureContextHooks).
+//!
+//! The existing [`File`](crate::core::File)-for-type mechanism solves a
+//! related but narrower problem -- mounting a single, statically known Rust
+//! extension at a fixed path -- and is left as-is; turning it into a
+//! `RequireProvider` implementation is future work, not attempted here.
+
+use std::fmt;
+use std::path::PathBuf;
+
+use crate::exception::Exception;
+use crate::ffi::InterpreterExtractError;
+use crate::Artichoke;
+
+/// A source resolved by a [`RequireProvider`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Source {
+    /// The virtual filesystem path the source is cached at before being
+    /// `require`d.
+    pub path: PathBuf,
+    /// The Ruby source contents.
+    pub contents: Vec<u8>,
+}
+
+impl Source {
+    /// Construct a new `Source` from a cache path and its contents.
+    #[must_use]
+    pub fn new(path: PathBuf, contents: Vec<u8>) -> Self {
+        Self { path, contents }
+    }
+}
+
+/// An embedder-defined fallback source for `require` and `require_relative`.
+///
+/// Register a provider with
+/// [`Artichoke::add_require_provider`]. Providers are consulted in
+/// registration order only after the virtual filesystem and the Ruby load
+/// path have both missed; the first provider to return `Some` wins.
+///
+/// This trait has `Send` and `Sync` supertrait bounds because registered
+/// providers are stored in [`State`](crate::state::State) behind the
+/// [`SharedInterpreter`](crate::shared::SharedInterpreter) `Mutex`, which
+/// requires everything it guards to be safe to hand off between threads.
+pub trait RequireProvider: fmt::Debug + Send + Sync {
+    /// Resolve `name` to a [`Source`], or return `None` to defer to the next
+    /// provider.
+    fn resolve(&self, name: &str) -> Option<Source>;
+}
+
+impl Artichoke {
+    /// Register a [`RequireProvider`] as a fallback source for `require` and
+    /// `require_relative`.
+    ///
+    /// Providers are consulted in registration order after the virtual
+    /// filesystem and the Ruby load path have both missed.
+    ///
+    /// # Errors
+    ///
+    /// If the interpreter's state is inaccessible, an error is returned.
+    pub fn add_require_provider(
+        &mut self,
+        provider: Box<dyn RequireProvider + Send + Sync>,
+    ) -> Result<(), Exception> {
+        let state = self.state.as_mut().ok_or(InterpreterExtractError)?;
+        state.require_providers.push(provider);
+        Ok(())
+    }
+}
@@ -0,0 +1,67 @@
+use std::fmt;
+
+/// Hooks an embedder can install to restrict which top-level constants a
+/// `require`/`load`/`require_relative` is allowed to leave behind.
+///
+/// Hosts that run multiple untrusted or multi-tenant scripts against one
+/// interpreter can use this hook to approximate plugin isolation: after a
+/// source file finishes loading, Artichoke diffs `Object.constants` against
+/// the snapshot it took before the file ran, and calls `filter_constants`
+/// with the names the file defined. Any name the hook does not return is
+/// removed with `Module#remove_const` before `require` returns, so code that
+/// runs afterward never sees it.
+///
+/// This is a Ruby-level, post-hoc policy, not a definition-time veto: the
+/// file's top-level code still runs to completion, and a name is visible to
+/// the rest of that same file's own body while it is defining things. mruby
+/// does not expose a hook into the VM's constant/method definition path
+/// (the only per-definition mechanism is `Module#method_added`, which is a
+/// Ruby-level override on `Module`/`Object` rather than something the
+/// interpreter calls out to the embedder for), and this crate does not patch
+/// the vendored mruby sources to add one. Diffing constants before and after
+/// `require` gives an embedder the same practical capability -- "what did
+/// this file add, and should it stay visible" -- without either of those.
+///
+/// Only constants are tracked. Methods defined on existing classes (for
+/// example reopening `String` to add a method) are not, since there is no
+/// bounded set of "methods that might have changed" to snapshot the way
+/// there is a bounded, enumerable set of top-level constants.
+///
+/// Install a set of hooks with
+/// [`Artichoke::set_require_visibility_hooks`](crate::Artichoke::set_require_visibility_hooks).
+#[derive(Clone, Copy)]
+pub struct RequireVisibilityHooks {
+    /// Called after a source file required with `Kernel#require`,
+    /// `Kernel#require_relative`, or `Kernel#load` finishes running, with the
+    /// path that was loaded and the names of every top-level constant the
+    /// file defined that did not already exist.
+    ///
+    /// Return the subset of `defined` that should remain visible. Names left
+    /// out are removed from `Object` with `remove_const` before the
+    /// `require` call returns.
+    pub filter_constants: fn(path: &[u8], defined: &[Vec<u8>]) -> Vec<Vec<u8>>,
+}
+
+impl fmt::Debug for RequireVisibilityHooks {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("RequireVisibilityHooks")
+            .field("filter_constants", &"fn(&[u8], &[Vec<u8>]) -> Vec<Vec<u8>>")
+            .finish()
+    }
+}
+
+/// Default [`RequireVisibilityHooks`].
+///
+/// Keeps every constant a required file defines visible, which reproduces
+/// the behavior of `require` before this hook existed.
+impl Default for RequireVisibilityHooks {
+    fn default() -> Self {
+        fn allow_all(_path: &[u8], defined: &[Vec<u8>]) -> Vec<Vec<u8>> {
+            defined.to_vec()
+        }
+
+        Self {
+            filter_constants: allow_all,
+        }
+    }
+}
@@ -0,0 +1,178 @@
+use std::collections::HashSet;
+use std::fmt;
+
+use crate::exception::Exception;
+use crate::extn::core::exception::TypeError;
+use crate::ffi::InterpreterExtractError;
+use crate::sys;
+use crate::types::Ruby;
+use crate::value::Value;
+use crate::Artichoke;
+
+/// Hooks an embedder can install to act when a string flagged untrusted by
+/// [`SecureContext`] reaches a dangerous sink.
+///
+/// Install a set of hooks with
+/// [`Artichoke::set_secure_context_hooks`](crate::Artichoke::set_secure_context_hooks).
+#[derive(Clone, Copy)]
+pub struct SecureContextHooks {
+    /// Called with the name of the sink (currently only `"require"`, for
+    /// `Kernel#require`, `#require_relative`, and `#load`) and the untrusted
+    /// value reaching it, before the operation proceeds. Return `Err` to
+    /// deny the operation; the returned message is raised in the
+    /// interpreter as a `SecurityError`. Returning `Ok(())` allows the
+    /// operation, whether or not the hook itself logs or warns.
+    pub check_sink: fn(sink: &str, value: &[u8]) -> Result<(), String>,
+}
+
+impl fmt::Debug for SecureContextHooks {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("SecureContextHooks")
+            .field("check_sink", &"fn(&str, &[u8]) -> Result<(), String>")
+            .finish()
+    }
+}
+
+/// Default [`SecureContextHooks`].
+///
+/// Allows every sink, which reproduces the behavior of `require` and `load`
+/// before this hook existed.
+impl Default for SecureContextHooks {
+    fn default() -> Self {
+        fn allow_all(_sink: &str, _value: &[u8]) -> Result<(), String> {
+            Ok(())
+        }
+
+        Self {
+            check_sink: allow_all,
+        }
+    }
+}
+
+/// Opt-in provenance tracking for untrusted `String`s.
+///
+/// `SecureContext` is a modern, embedding-oriented replacement for the
+/// taint (`$SAFE`) system removed from MRI: a host can flag a `String` it
+/// read from `ENV`, `ARGV`, an `IO`, or any other untrusted input with
+/// [`Artichoke::mark_untrusted`], query the flag with
+/// [`Artichoke::is_untrusted`], clear it with
+/// [`Artichoke::clear_untrusted`], and install a [`SecureContextHooks`] to
+/// be consulted when a flagged string reaches a dangerous sink.
+///
+/// # Scope
+///
+/// This is deliberately narrower than a VM-level taint bit:
+///
+/// - Flags are tracked in a Rust-side set keyed by [`sys::mrb_obj_id`],
+///   *not* stored on the `String` object itself: `MRB_TT_STRING` is not one
+///   of the object types mruby's `iv` table supports (see `obj_iv_p` in
+///   `vendor/mruby/src/variable.c`), so there is nowhere on the object to
+///   stash a flag without patching mruby's C source, which this crate does
+///   not do. `mrb_obj_id` derives its result from the object's heap address,
+///   so, exactly as with `Object#object_id` in any address-based Ruby
+///   implementation, an id can be reused by an unrelated `String` after the
+///   original is garbage collected. Callers should treat flags as
+///   short-lived: flag a string close to where it is read, and check or
+///   clear it before the flagged value could plausibly have been collected.
+/// - Flagging is opt-in and manual. There is no hook into `ENV`, `ARGV`, or
+///   `IO` that flags strings automatically; an embedder wires
+///   [`Artichoke::mark_untrusted`] into its own `ENV`/`IO`/host-input
+///   boundary (for example, the same place it installs
+///   [`EnvSecurityHooks`](crate::env_security::EnvSecurityHooks)).
+/// - The flag does not propagate through Ruby-level string operations.
+///   `"#{tainted}, world"` or `tainted.dup` produce new, unflagged `String`s;
+///   re-flag the result if it should still be treated as untrusted.
+/// - Only one sink is wired up today: the path argument to `Kernel#require`,
+///   `#require_relative`, and `#load`. There is no `Kernel#eval` in this
+///   backend to hook, and no `Process.spawn` in this backend to hook.
+#[derive(Debug, Default)]
+pub struct SecureContext {
+    flagged: HashSet<sys::mrb_int>,
+    hooks: SecureContextHooks,
+}
+
+impl SecureContext {
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn set_hooks(&mut self, hooks: SecureContextHooks) {
+        self.hooks = hooks;
+    }
+
+    pub fn check_sink(&self, sink: &str, value: &[u8]) -> Result<(), String> {
+        (self.hooks.check_sink)(sink, value)
+    }
+}
+
+impl Artichoke {
+    /// Flag `value`, which must be a `String`, as untrusted.
+    ///
+    /// # Errors
+    ///
+    /// If `value` is not a `String`, a [`TypeError`] is returned. If the
+    /// interpreter's state is inaccessible, an [`Exception`] is returned.
+    pub fn mark_untrusted(&mut self, value: &Value) -> Result<(), Exception> {
+        let id = string_object_id(self, value)?;
+        let state = self.state.as_mut().ok_or(InterpreterExtractError)?;
+        state.secure_context.flagged.insert(id);
+        Ok(())
+    }
+
+    /// Returns whether `value`, which must be a `String`, is flagged
+    /// untrusted.
+    ///
+    /// # Errors
+    ///
+    /// If `value` is not a `String`, a [`TypeError`] is returned. If the
+    /// interpreter's state is inaccessible, an [`Exception`] is returned.
+    pub fn is_untrusted(&mut self, value: &Value) -> Result<bool, Exception> {
+        let id = string_object_id(self, value)?;
+        let state = self.state.as_ref().ok_or(InterpreterExtractError)?;
+        Ok(state.secure_context.flagged.contains(&id))
+    }
+
+    /// Clear the untrusted flag on `value`, which must be a `String`.
+    ///
+    /// # Errors
+    ///
+    /// If `value` is not a `String`, a [`TypeError`] is returned. If the
+    /// interpreter's state is inaccessible, an [`Exception`] is returned.
+    pub fn clear_untrusted(&mut self, value: &Value) -> Result<(), Exception> {
+        let id = string_object_id(self, value)?;
+        let state = self.state.as_mut().ok_or(InterpreterExtractError)?;
+        state.secure_context.flagged.remove(&id);
+        Ok(())
+    }
+
+    /// Install hooks invoked when a `String` flagged untrusted by
+    /// [`SecureContext`] reaches a dangerous sink.
+    ///
+    /// The default hooks allow every sink. See
+    /// [`secure_context::SecureContextHooks`](crate::secure_context::SecureContextHooks).
+    ///
+    /// # Errors
+    ///
+    /// If the interpreter's state is inaccessible, an error is returned.
+    pub fn set_secure_context_hooks(
+        &mut self,
+        hooks: SecureContextHooks,
+    ) -> Result<(), Exception> {
+        let state = self.state.as_mut().ok_or(InterpreterExtractError)?;
+        state.secure_context.set_hooks(hooks);
+        Ok(())
+    }
+}
+
+fn string_object_id(interp: &mut Artichoke, value: &Value) -> Result<sys::mrb_int, Exception> {
+    if !matches!(value.ruby_type(), Ruby::String) {
+        let mut message = String::from("no implicit conversion of ");
+        message.push_str(value.pretty_name(interp));
+        message.push_str(" into String");
+        return Err(TypeError::from(message).into());
+    }
+    let inner = value.inner();
+    let id = unsafe { sys::mrb_obj_id(inner) };
+    Ok(id)
+}
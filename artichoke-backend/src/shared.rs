@@ -0,0 +1,243 @@
+use std::cell::RefCell;
+use std::collections::HashSet;
+use std::error;
+use std::fmt;
+use std::ops::{Deref, DerefMut};
+use std::sync::{Mutex, MutexGuard};
+
+use crate::core::Parser;
+use crate::gc::MrbGarbageCollection;
+use crate::Artichoke;
+
+thread_local! {
+    /// Addresses of [`SharedInterpreter`]s this thread currently holds the
+    /// lock on.
+    ///
+    /// Keyed by address rather than some opaque lock ID because a thread can
+    /// hold locks on more than one distinct `SharedInterpreter` at once (for
+    /// example, two independent interpreters each embedded by a different
+    /// plugin) and only re-locking the *same* one is the re-entrancy hazard
+    /// this guards against.
+    static HELD: RefCell<HashSet<usize>> = RefCell::new(HashSet::new());
+}
+
+/// An [`Artichoke`] interpreter behind a lock, safe to share between
+/// threads.
+///
+/// [`Artichoke`] itself holds a raw `mrb_state` pointer and is not `Send` or
+/// `Sync`: mruby's heap, GC, and parser state are not safe to touch from two
+/// threads at once. `SharedInterpreter` is the supported way to hand one
+/// interpreter to multiple threads -- call [`lock`](Self::lock) to get
+/// exclusive, serialized access instead of reaching for an unsafe `Send`/
+/// `Sync` impl on `Artichoke` directly.
+///
+/// # Re-entrant locking
+///
+/// A native method or callback invoked by the VM sometimes ends up back on
+/// the same OS thread that already holds the lock, for example a Rust
+/// `extern "C"` trampoline that calls back into [`SharedInterpreter::lock`]
+/// instead of reusing the `&mut Artichoke` it was already given. A
+/// [`std::sync::Mutex`] would deadlock that thread against itself;
+/// `SharedInterpreter` instead detects the re-entrant call and returns
+/// [`LockError::WouldDeadlock`] immediately.
+///
+/// # Poisoning
+///
+/// If a thread panics while holding the lock, the next [`lock`](Self::lock)
+/// call does not propagate the panic as [`std::sync::PoisonError`] normally
+/// would. A panic mid-`eval` can leave the parser's context stack and the
+/// GC arena in a state that assumes the unwound call frame is still live, so
+/// `SharedInterpreter` recovers by resetting the parser (clearing any
+/// pushed eval contexts and the line number) and running a full GC pass
+/// (reclaiming anything left on the arena by the unwound call) before
+/// handing the interpreter to the next caller.
+pub struct SharedInterpreter {
+    interp: Mutex<Artichoke>,
+}
+
+impl fmt::Debug for SharedInterpreter {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("SharedInterpreter").finish()
+    }
+}
+
+// Safety:
+//
+// `Artichoke` is not `Send` because its `mrb` pointer and the mruby values it
+// hands out are not safe to touch from two threads at once. Every piece of
+// that state is either owned by this crate (the raw `mrb` pointer, parser
+// state, GC-managed `Value`s) or, for the two trait objects `State` stores on
+// an embedder's behalf (`vfs: Box<dyn Filesystem + Send>` and
+// `require_providers: Vec<Box<dyn RequireProvider + Send + Sync>>`),
+// constrained by a `Send`/`Sync` supertrait bound so an embedder cannot plug
+// in thread-affine state (for example an `Rc`- or thread-local-backed
+// filesystem) that this impl can't see. With that bound in place, the only
+// remaining non-`Send` part of `Artichoke` is the raw `mrb` pointer, and the
+// `Mutex` is the enforcement mechanism for it: `lock` is the only way to
+// reach the `Artichoke` inside, and the returned `InterpreterGuard` borrows
+// the `Mutex`, so the interpreter is always fully handed off -- never
+// observed from two threads concurrently -- between the thread that last
+// held the lock and the thread that acquires it next.
+unsafe impl Send for SharedInterpreter {}
+unsafe impl Sync for SharedInterpreter {}
+
+impl SharedInterpreter {
+    #[must_use]
+    pub fn new(interp: Artichoke) -> Self {
+        Self {
+            interp: Mutex::new(interp),
+        }
+    }
+
+    /// Acquire exclusive access to the wrapped interpreter.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`LockError::WouldDeadlock`] if this thread already holds the
+    /// lock on this `SharedInterpreter`, rather than blocking forever.
+    pub fn lock(&self) -> Result<InterpreterGuard<'_>, LockError> {
+        let key = self.lock_key();
+        let already_held = HELD.with(|held| held.borrow().contains(&key));
+        if already_held {
+            return Err(LockError::WouldDeadlock);
+        }
+        let guard = match self.interp.lock() {
+            Ok(guard) => guard,
+            Err(poisoned) => {
+                let mut guard = poisoned.into_inner();
+                let _ = guard.reset_parser();
+                guard.full_gc();
+                guard
+            }
+        };
+        HELD.with(|held| held.borrow_mut().insert(key));
+        Ok(InterpreterGuard {
+            shared: self,
+            guard: Some(guard),
+        })
+    }
+
+    fn lock_key(&self) -> usize {
+        self as *const Self as usize
+    }
+}
+
+/// Exclusive, re-entrancy-checked access to the [`Artichoke`] inside a
+/// [`SharedInterpreter`], obtained via [`SharedInterpreter::lock`].
+///
+/// Dereferences to `&Artichoke`/`&mut Artichoke`, so existing code written
+/// against `&mut Artichoke` (for example `interp.eval(...)`) works unchanged
+/// once you have a guard.
+pub struct InterpreterGuard<'a> {
+    shared: &'a SharedInterpreter,
+    guard: Option<MutexGuard<'a, Artichoke>>,
+}
+
+impl<'a> Deref for InterpreterGuard<'a> {
+    type Target = Artichoke;
+
+    fn deref(&self) -> &Self::Target {
+        self.guard.as_ref().expect("guard taken before drop")
+    }
+}
+
+impl<'a> DerefMut for InterpreterGuard<'a> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        self.guard.as_mut().expect("guard taken before drop")
+    }
+}
+
+impl<'a> fmt::Debug for InterpreterGuard<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("InterpreterGuard").finish()
+    }
+}
+
+impl<'a> Drop for InterpreterGuard<'a> {
+    fn drop(&mut self) {
+        // Drop the `MutexGuard` first so the lock is released before another
+        // thread blocked on it can observe this thread's `HELD` entry gone.
+        self.guard.take();
+        let key = self.shared.lock_key();
+        HELD.with(|held| {
+            held.borrow_mut().remove(&key);
+        });
+    }
+}
+
+/// Error returned by [`SharedInterpreter::lock`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum LockError {
+    /// This thread already holds the lock on this `SharedInterpreter`;
+    /// blocking would deadlock it against itself.
+    WouldDeadlock,
+}
+
+impl fmt::Display for LockError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::WouldDeadlock => {
+                write!(f, "thread already holds this SharedInterpreter's lock")
+            }
+        }
+    }
+}
+
+impl error::Error for LockError {}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+    use std::thread;
+
+    use super::{LockError, SharedInterpreter};
+    use crate::test::prelude::*;
+
+    #[test]
+    fn lock_allows_eval() {
+        let interp = crate::interpreter().unwrap();
+        let shared = SharedInterpreter::new(interp);
+        let mut guard = shared.lock().unwrap();
+        let result = guard.eval(b"1 + 1").unwrap();
+        assert_eq!(result.try_into::<i64>(&guard).unwrap(), 2);
+    }
+
+    #[test]
+    fn reentrant_lock_on_same_thread_errors_instead_of_deadlocking() {
+        let interp = crate::interpreter().unwrap();
+        let shared = SharedInterpreter::new(interp);
+        let _outer = shared.lock().unwrap();
+        let inner = shared.lock();
+        assert_eq!(inner.err(), Some(LockError::WouldDeadlock));
+    }
+
+    #[test]
+    fn lock_is_released_after_guard_drops() {
+        let interp = crate::interpreter().unwrap();
+        let shared = SharedInterpreter::new(interp);
+        {
+            let _guard = shared.lock().unwrap();
+        }
+        assert!(shared.lock().is_ok());
+    }
+
+    #[test]
+    fn shared_interpreter_is_usable_across_threads() {
+        let interp = crate::interpreter().unwrap();
+        let shared = Arc::new(SharedInterpreter::new(interp));
+        let handles: Vec<_> = (0..4)
+            .map(|i| {
+                let shared = Arc::clone(&shared);
+                thread::spawn(move || {
+                    let mut guard = shared.lock().unwrap();
+                    let result = guard.eval(format!("{} + 1", i).as_bytes()).unwrap();
+                    result.try_into::<i64>(&guard).unwrap()
+                })
+            })
+            .collect();
+        let mut results: Vec<i64> = handles.into_iter().map(|h| h.join().unwrap()).collect();
+        results.sort_unstable();
+        assert_eq!(results, vec![1, 2, 3, 4]);
+    }
+}
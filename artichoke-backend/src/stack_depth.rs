@@ -0,0 +1,144 @@
+use std::ops::{Deref, DerefMut};
+
+use crate::exception::Exception;
+use crate::extn::core::exception::SystemStackError;
+use crate::ffi::InterpreterExtractError;
+use crate::Artichoke;
+
+/// Default maximum number of nested Ruby -> Rust -> Ruby calls before
+/// [`Artichoke::enter_recursive_call`] raises a [`SystemStackError`].
+///
+/// mruby's own `mrb_funcall_with_block` and `mrb_yield_with_class` (the C
+/// entry points behind [`Value::funcall`](crate::core::Value::funcall), which
+/// now calls them directly inside [`Artichoke::protect`](crate::Artichoke::protect),
+/// and [`sys::protect::block_yield`](crate::sys::protect::block_yield))
+/// already check their call info stack depth against a constant
+/// `MRB_FUNCALL_DEPTH_MAX` (512, see `vendor/mruby/src/vm.c`) and raise
+/// `SystemStackError` before recursing further, because each call is a new,
+/// real recursive invocation of `mrb_vm_exec`. `mrb_vm_run`/`mrb_top_run`,
+/// which back [`sys::protect::eval`](crate::sys::protect::eval), have no
+/// equivalent check: a script that recurses by repeatedly calling back into
+/// Ruby via `eval` from a Rust-backed method can still exhaust the native
+/// stack and crash the host process instead of raising. This tracker closes
+/// that gap uniformly across all three re-entry points and, unlike
+/// `MRB_FUNCALL_DEPTH_MAX`, is configurable per interpreter at runtime
+/// rather than fixed at mruby's compile time. 1024 is comfortably below
+/// where a typical 8MB thread stack is exhausted by this kind of recursion,
+/// while being deep enough not to interfere with legitimate recursive use
+/// of Rust-backed methods (e.g. `Array#each` with a block that itself calls
+/// a Rust-backed method).
+pub const DEFAULT_MAX_DEPTH: usize = 1024;
+
+/// Per-interpreter count of currently nested Ruby -> Rust -> Ruby calls.
+///
+/// See [`Artichoke::enter_recursive_call`] for where this is consulted and
+/// [`Artichoke::set_recursion_depth_limit`] for how to configure the limit
+/// per interpreter.
+#[derive(Debug, Clone, Copy)]
+pub struct StackDepth {
+    depth: usize,
+    max_depth: usize,
+}
+
+impl Default for StackDepth {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl StackDepth {
+    #[must_use]
+    pub fn new() -> Self {
+        Self {
+            depth: 0,
+            max_depth: DEFAULT_MAX_DEPTH,
+        }
+    }
+}
+
+/// RAII guard returned by [`Artichoke::enter_recursive_call`].
+///
+/// Decrements the interpreter's recursion depth when dropped, so a
+/// `funcall`, `eval`, or block yield that returns early -- including via
+/// `?` -- cannot leave the depth counter permanently incremented.
+pub(crate) struct RecursionGuard<'a>(&'a mut Artichoke);
+
+impl<'a> RecursionGuard<'a> {
+    fn new(interp: &'a mut Artichoke) -> Result<Self, Exception> {
+        let state = interp.state.as_mut().ok_or(InterpreterExtractError)?;
+        if state.stack_depth.depth >= state.stack_depth.max_depth {
+            return Err(SystemStackError::from("stack level too deep").into());
+        }
+        state.stack_depth.depth += 1;
+        Ok(Self(interp))
+    }
+}
+
+impl<'a> Deref for RecursionGuard<'a> {
+    type Target = Artichoke;
+
+    #[inline]
+    fn deref(&self) -> &Self::Target {
+        self.0
+    }
+}
+
+impl<'a> DerefMut for RecursionGuard<'a> {
+    #[inline]
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        self.0
+    }
+}
+
+impl<'a> Drop for RecursionGuard<'a> {
+    fn drop(&mut self) {
+        if let Some(state) = self.0.state.as_mut() {
+            state.stack_depth.depth = state.stack_depth.depth.saturating_sub(1);
+        }
+    }
+}
+
+impl Artichoke {
+    /// Get the maximum number of nested Ruby -> Rust -> Ruby calls this
+    /// interpreter allows before raising `SystemStackError`.
+    ///
+    /// # Errors
+    ///
+    /// If the interpreter's state is inaccessible, an error is returned.
+    pub fn recursion_depth_limit(&self) -> Result<usize, Exception> {
+        let state = self.state.as_ref().ok_or(InterpreterExtractError)?;
+        Ok(state.stack_depth.max_depth)
+    }
+
+    /// Set the maximum number of nested Ruby -> Rust -> Ruby calls this
+    /// interpreter allows before raising `SystemStackError`.
+    ///
+    /// The default is [`DEFAULT_MAX_DEPTH`].
+    ///
+    /// # Errors
+    ///
+    /// If the interpreter's state is inaccessible, an error is returned.
+    pub fn set_recursion_depth_limit(&mut self, max_depth: usize) -> Result<(), Exception> {
+        let state = self.state.as_mut().ok_or(InterpreterExtractError)?;
+        state.stack_depth.max_depth = max_depth;
+        Ok(())
+    }
+
+    /// Guard the start of a re-entrant Ruby -> Rust -> Ruby call.
+    ///
+    /// Call this once per `funcall`, `eval`, or block yield that crosses
+    /// back into Ruby from Rust, before making the call. The returned guard
+    /// derefs to this interpreter and decrements the recursion depth when
+    /// dropped.
+    ///
+    /// # Errors
+    ///
+    /// If this call would exceed
+    /// [`recursion_depth_limit`](Self::recursion_depth_limit), a
+    /// [`SystemStackError`] is returned instead of crashing the host
+    /// process with a native stack overflow. If the interpreter's state is
+    /// inaccessible, an error is returned.
+    pub(crate) fn enter_recursive_call(&mut self) -> Result<RecursionGuard<'_>, Exception> {
+        RecursionGuard::new(self)
+    }
+}
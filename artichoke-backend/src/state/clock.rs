@@ -0,0 +1,60 @@
+//! Per-interpreter override of the wall clock consulted by `Time.now`.
+//!
+//! Installed by `Artichoke::Test.freeze_time`/`.travel_to` (see
+//! [`extn::core::artichoke::test`](crate::extn::core::artichoke::test)), so
+//! a host's test suite can pin or shift what `Time.now` returns inside a
+//! single interpreter without monkeypatching `Time` from Ruby. Gated behind
+//! the `artichoke-test` feature so embedders that don't need it don't carry
+//! the bookkeeping.
+
+use chrono::{DateTime, Local};
+
+use crate::extn::core::time::Time;
+
+/// An installed override of the current time, as seen by `Time.now`.
+#[derive(Debug, Clone, Copy)]
+pub struct ClockOverride {
+    /// The apparent time this override reports, as of `installed_at`.
+    anchor: DateTime<Local>,
+    /// The real wall-clock time when this override was installed.
+    installed_at: DateTime<Local>,
+    /// If `true`, the clock is pinned to `anchor` forever. If `false`, the
+    /// clock advances in real time from `anchor`, as with `travel_to`.
+    frozen: bool,
+}
+
+impl ClockOverride {
+    /// Pin the clock at `anchor` until [`State::clock_override`] is cleared.
+    ///
+    /// [`State::clock_override`]: crate::state::State::clock_override
+    #[must_use]
+    pub fn frozen_at(anchor: DateTime<Local>) -> Self {
+        Self {
+            anchor,
+            installed_at: Local::now(),
+            frozen: true,
+        }
+    }
+
+    /// Shift the apparent current time to `anchor`; the clock continues to
+    /// advance in real time from there.
+    #[must_use]
+    pub fn traveled_to(anchor: DateTime<Local>) -> Self {
+        Self {
+            anchor,
+            installed_at: Local::now(),
+            frozen: false,
+        }
+    }
+
+    /// Compute the apparent current time under this override.
+    #[must_use]
+    pub fn now(&self) -> Time {
+        let now = if self.frozen {
+            self.anchor
+        } else {
+            self.anchor + (Local::now() - self.installed_at)
+        };
+        Time::from_datetime(now)
+    }
+}
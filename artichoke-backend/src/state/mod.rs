@@ -1,31 +1,87 @@
 use intaglio::bytes::SymbolTable;
 
+use crate::cache_hooks::CacheHooks;
 use crate::class;
+use crate::env_security::EnvSecurityHooks;
+use crate::events::EventRegistry;
+use crate::exception_handler::{self, UncaughtExceptionHandler};
 use crate::fs::{self, Filesystem};
 use crate::module;
+use crate::profile::Profile;
+use crate::replay::RecordReplay;
+use crate::require_provider::RequireProvider;
+#[cfg(feature = "core-require-remote")]
+use crate::require_remote::RemoteFetchHooks;
+use crate::require_visibility::RequireVisibilityHooks;
+use crate::secure_context::SecureContext;
+use crate::stack_depth::StackDepth;
 use crate::sys;
+use crate::terminal_hooks::TerminalHooks;
 
+#[cfg(feature = "artichoke-test")]
+pub mod clock;
+pub mod native_exception;
 pub mod output;
 pub mod parser;
 #[cfg(feature = "core-random")]
 pub mod prng;
+#[cfg(feature = "stdlib-readline")]
+pub mod readline;
 pub mod regexp;
+pub mod reload;
+pub mod require;
+pub mod require_lock;
+pub mod shared_bytes;
 
+#[cfg(feature = "artichoke-test")]
+use clock::ClockOverride;
 #[cfg(feature = "core-random")]
 use prng::Prng;
+#[cfg(feature = "stdlib-readline")]
+use readline::State as ReadlineState;
 
 /// Container for domain-specific interpreter state.
-#[derive(Default, Debug)]
+#[derive(Debug)]
 pub struct State {
+    #[cfg(feature = "interpreter-registry")]
+    pub id: u64,
     pub parser: Option<parser::State>,
     pub classes: class::Registry,
     pub modules: module::Registry,
-    pub vfs: Box<dyn Filesystem>,
+    pub vfs: Box<dyn Filesystem + Send>,
     pub regexp: regexp::State,
     pub symbols: SymbolTable,
     pub output: output::Strategy,
+    pub source_hashes: reload::State,
+    pub require_extensions: require::ExtensionStrategy,
+    pub require_providers: Vec<Box<dyn RequireProvider + Send + Sync>>,
+    pub require_lock: require_lock::State,
+    pub native_exceptions: native_exception::State,
+    pub uncaught_exception_handler: UncaughtExceptionHandler,
+    pub env_security_hooks: EnvSecurityHooks,
+    pub events: EventRegistry,
+    pub cache_hooks: CacheHooks,
+    pub require_visibility_hooks: RequireVisibilityHooks,
+    pub secure_context: SecureContext,
+    pub stack_depth: StackDepth,
+    pub terminal_hooks: TerminalHooks,
+    pub profile: Profile,
+    pub shared_bytes: shared_bytes::State,
+    #[cfg(feature = "core-require-remote")]
+    pub remote_fetch_hooks: RemoteFetchHooks,
     #[cfg(feature = "core-random")]
     pub prng: Prng,
+    #[cfg(feature = "stdlib-readline")]
+    pub readline: ReadlineState,
+    #[cfg(feature = "artichoke-test")]
+    pub clock_override: Option<ClockOverride>,
+    pub record_replay: RecordReplay,
+}
+
+impl Default for State {
+    fn default() -> Self {
+        Self::new()
+    }
 }
 
 impl State {
@@ -40,9 +96,59 @@ impl State {
     /// - [Ruby parser and file context](parser::State).
     /// - [Intepreter-level PRNG](Prng) (behind the `core-random` feature).
     /// - [IO capturing](output::Strategy) strategy.
+    /// - [Content hashes](reload::State) of required sources for `Kernel#reload!`.
+    /// - [Extension probing order](require::ExtensionStrategy) for
+    ///   `require`, `require_relative`, and `load`.
+    /// - [Fallback `require` providers](crate::require_provider::RequireProvider)
+    ///   consulted after the virtual filesystem and load path have missed.
+    /// - [In-progress `require` tracking](require_lock::State) for detecting
+    ///   circular requires.
+    /// - [Captured Rust exceptions](native_exception::State) raised with
+    ///   [`exception::raise`](crate::exception::raise), recoverable by a
+    ///   Ruby `rescue` that re-enters Rust.
+    /// - [`ENV` security hooks](crate::env_security::EnvSecurityHooks) for
+    ///   validating writes, redacting reads, and auditing access to `ENV`.
+    /// - A GC-rooted [registry](crate::events::EventRegistry) of Ruby
+    ///   callbacks registered with [`Artichoke::on_event`](crate::Artichoke::on_event)
+    ///   for host-driven event dispatch with
+    ///   [`Artichoke::emit`](crate::Artichoke::emit).
+    /// - [`Artichoke::Cache` hooks](crate::cache_hooks::CacheHooks) bounding
+    ///   its capacity and reporting evictions to the embedder.
+    /// - [`require` visibility hooks](crate::require_visibility::RequireVisibilityHooks)
+    ///   for restricting which constants a required source leaves visible.
+    /// - [`SecureContext`](crate::secure_context::SecureContext) for flagging
+    ///   untrusted `String`s and checking them at sinks like `require`.
+    /// - [`StackDepth`](crate::stack_depth::StackDepth) tracking how many
+    ///   Ruby -> Rust -> Ruby calls are currently nested, so deep recursion
+    ///   through a Rust-backed method raises `SystemStackError` instead of
+    ///   overflowing the native stack.
+    /// - [Terminal hooks](crate::terminal_hooks::TerminalHooks) letting an
+    ///   embedder report the width it renders output into, overriding
+    ///   `IO#winsize` and the pretty-printer's line-wrap width.
+    /// - The active [resource limit and security `Profile`](Profile),
+    ///   selected with [`Builder::with_profile`](crate::Builder::with_profile)
+    ///   and readable from Ruby as `Artichoke::VM.profile`.
+    /// - A [registry](shared_bytes::State) of `Arc<[u8]>` buffers backing
+    ///   zero-copy `String`s built with
+    ///   [`Artichoke::convert_shared_bytes`](crate::Artichoke::convert_shared_bytes),
+    ///   retained for the life of the interpreter since mruby's GC cannot see
+    ///   the `Arc`.
+    /// - [Remote fetch hooks](crate::require_remote::RemoteFetchHooks) for
+    ///   `Kernel#require_remote` (behind the `core-require-remote` feature).
+    /// - [`Readline` state](readline::State) holding the persistent line
+    ///   editor backing `Readline.readline` (behind the `stdlib-readline`
+    ///   feature).
+    /// - An optional [clock override](clock::ClockOverride) that pins or
+    ///   shifts `Time.now` (behind the `artichoke-test` feature).
+    /// - [Deterministic replay](crate::replay::RecordReplay) state, off by
+    ///   default, that a host can switch into recording or replaying mode to
+    ///   capture or re-feed `eval` inputs, RNG draws, clock reads, and `ENV`
+    ///   reads.
     #[must_use]
     pub fn new() -> Self {
         Self {
+            #[cfg(feature = "interpreter-registry")]
+            id: crate::registry::register(),
             parser: None,
             classes: class::Registry::new(),
             modules: module::Registry::new(),
@@ -50,8 +156,30 @@ impl State {
             regexp: regexp::State::new(),
             symbols: SymbolTable::new(),
             output: output::Strategy::new(),
+            source_hashes: reload::State::new(),
+            require_extensions: require::ExtensionStrategy::new(),
+            require_providers: Vec::new(),
+            require_lock: require_lock::State::new(),
+            native_exceptions: native_exception::State::new(),
+            uncaught_exception_handler: exception_handler::default_uncaught_exception_handler,
+            env_security_hooks: EnvSecurityHooks::default(),
+            events: EventRegistry::new(),
+            cache_hooks: CacheHooks::default(),
+            require_visibility_hooks: RequireVisibilityHooks::default(),
+            secure_context: SecureContext::new(),
+            stack_depth: StackDepth::new(),
+            terminal_hooks: TerminalHooks::default(),
+            profile: Profile::default(),
+            shared_bytes: shared_bytes::State::new(),
+            #[cfg(feature = "core-require-remote")]
+            remote_fetch_hooks: RemoteFetchHooks::default(),
             #[cfg(feature = "core-random")]
             prng: Prng::new(),
+            #[cfg(feature = "stdlib-readline")]
+            readline: ReadlineState::new(),
+            #[cfg(feature = "artichoke-test")]
+            clock_override: None,
+            record_replay: RecordReplay::new(),
         }
     }
 
@@ -0,0 +1,66 @@
+//! Round-trip storage for Rust-native exception values attached to raised
+//! Ruby exception objects.
+//!
+//! [`exception::raise`](crate::exception::raise) tags every `mrb_value` it
+//! raises with a hidden ivar (see [`IVAR_NAME`]) naming a slot in this table,
+//! so a `rescue` in Ruby that re-enters Rust with the caught exception can
+//! recover the original typed error with
+//! [`Artichoke::downcast_native_exception`](crate::Artichoke::downcast_native_exception)
+//! instead of reconstructing it from the exception's message and class name.
+
+use std::any::Any;
+use std::collections::HashMap;
+use std::fmt;
+
+/// Name of the hidden instance variable [`exception::raise`](crate::exception::raise)
+/// sets on a raised exception object to record its slot in this table.
+pub const IVAR_NAME: &str = "@__artichoke_native_exception_id";
+
+/// Table of captured Rust exception values, keyed by an id handed out by
+/// [`State::capture`].
+#[derive(Default)]
+pub struct State {
+    next_id: u64,
+    captured: HashMap<u64, Box<dyn Any>>,
+}
+
+impl fmt::Debug for State {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("State")
+            .field("next_id", &self.next_id)
+            .field("captured", &self.captured.len())
+            .finish()
+    }
+}
+
+impl State {
+    /// Create a new, empty table.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Store `exception`, returning the id to tag the raised Ruby object
+    /// with.
+    ///
+    /// Captured values live for the remaining lifetime of the interpreter;
+    /// there is currently no eviction, since a rescued exception may be
+    /// inspected, re-raised, or retried an arbitrary number of times over
+    /// the course of a program.
+    pub fn capture(&mut self, exception: Box<dyn Any>) -> u64 {
+        let id = self.next_id;
+        self.next_id += 1;
+        self.captured.insert(id, exception);
+        id
+    }
+
+    /// Recover the Rust value captured under `id`, downcast to `T`.
+    ///
+    /// Returns `None` if no value was captured under `id`, or if the
+    /// captured value is not a `T` -- for example, `T` named the wrong
+    /// exception struct for this `id`.
+    #[must_use]
+    pub fn downcast_ref<T: 'static>(&self, id: u64) -> Option<&T> {
+        self.captured.get(&id)?.downcast_ref::<T>()
+    }
+}
@@ -15,6 +15,22 @@ pub type Strategy = Captured;
 #[cfg(all(feature = "output-strategy-capture", feature = "output-strategy-null"))]
 pub type Strategy = Null;
 
+/// The default capacity, in bytes, of the buffer [`Process`] uses to batch
+/// writes to the real process stdout stream.
+pub const DEFAULT_STDOUT_BUFFER_CAPACITY: usize = 8 * 1024;
+
+/// Statistics about an [`Output`] strategy's buffered writes.
+///
+/// These counters are cumulative for the lifetime of the strategy; they are
+/// not reset by a call to [`flush`](Output::flush).
+#[derive(Default, Debug, Clone, Copy, Hash, PartialEq, Eq, PartialOrd, Ord)]
+pub struct BufferStats {
+    /// Total number of bytes passed to [`write_stdout`](Output::write_stdout).
+    pub bytes_written: u64,
+    /// Total number of times the strategy's buffer has been flushed.
+    pub flush_count: u64,
+}
+
 pub trait Output: Send + Sync {
     fn as_debug(&self) -> &dyn fmt::Debug;
 
@@ -22,6 +38,15 @@ pub trait Output: Send + Sync {
 
     fn write_stderr<T: AsRef<[u8]>>(&mut self, bytes: T) -> io::Result<()>;
 
+    /// Flush any buffered stdout writes.
+    ///
+    /// The default implementation is a no-op for strategies that do not
+    /// buffer, for example because they write directly to an in-memory
+    /// buffer or discard output entirely.
+    fn flush(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+
     fn print<T: AsRef<[u8]>>(&mut self, bytes: T) {
         let _ = self.write_stdout(bytes);
     }
@@ -33,8 +58,30 @@ pub trait Output: Send + Sync {
     }
 }
 
-#[derive(Default, Debug, Clone, Copy, Hash, PartialEq, Eq, PartialOrd, Ord)]
-pub struct Process;
+/// An output strategy that writes to the real process stdout and stderr
+/// streams.
+///
+/// Writes to stdout are buffered -- rather than crossing the FFI boundary
+/// and making a syscall for every small write a script makes, `Process`
+/// batches writes into an internal buffer and only flushes it to the
+/// process's stdout at explicit flush points: when an `eval` call completes,
+/// when Ruby code calls `$stdout.flush`, and when the interpreter is closed.
+pub struct Process {
+    stdout: io::BufWriter<io::Stdout>,
+    stats: BufferStats,
+}
+
+impl fmt::Debug for Process {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Process").field("stats", &self.stats).finish()
+    }
+}
+
+impl Default for Process {
+    fn default() -> Self {
+        Self::with_capacity(DEFAULT_STDOUT_BUFFER_CAPACITY)
+    }
+}
 
 impl Process {
     /// Constructs a new, default `Process` output strategy.
@@ -42,6 +89,22 @@ impl Process {
     pub fn new() -> Self {
         Self::default()
     }
+
+    /// Constructs a new `Process` output strategy with the given stdout
+    /// buffer capacity in bytes.
+    #[must_use]
+    pub fn with_capacity(capacity: usize) -> Self {
+        Self {
+            stdout: io::BufWriter::with_capacity(capacity, io::stdout()),
+            stats: BufferStats::default(),
+        }
+    }
+
+    /// Statistics about this strategy's buffered writes to stdout.
+    #[must_use]
+    pub fn stats(&self) -> BufferStats {
+        self.stats
+    }
 }
 
 impl Output for Process {
@@ -50,12 +113,21 @@ impl Output for Process {
     }
 
     fn write_stdout<T: AsRef<[u8]>>(&mut self, bytes: T) -> io::Result<()> {
-        io::stdout().write_all(bytes.as_ref())
+        let bytes = bytes.as_ref();
+        self.stdout.write_all(bytes)?;
+        self.stats.bytes_written += bytes.len() as u64;
+        Ok(())
     }
 
     fn write_stderr<T: AsRef<[u8]>>(&mut self, bytes: T) -> io::Result<()> {
         io::stderr().write_all(bytes.as_ref())
     }
+
+    fn flush(&mut self) -> io::Result<()> {
+        self.stdout.flush()?;
+        self.stats.flush_count += 1;
+        Ok(())
+    }
 }
 
 #[derive(Default, Debug, Clone, Hash, PartialEq, Eq, PartialOrd, Ord)]
@@ -145,3 +217,31 @@ impl Output for Null {
         Ok(())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn process_buffers_writes_and_tracks_stats() {
+        let mut strategy = Process::with_capacity(16);
+        strategy.write_stdout(b"hello").unwrap();
+        strategy.write_stdout(b" world").unwrap();
+        assert_eq!(strategy.stats().bytes_written, 11);
+        assert_eq!(strategy.stats().flush_count, 0);
+        strategy.flush().unwrap();
+        assert_eq!(strategy.stats().flush_count, 1);
+    }
+
+    #[test]
+    fn captured_and_null_flush_are_no_ops() {
+        let mut captured = Captured::new();
+        captured.write_stdout(b"hello").unwrap();
+        captured.flush().unwrap();
+        assert_eq!(captured.stdout(), b"hello");
+
+        let mut null = Null::new();
+        null.write_stdout(b"hello").unwrap();
+        null.flush().unwrap();
+    }
+}
@@ -37,6 +37,19 @@ impl State {
         unsafe { self.context.as_mut() }
     }
 
+    /// Release the "stack keep" retention of the most recently `eval`ed
+    /// result.
+    ///
+    /// After this call, the next `eval` run on this parser's context clears
+    /// the VM's register stack instead of preserving the previous eval's
+    /// return value, allowing it to be garbage collected.
+    pub fn release_stack_keep(&mut self) {
+        unsafe {
+            let ctx = self.context.as_mut();
+            sys::mrb_sys_release_context_stack_keep(ctx);
+        }
+    }
+
     /// Reset line number to `1`.
     pub fn reset(&mut self, mrb: &mut sys::mrb_state) {
         unsafe {
@@ -0,0 +1,43 @@
+use std::fmt;
+
+use rustyline::Editor;
+
+/// Host state for the `Readline` stdlib module.
+///
+/// Wraps a persistent [`rustyline::Editor`] so in-session line editing --
+/// history recall with the up/down arrows, the kill ring, and the like --
+/// survives across calls to `Readline.readline`.
+pub struct State {
+    editor: Editor<()>,
+}
+
+impl fmt::Debug for State {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("State").finish()
+    }
+}
+
+impl Default for State {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl State {
+    #[must_use]
+    pub fn new() -> Self {
+        Self {
+            editor: Editor::<()>::new(),
+        }
+    }
+
+    /// Display `prompt` and read one line from stdin with line editing.
+    ///
+    /// Returns `None` at EOF (Ctrl-D) or on interrupt (Ctrl-C), matching
+    /// MRI's `Readline.readline`, which returns `nil` in both cases.
+    pub fn readline(&mut self, prompt: &str) -> Option<String> {
+        let line = self.editor.readline(prompt).ok()?;
+        self.editor.add_history_entry(line.as_str());
+        Some(line)
+    }
+}
@@ -0,0 +1,44 @@
+//! Content-hash tracking for `Kernel#reload!`.
+
+use std::collections::HashMap;
+use std::collections::hash_map::DefaultHasher;
+use std::hash::{Hash, Hasher};
+use std::path::{Path, PathBuf};
+
+/// Tracks a content hash per required source path.
+///
+/// [`Kernel#reload!`](crate::extn::core::kernel::trampoline::reload) uses this
+/// table to detect whether a source's contents have changed since it was last
+/// required so it can skip re-requiring sources that have not changed.
+#[derive(Default, Debug)]
+pub struct State {
+    hashes: HashMap<PathBuf, u64>,
+}
+
+impl State {
+    /// Create a new, empty source hash table.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Compute a content hash for the given bytes.
+    #[must_use]
+    pub fn content_hash(contents: &[u8]) -> u64 {
+        let mut hasher = DefaultHasher::new();
+        contents.hash(&mut hasher);
+        hasher.finish()
+    }
+
+    /// Record `hash` as the last-seen content hash for `path`.
+    pub fn record(&mut self, path: &Path, hash: u64) {
+        self.hashes.insert(path.to_path_buf(), hash);
+    }
+
+    /// Return whether `hash` differs from the last-seen content hash for
+    /// `path`, or `path` has no recorded hash.
+    #[must_use]
+    pub fn has_changed(&self, path: &Path, hash: u64) -> bool {
+        self.hashes.get(path) != Some(&hash)
+    }
+}
@@ -0,0 +1,133 @@
+//! Extension probing strategy for `Kernel#require`, `require_relative`, and
+//! `Kernel#load`.
+
+use std::path::{Path, PathBuf};
+
+/// The ordered list of extensions [`Kernel#require`](crate::extn::core::kernel::require::require),
+/// `require_relative`, and [`Kernel#load`](crate::extn::core::kernel::require::load)
+/// append to an extension-less path while searching for a matching source.
+///
+/// Defaults to `[".rb"]`, which reproduces the behavior of `require` before
+/// this strategy was configurable: try the path with `.rb` appended, then
+/// fall back to the path unmodified. Override with
+/// [`Artichoke::set_require_extensions`](crate::Artichoke::set_require_extensions)
+/// to also probe for precompiled or natively compiled sources, for example
+/// `[".rb", ".mrb"]`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ExtensionStrategy {
+    extensions: Vec<String>,
+}
+
+impl Default for ExtensionStrategy {
+    fn default() -> Self {
+        Self {
+            extensions: vec![String::from(".rb")],
+        }
+    }
+}
+
+impl ExtensionStrategy {
+    /// Create a new strategy that only probes for `.rb` sources.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Replace the ordered list of extensions this strategy probes for.
+    ///
+    /// Each extension is matched and appended with its leading `.`, for
+    /// example `".rb"` or `".mrb"`.
+    pub fn set_extensions<I, S>(&mut self, extensions: I)
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        self.extensions = extensions.into_iter().map(Into::into).collect();
+    }
+
+    /// The ordered list of extensions this strategy probes for.
+    #[must_use]
+    pub fn extensions(&self) -> &[String] {
+        &self.extensions
+    }
+
+    /// Whether `path`'s extension already matches one of the configured
+    /// extensions.
+    #[must_use]
+    pub fn has_known_extension(&self, path: &Path) -> bool {
+        let ext = match path.extension() {
+            Some(ext) => ext,
+            None => return false,
+        };
+        self.extensions
+            .iter()
+            .any(|known| ext == known.trim_start_matches('.'))
+    }
+
+    /// Build the candidate paths for `path`, most to least specific.
+    ///
+    /// If `path` already ends in one of the configured extensions, there is
+    /// exactly one candidate: `path` unmodified. Otherwise, there is one
+    /// candidate per configured extension, in order, followed by `path`
+    /// unmodified as the last resort, so a source registered under its
+    /// extension-less name is still found.
+    #[must_use]
+    pub fn candidates(&self, path: &Path) -> Vec<PathBuf> {
+        if self.has_known_extension(path) {
+            return vec![path.to_path_buf()];
+        }
+        let mut candidates = Vec::with_capacity(self.extensions.len() + 1);
+        for extension in &self.extensions {
+            let mut candidate = path.to_path_buf();
+            candidate.set_extension(extension.trim_start_matches('.'));
+            candidates.push(candidate);
+        }
+        candidates.push(path.to_path_buf());
+        candidates
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn default_probes_rb_then_bare_path() {
+        let strategy = ExtensionStrategy::new();
+        let candidates = strategy.candidates(Path::new("foo"));
+        assert_eq!(
+            candidates,
+            vec![PathBuf::from("foo.rb"), PathBuf::from("foo")]
+        );
+    }
+
+    #[test]
+    fn path_with_known_extension_has_single_candidate() {
+        let strategy = ExtensionStrategy::new();
+        let candidates = strategy.candidates(Path::new("foo.rb"));
+        assert_eq!(candidates, vec![PathBuf::from("foo.rb")]);
+    }
+
+    #[test]
+    fn configured_extensions_are_probed_in_order() {
+        let mut strategy = ExtensionStrategy::new();
+        strategy.set_extensions(vec![".rb", ".mrb"]);
+        let candidates = strategy.candidates(Path::new("foo"));
+        assert_eq!(
+            candidates,
+            vec![
+                PathBuf::from("foo.rb"),
+                PathBuf::from("foo.mrb"),
+                PathBuf::from("foo"),
+            ]
+        );
+    }
+
+    #[test]
+    fn configured_extension_short_circuits_known_suffix() {
+        let mut strategy = ExtensionStrategy::new();
+        strategy.set_extensions(vec![".rb", ".mrb"]);
+        let candidates = strategy.candidates(Path::new("foo.mrb"));
+        assert_eq!(candidates, vec![PathBuf::from("foo.mrb")]);
+    }
+}
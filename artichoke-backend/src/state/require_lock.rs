@@ -0,0 +1,54 @@
+//! In-progress tracking for `Kernel#require`/`require_relative`, so a
+//! circular require does not recurse forever.
+
+use std::collections::HashSet;
+use std::path::{Path, PathBuf};
+
+/// Tracks which source paths have a `require` currently running.
+///
+/// [`crate::load::LoadSources::require_source`] consults this before
+/// evaluating a source: if the path is already in this set, some enclosing
+/// frame on the *same* call stack is already requiring it (a circular
+/// require), and evaluating it again would recurse without ever finishing
+/// the first require. MRI handles this the same way -- warn and return
+/// without re-running the file -- rather than, say, raising, since a
+/// circular require is usually harmless (the first `require` has already
+/// defined everything the second one's caller needs by the time it returns).
+///
+/// A single [`crate::Artichoke`] is never accessed from two threads at
+/// once (see [`crate::shared::SharedInterpreter`]), so this only ever needs
+/// to guard against reentrancy on one call stack, not real concurrent
+/// access to the set.
+#[derive(Default, Debug)]
+pub struct State {
+    in_progress: HashSet<PathBuf>,
+}
+
+impl State {
+    /// Create a new, empty in-progress table.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Mark `path` as having a require in progress.
+    ///
+    /// Returns `true` if `path` was not already in progress (the caller
+    /// should proceed with the require and call [`State::finish`] once it
+    /// is done), or `false` if a require of `path` is already running
+    /// higher up the call stack (the caller should treat this as a
+    /// circular require and not evaluate `path` again).
+    #[must_use]
+    pub fn begin(&mut self, path: &Path) -> bool {
+        self.in_progress.insert(path.to_path_buf())
+    }
+
+    /// Mark `path` as no longer having a require in progress.
+    ///
+    /// Must be called exactly once for every [`State::begin`] call that
+    /// returned `true`, regardless of whether the require succeeded, or
+    /// `path` is permanently (and incorrectly) treated as circular.
+    pub fn finish(&mut self, path: &Path) {
+        self.in_progress.remove(path);
+    }
+}
@@ -0,0 +1,34 @@
+//! Retains buffers backing `Arc<[u8]>`-sourced `String`s.
+//!
+//! [`Artichoke::convert_shared_bytes`](crate::Artichoke::convert_shared_bytes)
+//! hands a buffer's pointer directly to mruby via `mrb_str_new_static`, which
+//! embeds the pointer and length in the `RString` and never frees or
+//! reallocates them. mruby's GC has no visibility into the `Arc` behind that
+//! pointer, so nothing would otherwise keep the buffer alive once the caller
+//! drops its own reference. This registry holds a strong reference to every
+//! buffer handed to `convert_shared_bytes` for the remainder of the
+//! interpreter's lifetime, guaranteeing the memory `mrb_str_new_static`
+//! pointed at always outlives the `String`s built from it.
+
+use std::sync::Arc;
+
+/// A set of reference-counted byte buffers kept alive for the life of the
+/// interpreter.
+#[derive(Default, Debug)]
+pub struct State {
+    buffers: Vec<Arc<[u8]>>,
+}
+
+impl State {
+    /// Create a new, empty registry.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Retain a strong reference to `buffer` for the life of the
+    /// interpreter.
+    pub fn retain(&mut self, buffer: Arc<[u8]>) {
+        self.buffers.push(buffer);
+    }
+}
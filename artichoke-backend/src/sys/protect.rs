@@ -1,27 +1,10 @@
 use std::convert::TryFrom;
 use std::ffi::c_void;
-use std::mem;
 use std::ptr::{self, NonNull};
 
 use crate::sys;
 use crate::types::Int;
 
-pub unsafe fn funcall(
-    mrb: *mut sys::mrb_state,
-    slf: sys::mrb_value,
-    func: sys::mrb_sym,
-    args: &[sys::mrb_value],
-    block: Option<sys::mrb_value>,
-) -> Result<sys::mrb_value, sys::mrb_value> {
-    let data = Funcall {
-        slf,
-        func,
-        args,
-        block,
-    };
-    protect(mrb, data)
-}
-
 pub unsafe fn eval(
     mrb: *mut sys::mrb_state,
     context: *mut sys::mrbc_context,
@@ -40,6 +23,31 @@ pub unsafe fn block_yield(
     protect(mrb, data)
 }
 
+pub unsafe fn block_yield_argv(
+    mrb: *mut sys::mrb_state,
+    block: sys::mrb_value,
+    args: &[sys::mrb_value],
+) -> Result<sys::mrb_value, sys::mrb_value> {
+    let data = BlockYieldArgv { block, args };
+    protect(mrb, data)
+}
+
+pub unsafe fn block_yield_with_class(
+    mrb: *mut sys::mrb_state,
+    block: sys::mrb_value,
+    args: &[sys::mrb_value],
+    slf: sys::mrb_value,
+    target_class: *mut sys::RClass,
+) -> Result<sys::mrb_value, sys::mrb_value> {
+    let data = BlockYieldWithClass {
+        block,
+        args,
+        slf,
+        target_class,
+    };
+    protect(mrb, data)
+}
+
 unsafe fn protect<T>(mrb: *mut sys::mrb_state, data: T) -> Result<sys::mrb_value, sys::mrb_value>
 where
     T: Protect,
@@ -64,43 +72,42 @@ trait Protect {
     unsafe extern "C" fn run(mrb: *mut sys::mrb_state, data: sys::mrb_value) -> sys::mrb_value;
 }
 
-// `Funcall` must be `Copy` because the we may unwind past the frames in which
-// it is used with `longjmp` which does not allow Rust  to run destructors.
+/// Run an arbitrary raise-capable FFI operation behind `mrb_protect`.
+///
+/// This is the generic counterpart to the per-operation [`Protect`]
+/// implementations in this module (for example [`Eval`] or [`StrCat`]):
+/// rather than defining a new data struct and `Protect` impl for a one-off
+/// native operation, wrap it in a closure and pass it here, or prefer
+/// [`Artichoke::protect`](crate::Artichoke::protect), which additionally
+/// extracts a raised exception with
+/// [`exception_handler::last_error`](crate::exception_handler::last_error).
+pub unsafe fn closure<F>(
+    mrb: *mut sys::mrb_state,
+    func: F,
+) -> Result<sys::mrb_value, sys::mrb_value>
+where
+    F: FnMut(*mut sys::mrb_state) -> sys::mrb_value + Copy,
+{
+    let data = Closure { func };
+    protect(mrb, data)
+}
+
+// `Closure` must be `Copy` because the wrapped operation may unwind with
+// `longjmp`, which does not allow Rust to run destructors; the `F: Copy`
+// bound ensures any captured state is equally safe to leave un-dropped.
 #[derive(Clone, Copy)]
-struct Funcall<'a> {
-    slf: sys::mrb_value,
-    func: u32,
-    args: &'a [sys::mrb_value],
-    block: Option<sys::mrb_value>,
+struct Closure<F> {
+    func: F,
 }
 
-impl<'a> Protect for Funcall<'a> {
+impl<F> Protect for Closure<F>
+where
+    F: FnMut(*mut sys::mrb_state) -> sys::mrb_value + Copy,
+{
     unsafe extern "C" fn run(mrb: *mut sys::mrb_state, data: sys::mrb_value) -> sys::mrb_value {
         let ptr = sys::mrb_sys_cptr_ptr(data);
-        // `protect` must be `Copy` because the call to a function in the
-        // `mrb_funcall...` family can unwind with `longjmp` which does not
-        // allow Rust to run destructors.
-        let Self {
-            slf,
-            func,
-            args,
-            block,
-        } = *Box::from_raw(ptr as *mut Self);
-
-        // This will always unwrap because we've already checked that we
-        // have fewer than `MRB_FUNCALL_ARGC_MAX` args, which is less than
-        // i64 max value.
-        let argslen = if let Ok(argslen) = Int::try_from(args.len()) {
-            argslen
-        } else {
-            return sys::mrb_sys_nil_value();
-        };
-
-        if let Some(block) = block {
-            sys::mrb_funcall_with_block(mrb, slf, func, argslen, args.as_ptr(), block)
-        } else {
-            sys::mrb_funcall_argv(mrb, slf, func, argslen, args.as_ptr())
-        }
+        let Self { mut func } = *Box::from_raw(ptr as *mut Self);
+        func(mrb)
     }
 }
 
@@ -143,62 +150,118 @@ impl Protect for BlockYield {
     }
 }
 
-pub unsafe fn is_range(
+// `BlockYieldArgv` must be `Copy` because the we may unwind past the frames
+// in which it is used with `longjmp` which does not allow Rust to run
+// destructors.
+#[derive(Clone, Copy)]
+struct BlockYieldArgv<'a> {
+    block: sys::mrb_value,
+    args: &'a [sys::mrb_value],
+}
+
+impl<'a> Protect for BlockYieldArgv<'a> {
+    unsafe extern "C" fn run(mrb: *mut sys::mrb_state, data: sys::mrb_value) -> sys::mrb_value {
+        let ptr = sys::mrb_sys_cptr_ptr(data);
+        let Self { block, args } = *Box::from_raw(ptr as *mut Self);
+
+        // This will always unwrap because callers only ever pass arg slices
+        // well within `Int::max_value()`.
+        let argslen = if let Ok(argslen) = Int::try_from(args.len()) {
+            argslen
+        } else {
+            return sys::mrb_sys_nil_value();
+        };
+        sys::mrb_yield_argv(mrb, block, argslen, args.as_ptr())
+    }
+}
+
+// `BlockYieldWithClass` must be `Copy` because the we may unwind past the
+// frames in which it is used with `longjmp` which does not allow Rust to run
+// destructors.
+#[derive(Clone, Copy)]
+struct BlockYieldWithClass<'a> {
+    block: sys::mrb_value,
+    args: &'a [sys::mrb_value],
+    slf: sys::mrb_value,
+    target_class: *mut sys::RClass,
+}
+
+impl<'a> Protect for BlockYieldWithClass<'a> {
+    unsafe extern "C" fn run(mrb: *mut sys::mrb_state, data: sys::mrb_value) -> sys::mrb_value {
+        let ptr = sys::mrb_sys_cptr_ptr(data);
+        let Self {
+            block,
+            args,
+            slf,
+            target_class,
+        } = *Box::from_raw(ptr as *mut Self);
+
+        // This will always unwrap because callers only ever pass arg slices
+        // well within `Int::max_value()`.
+        let argslen = if let Ok(argslen) = Int::try_from(args.len()) {
+            argslen
+        } else {
+            return sys::mrb_sys_nil_value();
+        };
+        sys::mrb_yield_with_class(mrb, block, argslen, args.as_ptr(), slf, target_class)
+    }
+}
+
+pub unsafe fn str_cat(
     mrb: *mut sys::mrb_state,
     value: sys::mrb_value,
-    len: i64,
-) -> Result<Option<Range>, sys::mrb_value> {
-    let data = IsRange { value, len };
-    let is_range = protect(mrb, data)?;
-    if sys::mrb_sys_value_is_nil(is_range) {
-        Ok(None)
-    } else {
-        let ptr = sys::mrb_sys_cptr_ptr(is_range);
-        let out = *Box::from_raw(ptr as *mut Range);
-        Ok(Some(out))
+    bytes: &[u8],
+) -> Result<sys::mrb_value, sys::mrb_value> {
+    let data = StrCat { value, bytes };
+    protect(mrb, data)
+}
+
+// `StrCat` must be `Copy` because the call to `mrb_str_cat` can unwind with
+// `longjmp` (e.g. if `value` is frozen), which does not allow Rust to run
+// destructors.
+#[derive(Clone, Copy)]
+struct StrCat<'a> {
+    value: sys::mrb_value,
+    bytes: &'a [u8],
+}
+
+impl<'a> Protect for StrCat<'a> {
+    unsafe extern "C" fn run(mrb: *mut sys::mrb_state, data: sys::mrb_value) -> sys::mrb_value {
+        let ptr = sys::mrb_sys_cptr_ptr(data);
+        let Self { value, bytes } = *Box::from_raw(ptr as *mut Self);
+        sys::mrb_str_cat(mrb, value, bytes.as_ptr() as *const i8, bytes.len())
     }
 }
 
-#[derive(Default, Debug, Clone, Copy, Hash, PartialEq, Eq, PartialOrd, Ord)]
-pub struct Range {
-    pub start: sys::mrb_int,
-    pub len: sys::mrb_int,
+pub unsafe fn str_resize(
+    mrb: *mut sys::mrb_state,
+    value: sys::mrb_value,
+    len: usize,
+) -> Result<sys::mrb_value, sys::mrb_value> {
+    let data = StrResize { value, len };
+    protect(mrb, data)
 }
 
-// `IsRange` must be `Copy` because the we may unwind past the frames in which
-// it is used with `longjmp` which does not allow Rust  to run destructors.
-#[derive(Default, Debug, Clone, Copy)]
-struct IsRange {
+// `StrResize` must be `Copy` because the call to `mrb_str_resize` can unwind
+// with `longjmp` (e.g. if `value` is frozen), which does not allow Rust to
+// run destructors.
+#[derive(Clone, Copy)]
+struct StrResize {
     value: sys::mrb_value,
-    len: sys::mrb_int,
+    len: usize,
 }
 
-impl Protect for IsRange {
+impl Protect for StrResize {
     unsafe extern "C" fn run(mrb: *mut sys::mrb_state, data: sys::mrb_value) -> sys::mrb_value {
         let ptr = sys::mrb_sys_cptr_ptr(data);
         let Self { value, len } = *Box::from_raw(ptr as *mut Self);
-        let mut start = mem::MaybeUninit::<sys::mrb_int>::uninit();
-        let mut range_len = mem::MaybeUninit::<sys::mrb_int>::uninit();
-        let check_range = sys::mrb_range_beg_len(
-            mrb,
-            value,
-            start.as_mut_ptr(),
-            range_len.as_mut_ptr(),
-            len,
-            0_u8,
-        );
-        if check_range == sys::mrb_range_beg_len::MRB_RANGE_OK {
-            let start = start.assume_init();
-            let range_len = range_len.assume_init();
-            let out = Range {
-                start,
-                len: range_len,
-            };
-            let out = Box::new(out);
-            let out = Box::into_raw(out);
-            sys::mrb_sys_cptr_value(mrb, out as *mut c_void)
-        } else {
-            sys::mrb_sys_nil_value()
-        }
+        let len = Int::try_from(len).unwrap_or(Int::max_value());
+        sys::mrb_str_resize(mrb, value, len)
     }
 }
+
+#[derive(Default, Debug, Clone, Copy, Hash, PartialEq, Eq, PartialOrd, Ord)]
+pub struct Range {
+    pub start: sys::mrb_int,
+    pub len: sys::mrb_int,
+}
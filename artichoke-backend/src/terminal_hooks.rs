@@ -0,0 +1,47 @@
+use std::fmt;
+
+/// Hooks an embedder can install to override terminal size detection for
+/// `IO#winsize` and the pretty-printer's (`Kernel#pp`) line-wrap width.
+///
+/// By default, `IO#winsize` asks the process's real file descriptor for its
+/// size and the pretty-printer wraps at however many columns that reports.
+/// Hosts that render a script's output into something other than a real
+/// terminal -- for example a fixed-width pane in a web UI, where stdout is
+/// not attached to a tty at all -- can use this hook to report the width
+/// they are actually rendering into instead.
+///
+/// Install a set of hooks with
+/// [`Artichoke::set_terminal_hooks`](crate::Artichoke::set_terminal_hooks).
+#[derive(Clone, Copy)]
+pub struct TerminalHooks {
+    /// Called before consulting the real file descriptor for its width in
+    /// columns. Returning `Some` overrides the real device size, including
+    /// for a stream that is not a tty at all; returning `None` (the
+    /// default) falls back to asking the real file descriptor.
+    pub columns: fn() -> Option<u16>,
+}
+
+impl fmt::Debug for TerminalHooks {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("TerminalHooks")
+            .field("columns", &"fn() -> Option<u16>")
+            .finish()
+    }
+}
+
+/// Default [`TerminalHooks`].
+///
+/// Always defers to the real file descriptor, which reproduces the
+/// behavior of `IO#winsize` and the pretty-printer before this hook
+/// existed.
+impl Default for TerminalHooks {
+    fn default() -> Self {
+        fn defer_to_device() -> Option<u16> {
+            None
+        }
+
+        Self {
+            columns: defer_to_device,
+        }
+    }
+}
@@ -26,3 +26,14 @@ pub use crate::string;
 pub use crate::sys;
 pub use crate::types::{Fp, Int};
 pub use crate::value::Value;
+
+/// Construct a fresh, deterministic interpreter for use in a test.
+///
+/// This is [`crate::interpreter`] with the `Result` unwrapped, so
+/// property-based tests that construct a new interpreter per generated case
+/// -- for example, `Convert`/`TryConvert` round-trip tests -- don't need to
+/// repeat the same `.unwrap()` at every call site.
+#[must_use]
+pub fn fixture() -> crate::Artichoke {
+    crate::interpreter().unwrap()
+}
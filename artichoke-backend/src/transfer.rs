@@ -0,0 +1,177 @@
+//! Deep copy [`Value`]s between two independent [`Artichoke`] interpreters.
+//!
+//! Hosts that run a pool of interpreters -- for example, one worker `mrb_state`
+//! per request, recycled between requests -- need a way to hand a result back
+//! to a coordinator interpreter once a worker is done with it. A [`Value`] is
+//! a thin wrapper around an `mrb_value` that is only meaningful against the
+//! `mrb_state` that allocated it, so it cannot be read by, or even safely
+//! dropped by, a different interpreter. [`transfer`] walks the source value's
+//! object graph and reconstructs an equivalent value on the destination
+//! interpreter using only data, never sharing the original heap allocation.
+
+use std::collections::HashSet;
+
+use crate::core::{Convert, ConvertMut, Intern, TryConvert, TryConvertMut};
+use crate::exception::Exception;
+use crate::extn::core::exception::TypeError;
+use crate::extn::core::symbol::Symbol;
+use crate::sys;
+use crate::types::Ruby;
+use crate::value::Value;
+use crate::Artichoke;
+
+/// Deep copy `value`, which belongs to `src`, into an equivalent [`Value`]
+/// allocated on `dst`.
+///
+/// Supported types are `nil`, `true`/`false`, `Integer`, `Float`, `Symbol`,
+/// `String`, `Array`, and `Hash`; `Array` and `Hash` are copied recursively,
+/// so a graph of these types of any depth can be transferred. There is no
+/// `Marshal` in Artichoke to fall back on for arbitrary objects, so instances
+/// of any other class -- including custom, host-defined classes -- cannot be
+/// transferred and are rejected with a `TypeError`. There is likewise no hook
+/// for a custom class to supply its own transfer logic; a host that needs to
+/// move a custom type between interpreters must decompose it into one of the
+/// supported types itself, on either side of the call.
+///
+/// `value`'s object graph is walked with a visited set keyed on each object's
+/// identity, so a cycle (for example, an `Array` that contains itself) is
+/// rejected with a `TypeError` instead of recursing forever.
+///
+/// # Errors
+///
+/// If `value` or any value reachable from it is of an unsupported type, or if
+/// `value`'s object graph contains a cycle, a `TypeError` is returned. If
+/// reading from `src` or allocating on `dst` fails, the underlying error is
+/// returned.
+pub fn transfer(src: &mut Artichoke, dst: &mut Artichoke, value: Value) -> Result<Value, Exception> {
+    let mut seen = HashSet::new();
+    transfer_inner(src, dst, value, &mut seen)
+}
+
+fn transfer_inner(
+    src: &mut Artichoke,
+    dst: &mut Artichoke,
+    value: Value,
+    seen: &mut HashSet<*const ()>,
+) -> Result<Value, Exception> {
+    match value.ruby_type() {
+        Ruby::Nil => Ok(Value::nil()),
+        Ruby::Bool => {
+            let value = src.try_convert(value)?;
+            Ok(dst.convert(value))
+        }
+        Ruby::Fixnum => {
+            let value = src.try_convert(value)?;
+            Ok(dst.convert(value))
+        }
+        Ruby::Float => {
+            let value = src.try_convert(value)?;
+            Ok(dst.convert_mut(value))
+        }
+        Ruby::Symbol => {
+            let mut value = value;
+            let bytes = unsafe { Symbol::unbox_from_value(&mut value, src)?.bytes(src).to_vec() };
+            let sym = dst.intern_bytes(bytes)?;
+            Ok(Symbol::alloc_value(Symbol::from(sym), dst)?)
+        }
+        Ruby::String => {
+            let bytes: Vec<u8> = src.try_convert_mut(value)?;
+            Ok(dst.convert_mut(bytes))
+        }
+        Ruby::Array => {
+            let ptr = guarded_ptr(value, seen)?;
+            let elements: Vec<Value> = src.try_convert_mut(value)?;
+            let transferred = elements
+                .into_iter()
+                .map(|element| transfer_inner(src, dst, element, seen))
+                .collect::<Result<Vec<_>, _>>()?;
+            seen.remove(&ptr);
+            dst.try_convert_mut(transferred)
+        }
+        Ruby::Hash => {
+            let ptr = guarded_ptr(value, seen)?;
+            let pairs: Vec<(Value, Value)> = src.try_convert_mut(value)?;
+            let mut transferred = Vec::with_capacity(pairs.len());
+            for (key, val) in pairs {
+                let key = transfer_inner(src, dst, key, seen)?;
+                let val = transfer_inner(src, dst, val, seen)?;
+                transferred.push((key, val));
+            }
+            seen.remove(&ptr);
+            Ok(dst.convert_mut(transferred))
+        }
+        type_tag => Err(TypeError::from(format!(
+            "can't transfer instance of {} between interpreters",
+            type_tag.class_name()
+        ))
+        .into()),
+    }
+}
+
+/// Record `value`'s heap identity in `seen`, failing if it is already present.
+///
+/// Only `Array` and `Hash` values, which are the only supported types that
+/// can recursively contain themselves, are guarded this way.
+fn guarded_ptr(value: Value, seen: &mut HashSet<*const ()>) -> Result<*const (), Exception> {
+    let ptr = unsafe { sys::mrb_sys_basic_ptr(value.inner()) }.cast::<()>();
+    if !seen.insert(ptr) {
+        return Err(TypeError::from("can't transfer a value that contains itself").into());
+    }
+    Ok(ptr)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::test::prelude::*;
+    use crate::transfer::transfer;
+
+    #[test]
+    fn transfers_nested_array_and_hash_by_value() {
+        let mut src = crate::interpreter().unwrap();
+        let mut dst = crate::interpreter().unwrap();
+
+        let value = src
+            .eval(br#"[1, 2.5, "three", :four, [5, { six: 6 }], nil, true]"#)
+            .unwrap();
+        let transferred = transfer(&mut src, &mut dst, value).unwrap();
+
+        let inspected = transferred.inspect(&mut dst);
+        assert_eq!(
+            inspected,
+            br#"[1, 2.5, "three", :four, [5, {:six=>6}], nil, true]"#.to_vec()
+        );
+    }
+
+    #[test]
+    fn transferred_string_is_independent_of_the_original() {
+        let mut src = crate::interpreter().unwrap();
+        let mut dst = crate::interpreter().unwrap();
+
+        let value = src.eval(b"$sent = +'hello'").unwrap();
+        let transferred = transfer(&mut src, &mut dst, value).unwrap();
+        let _ = src.eval(b"$sent << ', world'").unwrap();
+
+        let transferred: String = transferred.try_into_mut(&mut dst).unwrap();
+        assert_eq!(transferred, "hello");
+    }
+
+    #[test]
+    fn rejects_values_of_unsupported_type() {
+        let mut src = crate::interpreter().unwrap();
+        let mut dst = crate::interpreter().unwrap();
+
+        let value = src.eval(b"Object.new").unwrap();
+        let result = transfer(&mut src, &mut dst, value);
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn rejects_a_cyclic_array() {
+        let mut src = crate::interpreter().unwrap();
+        let mut dst = crate::interpreter().unwrap();
+
+        let value = src.eval(b"a = []; a << a; a").unwrap();
+        let result = transfer(&mut src, &mut dst, value);
+        assert!(result.is_err());
+    }
+}
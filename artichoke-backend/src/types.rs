@@ -4,7 +4,9 @@ use crate::sys;
 ///
 /// `Fp` is the backend to the [`Float`](crate::extn::core::float::Float) class.
 ///
-/// The `Fp` type alias is for the `f64` floating point primitive.
+/// The `Fp` type alias is for the `f64` floating point primitive. It is
+/// defined in `artichoke-core` so frontends and backends share a single
+/// vocabulary type.
 ///
 /// ```
 /// # use std::any::TypeId;
@@ -13,14 +15,16 @@ use crate::sys;
 /// assert_eq!(mem::size_of::<f64>(), mem::size_of::<Fp>());
 /// assert_eq!(TypeId::of::<f64>(), TypeId::of::<Fp>());
 /// ```
-pub type Fp = f64;
+pub use crate::core::Fp;
 
 /// Artichoke native integer type.
 ///
 /// `Int` is the fixed size (`Fixnum`) backend to the
 /// [`Integer`](crate::extn::core::integer::Integer) class.
 ///
-/// The `Int` type alias is for the `i64` integer primitive.
+/// The `Int` type alias is for the `i64` integer primitive. It is defined in
+/// `artichoke-core` so frontends and backends share a single vocabulary
+/// type.
 ///
 /// ```
 /// # use std::any::TypeId;
@@ -31,7 +35,7 @@ pub type Fp = f64;
 /// assert_eq!(i64::max_value(), Int::max_value());
 /// assert_eq!(TypeId::of::<i64>(), TypeId::of::<Int>());
 /// ```
-pub type Int = i64;
+pub use crate::core::Int;
 
 pub use crate::core::{Ruby, Rust};
 
@@ -1,6 +1,7 @@
 use std::borrow::Cow;
 use std::convert::TryFrom;
 use std::error;
+use std::ffi::{c_void, CStr};
 use std::fmt;
 use std::mem;
 use std::ptr;
@@ -13,17 +14,42 @@ use crate::exception_handler;
 use crate::extn::core::exception::{ArgumentError, Fatal, TypeError};
 use crate::extn::core::symbol::Symbol;
 use crate::gc::MrbGarbageCollection;
+use crate::inspector;
 use crate::sys::{self, protect};
-use crate::types::{self, Int, Ruby};
+use crate::types::{self, Fp, Int, Ruby};
 use crate::Artichoke;
 
 /// Max argument count for function calls including initialize and yield.
-pub const MRB_FUNCALL_ARGC_MAX: usize = 16;
+///
+/// This is bounded by [`sys::mrb_int`] (aliased as [`Int`] in this crate),
+/// the integer type mruby's C API uses to represent an argument count, and
+/// not by any limit Artichoke itself imposes. In particular, this is *not*
+/// mruby's `CALL_MAXARGS` (127): calls with more than `CALL_MAXARGS`
+/// arguments are handled entirely inside `mrb_funcall_with_block`, which
+/// transparently spills them into a single splatted `Array` argument and
+/// still raises mruby's own `ArgumentError` if the called method's arity
+/// can't accept the call. Artichoke does not need to reimplement that
+/// spilling and should not preempt it with a smaller, artificial cap.
+pub const MRB_FUNCALL_ARGC_MAX: usize = Int::max_value() as usize;
 
 /// Boxed Ruby value in the [`Artichoke`] interpreter.
-#[derive(Default, Debug, Clone, Copy)]
+#[derive(Default, Clone, Copy)]
 pub struct Value(sys::mrb_value);
 
+impl fmt::Debug for Value {
+    /// Format this value's [`Ruby`] type tag.
+    ///
+    /// This impl never touches the VM -- it only reads the type tag packed
+    /// into the boxed `mrb_value` -- so it is safe to call even on a dead or
+    /// otherwise unreachable value. For a richer dump of a value's class and
+    /// contents, which does need the VM, see [`Value::debug`].
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Value")
+            .field("ruby_type", &self.ruby_type())
+            .finish()
+    }
+}
+
 impl From<sys::mrb_value> for Value {
     /// Construct a new [`Value`] from a [`sys::mrb_value`].
     fn from(value: sys::mrb_value) -> Self {
@@ -95,10 +121,36 @@ impl Value {
             Ok(None) => "nil",
             Err(_) => {
                 if let Ruby::Data | Ruby::Object = self.ruby_type() {
-                    self.funcall(interp, "class", &[], None)
-                        .and_then(|class| class.funcall(interp, "name", &[], None))
-                        .and_then(|class| class.try_into_mut(interp))
-                        .unwrap_or_default()
+                    // `mrb_obj_classname` looks up the class name directly off
+                    // the object's `RClass`, which mruby memoizes on the class
+                    // as the `__classname__` ivar the first time it is
+                    // computed. This is the same lookup a funcall of `class`
+                    // followed by `name` would perform, but without
+                    // re-entering the VM, so there is no need to maintain a
+                    // second, Rust-side cache alongside it.
+                    let value = self.inner();
+                    let name = unsafe {
+                        interp.with_ffi_boundary(|mrb| sys::mrb_obj_classname(mrb, value))
+                    };
+                    if let Ok(name) = name {
+                        if !name.is_null() {
+                            let name = unsafe { CStr::from_ptr(name) };
+                            if let Ok(name) = name.to_str() {
+                                // Safety:
+                                //
+                                // The class name string returned by
+                                // `mrb_obj_classname` is owned by the class's
+                                // `__classname__` ivar, which is valid for the
+                                // lifetime of the interpreter, which is a
+                                // longer lifetime than `self`.
+                                //
+                                // This transmute shrinks the lifetime of the
+                                // class name to the lifetime of this `Value`.
+                                return unsafe { mem::transmute(name) };
+                            }
+                        }
+                    }
+                    ""
                 } else {
                     self.ruby_type().class_name()
                 }
@@ -122,6 +174,32 @@ impl Value {
         matches!(self.ruby_type(), Ruby::Unreachable)
     }
 
+    /// Render this value's class and a shallow dump of its contents.
+    ///
+    /// This is a richer alternative to the [`fmt::Debug`](Value) impl for use
+    /// in Rust test failure output and other diagnostics where `interp` is
+    /// available. It is built on [`Artichoke::snapshot_value`], which never
+    /// invokes a container's real `#inspect`, so this is safe to call on
+    /// arbitrarily large or cyclic values.
+    ///
+    /// [`is_unreachable`](Self::is_unreachable) and [`is_dead`](Self::is_dead)
+    /// values, along with values whose snapshot otherwise fails to build --
+    /// for example, a `Data`-typed value mid-finalization -- render as just
+    /// their [`Ruby`] type tag rather than panicking.
+    #[must_use]
+    pub fn debug(&self, interp: &mut Artichoke) -> String {
+        if self.is_unreachable() {
+            return format!("#<unreachable value ({:?})>", self.ruby_type());
+        }
+        if self.is_dead(interp) {
+            return format!("#<dead value ({:?})>", self.ruby_type());
+        }
+        match interp.snapshot_value(*self, inspector::DEFAULT_DEPTH_LIMIT) {
+            Ok(snapshot) => format!("#<{}: {}>", snapshot.class_name(), snapshot.inspect()),
+            Err(_) => format!("#<{:?} (snapshot failed)>", self.ruby_type()),
+        }
+    }
+
     /// Return whether this object is unreachable by any GC roots.
     #[must_use]
     pub fn is_dead(&self, interp: &mut Artichoke) -> bool {
@@ -136,19 +214,73 @@ impl Value {
         interp: &mut Artichoke,
         len: Int,
     ) -> Result<Option<protect::Range>, Exception> {
-        let mut arena = interp.create_arena_savepoint()?;
+        let value = self.inner();
         let result = unsafe {
+            interp.protect(|mrb| {
+                let mut start = mem::MaybeUninit::<sys::mrb_int>::uninit();
+                let mut range_len = mem::MaybeUninit::<sys::mrb_int>::uninit();
+                let check_range = sys::mrb_range_beg_len(
+                    mrb,
+                    value,
+                    start.as_mut_ptr(),
+                    range_len.as_mut_ptr(),
+                    len,
+                    0_u8,
+                );
+                if check_range == sys::mrb_range_beg_len::MRB_RANGE_OK {
+                    let out = protect::Range {
+                        start: start.assume_init(),
+                        len: range_len.assume_init(),
+                    };
+                    let out = Box::new(out);
+                    let out = Box::into_raw(out);
+                    sys::mrb_sys_cptr_value(mrb, out as *mut c_void)
+                } else {
+                    sys::mrb_sys_nil_value()
+                }
+            })?
+        };
+        if unsafe { sys::mrb_sys_value_is_nil(result) } {
+            Ok(None)
+        } else {
+            let out = unsafe {
+                let ptr = sys::mrb_sys_cptr_ptr(result);
+                *Box::from_raw(ptr as *mut protect::Range)
+            };
+            Ok(Some(out))
+        }
+    }
+
+    /// Check whether this value's class defines the named method, without
+    /// going through a Ruby-level `respond_to?` funcall.
+    ///
+    /// This calls [`sys::mrb_respond_to`] directly, which is the same
+    /// method-table lookup mruby's own default `Kernel#respond_to?`
+    /// implementation performs, so it gives identical results for the
+    /// overwhelming majority of objects at a fraction of the cost of a full
+    /// [`Value::funcall`]. The one difference: it does not call an
+    /// overridden `respond_to?`/`respond_to_missing?`, so it must only be
+    /// used on hot paths, like the implicit conversion protocol below, where
+    /// skipping a user-defined `respond_to?` override is an acceptable
+    /// trade-off.
+    fn responds_to_by_method_table(&self, interp: &mut Artichoke, method: &str) -> bool {
+        let mut arena = if let Ok(arena) = interp.create_arena_savepoint() {
+            arena
+        } else {
+            return false;
+        };
+        let method = if let Ok(method) = arena.intern_string(method.to_string()) {
+            method
+        } else {
+            return false;
+        };
+        let value = self.inner();
+        let responds_to = unsafe {
             arena
                 .interp()
-                .with_ffi_boundary(|mrb| protect::is_range(mrb, self.inner(), len))?
+                .with_ffi_boundary(|mrb| sys::mrb_respond_to(mrb, value, method.into()))
         };
-        match result {
-            Ok(range) => Ok(range),
-            Err(exception) => {
-                let exception = Self::from(exception);
-                Err(exception_handler::last_error(&mut arena, exception)?)
-            }
-        }
+        responds_to.unwrap_or_default() != 0
     }
 
     pub fn implicitly_convert_to_int(&self, interp: &mut Artichoke) -> Result<Int, TypeError> {
@@ -160,7 +292,7 @@ impl Value {
                     "no implicit conversion from nil to integer",
                 ));
             }
-        } else if let Ok(true) = self.respond_to(interp, "to_int") {
+        } else if self.responds_to_by_method_table(interp, "to_int") {
             if let Ok(maybe) = self.funcall(interp, "to_int", &[], None) {
                 if let Ok(int) = maybe.try_into::<Int>(interp) {
                     int
@@ -189,6 +321,52 @@ impl Value {
         Ok(int)
     }
 
+    /// Convert this `Value` to a `Float` using the implicit conversion
+    /// protocol `to_f`.
+    ///
+    /// Unlike [`implicitly_convert_to_int`](Self::implicitly_convert_to_int),
+    /// which falls back from `to_int` to `to_i`, this conversion is strict:
+    /// only `to_f` is consulted. This matches `Kernel#Float`'s object
+    /// coercion ladder, which does not fall back to `to_i`/`to_int` for
+    /// non-`String` arguments.
+    pub fn implicitly_convert_to_float(&self, interp: &mut Artichoke) -> Result<Fp, TypeError> {
+        let float = if let Ok(float) = self.try_into::<Option<Fp>>(interp) {
+            if let Some(float) = float {
+                float
+            } else {
+                return Err(TypeError::from(
+                    "no implicit conversion from nil to float",
+                ));
+            }
+        } else if self.responds_to_by_method_table(interp, "to_f") {
+            if let Ok(maybe) = self.funcall(interp, "to_f", &[], None) {
+                if let Ok(float) = maybe.try_into::<Fp>(interp) {
+                    float
+                } else {
+                    let mut message = String::from("can't convert ");
+                    message.push_str(self.pretty_name(interp));
+                    message.push_str(" to Float (");
+                    message.push_str(self.pretty_name(interp));
+                    message.push_str("#to_f gives ");
+                    message.push_str(maybe.pretty_name(interp));
+                    message.push(')');
+                    return Err(TypeError::from(message));
+                }
+            } else {
+                let mut message = String::from("can't convert ");
+                message.push_str(self.pretty_name(interp));
+                message.push_str(" into Float");
+                return Err(TypeError::from(message));
+            }
+        } else {
+            let mut message = String::from("can't convert ");
+            message.push_str(self.pretty_name(interp));
+            message.push_str(" into Float");
+            return Err(TypeError::from(message));
+        };
+        Ok(float)
+    }
+
     pub fn implicitly_convert_to_string(
         &mut self,
         interp: &mut Artichoke,
@@ -205,7 +383,7 @@ impl Value {
             // This transmute shrinks the lifetime of the interned bytes to the
             // lifetime of this `Value`.
             unsafe { mem::transmute(bytes) }
-        } else if let Ok(true) = self.respond_to(interp, "to_str") {
+        } else if self.responds_to_by_method_table(interp, "to_str") {
             if let Ok(maybe) = self.funcall(interp, "to_str", &[], None) {
                 if let Ok(string) = maybe.try_into_mut::<&[u8]>(interp) {
                     string
@@ -246,6 +424,150 @@ impl Value {
             Ok(Some(string))
         }
     }
+
+    /// Try to convert this `Value` to a `Vec<Value>` using the `to_ary`
+    /// implicit conversion protocol.
+    ///
+    /// Unlike [`Kernel#Array`](crate::extn::core::kernel), which also falls
+    /// back to `to_a` and wraps non-convertible objects in a one-element
+    /// `Array`, this only consults `to_ary`, matching MRI's
+    /// `rb_check_array_type`. Callers that want to treat "does not respond
+    /// to `to_ary`" as "not an Array" rather than an error -- for example
+    /// `Kernel#puts`'s recursive flattening of Array arguments, or
+    /// `Regexp.union`'s single-Array-argument form -- should match on
+    /// `Ok`/`Err` rather than propagating the error with `?`.
+    pub fn implicitly_convert_to_array(
+        &mut self,
+        interp: &mut Artichoke,
+    ) -> Result<Vec<Value>, TypeError> {
+        let array = if let Ok(array) = self.try_into_mut::<Vec<Value>>(interp) {
+            array
+        } else if self.responds_to_by_method_table(interp, "to_ary") {
+            if let Ok(maybe) = self.funcall(interp, "to_ary", &[], None) {
+                if let Ok(array) = maybe.try_into_mut::<Vec<Value>>(interp) {
+                    array
+                } else {
+                    let mut message = String::from("can't convert ");
+                    message.push_str(self.pretty_name(interp));
+                    message.push_str(" to Array (");
+                    message.push_str(self.pretty_name(interp));
+                    message.push_str("#to_ary gives ");
+                    message.push_str(maybe.pretty_name(interp));
+                    message.push(')');
+                    return Err(TypeError::from(message));
+                }
+            } else {
+                let mut message = String::from("no implicit conversion of ");
+                message.push_str(self.pretty_name(interp));
+                message.push_str(" into Array");
+                return Err(TypeError::from(message));
+            }
+        } else {
+            let mut message = String::from("no implicit conversion of ");
+            message.push_str(self.pretty_name(interp));
+            message.push_str(" into Array");
+            return Err(TypeError::from(message));
+        };
+        Ok(array)
+    }
+
+    /// Append `bytes` to the end of this `String` in place.
+    ///
+    /// This is a thin wrapper around `mrb_str_cat` and mutates the
+    /// underlying `RString` directly, so repeated calls do not allocate a
+    /// new Ruby `String` per chunk the way `value.funcall(interp, "<<",
+    /// ...)` would if the receiver were reassigned. Frozen checks and GC
+    /// write barriers are handled by mruby inside `mrb_str_cat`.
+    ///
+    /// # Errors
+    ///
+    /// If this `Value` does not wrap a Ruby `String`, or the `String` is
+    /// frozen, an [`Exception`] is returned.
+    pub fn append_bytes(&mut self, interp: &mut Artichoke, bytes: &[u8]) -> Result<(), Exception> {
+        let mut arena = interp.create_arena_savepoint()?;
+        let value = self.inner();
+        let result =
+            unsafe { arena.interp().with_ffi_boundary(|mrb| protect::str_cat(mrb, value, bytes))? };
+        match result {
+            Ok(value) => {
+                *self = Self::from(value);
+                Ok(())
+            }
+            Err(exception) => {
+                let exception = Self::from(exception);
+                Err(exception_handler::last_error(&mut arena, exception)?)
+            }
+        }
+    }
+
+    /// Replace the contents of this `String` with `bytes` in place.
+    ///
+    /// This truncates the backing buffer to zero length and then appends
+    /// `bytes`, which reuses the existing allocation rather than creating a
+    /// new `String` object, as long as `bytes` fits within the buffer's
+    /// existing capacity.
+    ///
+    /// # Errors
+    ///
+    /// If this `Value` does not wrap a Ruby `String`, or the `String` is
+    /// frozen, an [`Exception`] is returned.
+    pub fn replace_bytes(
+        &mut self,
+        interp: &mut Artichoke,
+        bytes: &[u8],
+    ) -> Result<(), Exception> {
+        self.reserve(interp, 0)?;
+        self.append_bytes(interp, bytes)
+    }
+
+    /// Grow the backing buffer of this `String` to hold at least
+    /// `additional` more bytes without changing its length.
+    ///
+    /// This lets a streaming producer size the buffer for a chunk up front
+    /// so a subsequent [`append_bytes`](Self::append_bytes) does not need
+    /// to reallocate.
+    ///
+    /// # Errors
+    ///
+    /// If this `Value` does not wrap a Ruby `String`, or the `String` is
+    /// frozen, an [`Exception`] is returned.
+    pub fn reserve(&mut self, interp: &mut Artichoke, additional: usize) -> Result<(), Exception> {
+        let mut arena = interp.create_arena_savepoint()?;
+        let value = self.inner();
+        let len = unsafe {
+            arena
+                .interp()
+                .with_ffi_boundary(|mrb| sys::mrb_string_value_len(mrb, value))?
+        };
+        let len = usize::try_from(len).unwrap_or_default();
+        let grown = unsafe {
+            arena
+                .interp()
+                .with_ffi_boundary(|mrb| protect::str_resize(mrb, value, len + additional))?
+        };
+        let grown = match grown {
+            Ok(value) => value,
+            Err(exception) => {
+                let exception = Self::from(exception);
+                return Err(exception_handler::last_error(&mut arena, exception)?);
+            }
+        };
+        let result = unsafe {
+            arena
+                .interp()
+                .with_ffi_boundary(|mrb| protect::str_resize(mrb, grown, len))?
+        };
+        match result {
+            Ok(value) => {
+                *self = Self::from(value);
+                Ok(())
+            }
+            Err(exception) => {
+                let exception = Self::from(exception);
+                Err(exception_handler::last_error(&mut arena, exception)?)
+            }
+        }
+    }
 }
 
 impl ValueCore for Value {
@@ -262,7 +584,8 @@ impl ValueCore for Value {
         args: &[Self::Arg],
         block: Option<Self::Block>,
     ) -> Result<Self::Value, Self::Error> {
-        let mut arena = interp.create_arena_savepoint()?;
+        let mut guard = interp.enter_recursive_call()?;
+        let mut arena = guard.create_arena_savepoint()?;
         if let Ok(arg_count_error) = ArgCountError::try_from(args) {
             warn!("{}", arg_count_error);
             return Err(arg_count_error.into());
@@ -275,36 +598,33 @@ impl ValueCore for Value {
             args.len(),
             if block.is_some() { " and block" } else { "" }
         );
-        let func = arena.intern_string(func.to_string())?;
+        let func: sys::mrb_sym = arena.intern_string(func.to_string())?.into();
+        let slf = self.inner();
+        let block = block.as_ref().map(Self::inner);
+        // `args.len()` was already checked against `MRB_FUNCALL_ARGC_MAX`,
+        // which is `Int::max_value()`, by the `ArgCountError` check above, so
+        // this always succeeds.
+        let argslen = Int::try_from(args.len()).unwrap_or(Int::max_value());
+        let args = args.as_ptr();
         let result = unsafe {
-            arena.with_ffi_boundary(|mrb| {
-                protect::funcall(
-                    mrb,
-                    self.inner(),
-                    func.into(),
-                    args.as_slice(),
-                    block.as_ref().map(Self::inner),
-                )
-            })?
-        };
-        match result {
-            Ok(value) => {
-                let value = Self::from(value);
-                if value.is_unreachable() {
-                    // Unreachable values are internal to the mruby interpreter
-                    // and interacting with them via the C API is unspecified
-                    // and may result in a segfault.
-                    //
-                    // See: https://github.com/mruby/mruby/issues/4460
-                    Err(Fatal::from("Unreachable Ruby value").into())
+            arena.protect(|mrb| {
+                if let Some(block) = block {
+                    sys::mrb_funcall_with_block(mrb, slf, func, argslen, args, block)
                 } else {
-                    Ok(value)
+                    sys::mrb_funcall_argv(mrb, slf, func, argslen, args)
                 }
-            }
-            Err(exception) => {
-                let exception = Self::from(exception);
-                Err(exception_handler::last_error(&mut arena, exception)?)
-            }
+            })?
+        };
+        let value = Self::from(result);
+        if value.is_unreachable() {
+            // Unreachable values are internal to the mruby interpreter and
+            // interacting with them via the C API is unspecified and may
+            // result in a segfault.
+            //
+            // See: https://github.com/mruby/mruby/issues/4460
+            Err(Fatal::from("Unreachable Ruby value").into())
+        } else {
+            Ok(value)
         }
     }
 
@@ -359,7 +679,16 @@ impl ConvertMut<Value, Value> for Artichoke {
     }
 }
 
-/// Argument count exceeds maximum allowed by the VM.
+/// Argument count cannot be represented by the native argument count type
+/// mruby's C API expects.
+///
+/// This is distinct from a Ruby-level `ArgumentError` raised because a
+/// method was called with the wrong number of arguments for its arity;
+/// that check happens inside mruby itself, including for calls with more
+/// arguments than mruby's internal `CALL_MAXARGS`, which are transparently
+/// spilled into a splatted `Array` argument before arity is checked.
+/// `ArgCountError` only fires when `given` doesn't fit in [`Int`] at all,
+/// which [`Value::funcall`] cannot pass across the FFI boundary.
 #[derive(Default, Debug, Clone, Copy, Hash, PartialEq, Eq, PartialOrd, Ord)]
 pub struct ArgCountError {
     /// Number of arguments given.
@@ -444,7 +773,7 @@ impl fmt::Display for ArgCountError {
         f.write_str("Too many arguments for function call: ")?;
         write!(
             f,
-            "gave {} arguments, but Artichoke only supports a maximum of {} arguments",
+            "gave {} arguments, but at most {} can cross the native call boundary",
             self.given, self.max
         )
     }
@@ -523,6 +852,46 @@ mod tests {
         assert_eq!(debug, b"true");
     }
 
+    #[test]
+    fn append_bytes_mutates_in_place() {
+        let mut interp = crate::interpreter().unwrap();
+
+        let mut value = interp.convert_mut(b"hello".to_vec());
+        value.append_bytes(&mut interp, b", world").unwrap();
+        let appended: Vec<u8> = value.try_into_mut(&mut interp).unwrap();
+        assert_eq!(appended, b"hello, world".to_vec());
+    }
+
+    #[test]
+    fn append_bytes_on_frozen_string_errors() {
+        let mut interp = crate::interpreter().unwrap();
+
+        let mut value = interp.convert_mut(b"hello".to_vec());
+        value.freeze(&mut interp).unwrap();
+        let result = value.append_bytes(&mut interp, b", world");
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn replace_bytes_overwrites_contents() {
+        let mut interp = crate::interpreter().unwrap();
+
+        let mut value = interp.convert_mut(b"hello, world".to_vec());
+        value.replace_bytes(&mut interp, b"goodbye").unwrap();
+        let replaced: Vec<u8> = value.try_into_mut(&mut interp).unwrap();
+        assert_eq!(replaced, b"goodbye".to_vec());
+    }
+
+    #[test]
+    fn reserve_does_not_change_contents() {
+        let mut interp = crate::interpreter().unwrap();
+
+        let mut value = interp.convert_mut(b"hello".to_vec());
+        value.reserve(&mut interp, 256).unwrap();
+        let reserved: Vec<u8> = value.try_into_mut(&mut interp).unwrap();
+        assert_eq!(reserved, b"hello".to_vec());
+    }
+
     #[test]
     fn to_s_false() {
         let mut interp = crate::interpreter().unwrap();
@@ -714,4 +1083,144 @@ mod tests {
             err.message().as_ref()
         );
     }
+
+    #[test]
+    fn funcall_arithmetic() {
+        let mut interp = crate::interpreter().unwrap();
+        let one = interp.convert(1);
+        let two = interp.convert(2);
+        let half = interp.convert_mut(0.5);
+
+        let sum = one
+            .funcall(&mut interp, "+", &[two], None)
+            .and_then(|value| value.try_into::<Int>(&interp))
+            .unwrap();
+        assert_eq!(sum, 3);
+
+        let sum = one
+            .funcall(&mut interp, "+", &[half], None)
+            .and_then(|value| value.try_into::<Fp>(&interp))
+            .unwrap();
+        assert!((sum - 1.5).abs() < Fp::EPSILON);
+
+        let less_than = one
+            .funcall(&mut interp, "<", &[two], None)
+            .and_then(|value| value.try_into::<bool>(&interp))
+            .unwrap();
+        assert!(less_than);
+
+        let eql = one
+            .funcall(&mut interp, "==", &[one], None)
+            .and_then(|value| value.try_into::<bool>(&interp))
+            .unwrap();
+        assert!(eql);
+    }
+
+    #[test]
+    fn funcall_arithmetic_overflow_promotes_to_float() {
+        let mut interp = crate::interpreter().unwrap();
+        let max = interp.convert(Int::max_value());
+        let one = interp.convert(1);
+
+        // `Fixnum + Fixnum` overflow promotes to `Float`, mirroring mruby's
+        // own `OP_ADD` bytecode handler, rather than wrapping or raising.
+        let sum = max
+            .funcall(&mut interp, "+", &[one], None)
+            .and_then(|value| value.try_into::<Fp>(&interp))
+            .unwrap();
+        assert!((sum - (Int::max_value() as Fp + 1.0)).abs() < 1.0);
+    }
+
+    #[test]
+    fn funcall_honors_redefined_integer_and_float_operators() {
+        let mut interp = crate::interpreter().unwrap();
+        interp
+            .eval(
+                b"
+                class Integer
+                  def +(other)
+                    999
+                  end
+                end
+                class Float
+                  def ==(other)
+                    false
+                  end
+                end
+                ",
+            )
+            .unwrap();
+        let one = interp.convert(1);
+        let two = interp.convert(2);
+        let half = interp.convert_mut(0.5);
+
+        // `Value::funcall` is a general dynamic dispatch API, unlike
+        // mruby's `OP_ADD`/`OP_EQ` bytecode handlers for compiled Ruby
+        // source, so it must honor a method redefined at the Ruby level
+        // rather than special-casing `Integer`/`Float` receivers.
+        let sum = one
+            .funcall(&mut interp, "+", &[two], None)
+            .and_then(|value| value.try_into::<Int>(&interp))
+            .unwrap();
+        assert_eq!(sum, 999);
+
+        let eql = half
+            .funcall(&mut interp, "==", &[half], None)
+            .and_then(|value| value.try_into::<bool>(&interp))
+            .unwrap();
+        assert!(!eql);
+    }
+
+    #[test]
+    fn implicitly_convert_to_int_uses_to_int_method_table_fast_path() {
+        let mut interp = crate::interpreter().unwrap();
+        let value = interp
+            .eval(
+                br#"
+                class HasToInt
+                  def to_int
+                    42
+                  end
+                end
+                HasToInt.new
+                "#,
+            )
+            .unwrap();
+        let int = value.implicitly_convert_to_int(&mut interp).unwrap();
+        assert_eq!(int, 42);
+    }
+
+    #[test]
+    fn implicitly_convert_to_int_without_to_int_errors() {
+        let mut interp = crate::interpreter().unwrap();
+        let value = interp.eval(b"Object.new").unwrap();
+        let err = value.implicitly_convert_to_int(&mut interp).unwrap_err();
+        assert_eq!(
+            &b"no implicit conversion of Object into Integer"[..],
+            err.message().as_ref()
+        );
+    }
+
+    #[test]
+    fn pretty_name_for_object_and_data_values() {
+        let mut interp = crate::interpreter().unwrap();
+        let value = interp.eval(b"Object.new").unwrap();
+        assert_eq!(value.pretty_name(&mut interp), "Object");
+
+        let value = interp
+            .eval(b"class PrettyNameTarget; end; PrettyNameTarget.new")
+            .unwrap();
+        assert_eq!(value.pretty_name(&mut interp), "PrettyNameTarget");
+    }
+
+    #[test]
+    fn pretty_name_for_immediates() {
+        let mut interp = crate::interpreter().unwrap();
+        let value = interp.convert(true);
+        assert_eq!(value.pretty_name(&mut interp), "true");
+        let value = interp.convert(false);
+        assert_eq!(value.pretty_name(&mut interp), "false");
+        let value = Value::nil();
+        assert_eq!(value.pretty_name(&mut interp), "nil");
+    }
 }
@@ -0,0 +1,136 @@
+#![deny(clippy::all)]
+#![deny(clippy::pedantic)]
+
+//! This integration test exercises `ensure`/`retry` semantics across a
+//! Ruby -> Rust -> Ruby sandwich: a Ruby block, raising an exception, that is
+//! `yield`ed from a Rust-backed method.
+//!
+//! [`sys::protect::block_yield`](artichoke_backend::sys::protect::block_yield)
+//! traps the `longjmp` from a raise inside the yielded block and converts it
+//! into a `Result`, so `Native.call_block`'s own Rust stack frame always
+//! returns normally -- the single `longjmp` that actually re-raises the
+//! error happens at the outer FFI boundary (see
+//! `artichoke_backend::exception::raise`), after that frame, and everything
+//! Rust-owned in it, has already unwound. `tests/raise_capable_ffi_audit.rs`
+//! checks the source-level half of this invariant (no raise-capable mruby
+//! function is called outside `sys::protect`); this test checks the
+//! behavioral half: that Ruby's own `ensure` and `retry` still see a clean,
+//! correctly propagated exception on the other side of that sandwich.
+
+#[macro_use]
+extern crate artichoke_backend;
+
+use artichoke_backend::extn::prelude::*;
+
+struct Native;
+
+unsafe extern "C" fn native_call_block(
+    mrb: *mut sys::mrb_state,
+    _slf: sys::mrb_value,
+) -> sys::mrb_value {
+    let (arg, block) = mrb_get_args!(mrb, required = 1, &block);
+    let mut interp = unwrap_interpreter!(mrb);
+    let mut guard = Guard::new(&mut interp);
+    let arg = Value::from(arg);
+    let block = block.expect("test always passes a block");
+    match block.yield_arg(&mut guard, &arg) {
+        Ok(result) => result.inner(),
+        Err(exception) => exception::raise(guard, exception),
+    }
+}
+
+impl File for Native {
+    type Artichoke = Artichoke;
+
+    type Error = Exception;
+
+    fn require(interp: &mut Artichoke) -> Result<(), Self::Error> {
+        let spec = class::Spec::new("Native", None, None)?;
+        class::Builder::for_spec(interp, &spec)
+            .add_self_method("call_block", native_call_block, sys::mrb_args_req(1))?
+            .define()?;
+        interp.def_class::<Self>(spec)?;
+        Ok(())
+    }
+}
+
+#[test]
+fn ensure_runs_when_block_yielded_from_rust_raises() {
+    let mut interp = artichoke_backend::interpreter().expect("init");
+    Native::require(&mut interp).unwrap();
+    let result = interp
+        .eval(
+            br#"
+            trace = []
+            begin
+              begin
+                Native.call_block(1) { |x| raise "boom from block" }
+              ensure
+                trace << :ensure
+              end
+            rescue => e
+              trace << :rescued
+              trace << e.message
+            end
+            trace
+            "#,
+        )
+        .unwrap();
+    let trace = result.try_into_mut::<Vec<String>>(&mut interp).unwrap();
+    assert_eq!(trace, vec!["ensure", "rescued", "boom from block"]);
+}
+
+#[test]
+fn retry_re_enters_rust_after_a_raise_from_a_yielded_block() {
+    let mut interp = artichoke_backend::interpreter().expect("init");
+    Native::require(&mut interp).unwrap();
+    let result = interp
+        .eval(
+            br#"
+            attempts = 0
+            begin
+              Native.call_block(attempts) do |count|
+                attempts += 1
+                raise "not yet" if count < 2
+                count
+              end
+            rescue
+              retry
+            end
+            attempts
+            "#,
+        )
+        .unwrap();
+    let attempts = result.try_into::<Int>(&interp).unwrap();
+    assert_eq!(attempts, 3);
+}
+
+#[test]
+fn ensure_runs_exactly_once_per_attempt_across_retries() {
+    let mut interp = artichoke_backend::interpreter().expect("init");
+    Native::require(&mut interp).unwrap();
+    let result = interp
+        .eval(
+            br#"
+            attempts = 0
+            ensure_runs = 0
+            begin
+              begin
+                Native.call_block(attempts) do |count|
+                  attempts += 1
+                  raise "not yet" if count < 2
+                  count
+                end
+              ensure
+                ensure_runs += 1
+              end
+            rescue
+              retry
+            end
+            [attempts, ensure_runs]
+            "#,
+        )
+        .unwrap();
+    let counts = result.try_into_mut::<Vec<Int>>(&mut interp).unwrap();
+    assert_eq!(counts, vec![3, 3]);
+}
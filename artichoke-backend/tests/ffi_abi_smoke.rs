@@ -0,0 +1,48 @@
+#![deny(clippy::all)]
+#![deny(clippy::pedantic)]
+
+//! ABI drift smoke test for the `mruby-sys` bindings.
+//!
+//! `sys::mrb_value` and friends are generated by bindgen from the vendored
+//! mruby C headers at build time (see `build.rs`); nothing in the Rust type
+//! system checks that the generated layout still matches what the rest of
+//! this crate assumes. A future vendored mruby upgrade that changes a struct
+//! definition -- or a build performed with a different combination of the
+//! `mrb-utf8-string`/`mrb-value-boxing-nan`/`mrb-value-boxing-word` features
+//! than this crate's default -- could silently produce bindings with a
+//! different layout, which would manifest as memory corruption rather than a
+//! compile error. This test pins the layout we expect for the default build
+//! configuration so that kind of drift fails loudly instead.
+//!
+//! These assertions are only valid for the default feature set (no
+//! `mrb-value-boxing-nan`/`mrb-value-boxing-word`, `mrb-utf8-string` on) on a
+//! 64-bit target, which is how this crate is built and tested upstream; see
+//! `vendor/mruby/include/mruby/boxing_no.h` for the C definition this mirrors.
+
+use std::mem;
+
+use artichoke_backend::sys;
+
+#[test]
+#[cfg(all(
+    target_pointer_width = "64",
+    not(feature = "mrb-value-boxing-nan"),
+    not(feature = "mrb-value-boxing-word")
+))]
+fn mrb_value_layout_matches_unboxed_mruby_representation() {
+    // `mrb_value` is `{ union { mrb_float f; void *p; mrb_int i; mrb_sym sym; } value; enum
+    // mrb_vtype tt; }`. The union is word-sized (the widest member is an 8 byte `f64`/pointer/
+    // `i64`), and the trailing `tt` enum is padded out to the union's alignment.
+    assert_eq!(mem::size_of::<sys::mrb_value>(), 16);
+    assert_eq!(mem::align_of::<sys::mrb_value>(), 8);
+}
+
+#[test]
+fn mrb_value_is_copy() {
+    // `mrb_value` is passed by value across the FFI boundary extensively; if bindgen ever
+    // stopped deriving `Copy` for it (e.g. because a future mruby version added a field with a
+    // destructor-requiring type), most of this crate would fail to compile, but it's cheap to
+    // assert explicitly here alongside the rest of the layout checks.
+    fn assert_copy<T: Copy>(_: T) {}
+    assert_copy(sys::mrb_value::default());
+}
@@ -0,0 +1,81 @@
+//! Lint harness that fails the build if a trampoline calls a longjmp-capable
+//! mruby C API function directly instead of going through the
+//! [`sys::protect`](artichoke_backend::sys::protect) wrappers.
+//!
+//! `mrb_funcall`, `mrb_yield`, and `mrb_load_string`-style entry points can
+//! call `longjmp` to unwind the mruby stack on a raised Ruby exception. Doing
+//! so across a Rust stack frame skips Rust destructors and is undefined
+//! behavior. Everywhere these functions are needed, code should go through
+//! `sys::protect`, which traps the C longjmp with `mrb_protect` and converts
+//! it into a `Result`.
+//!
+//! This test is a source-level audit, not a type-level guarantee: it greps
+//! the crate for denylisted function names outside of the `sys` module,
+//! where the safe wrappers live.
+
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// mruby C API functions that may raise a Ruby exception and therefore
+/// `longjmp` out of the current stack frame. New callers of these functions
+/// must go through `sys::protect` instead of calling them directly.
+const RAISE_CAPABLE_FUNCTIONS: &[&str] = &[
+    "mrb_funcall",
+    "mrb_yield",
+    "mrb_yield_argv",
+    "mrb_load_string",
+    "mrb_load_nstring",
+];
+
+/// Source files that are allowed to mention the denylisted functions because
+/// they implement the `sys::protect` wrappers or are raw FFI declarations.
+const ALLOWED_PATHS: &[&str] = &["src/sys/protect.rs", "src/sys/ffi.rs", "src/sys/mod.rs"];
+
+#[test]
+fn trampolines_do_not_call_raise_capable_functions_directly() {
+    let root = PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("src");
+    let mut violations = Vec::new();
+    visit(&root, &mut violations);
+    assert!(
+        violations.is_empty(),
+        "found direct calls to raise-capable mruby functions outside sys::protect: {:#?}\n\
+         wrap these calls in artichoke_backend::sys::protect instead",
+        violations
+    );
+}
+
+fn visit(dir: &Path, violations: &mut Vec<String>) {
+    let entries = match fs::read_dir(dir) {
+        Ok(entries) => entries,
+        Err(_) => return,
+    };
+    for entry in entries.flatten() {
+        let path = entry.path();
+        if path.is_dir() {
+            visit(&path, violations);
+            continue;
+        }
+        if path.extension().and_then(|ext| ext.to_str()) != Some("rs") {
+            continue;
+        }
+        let relative = path
+            .strip_prefix(PathBuf::from(env!("CARGO_MANIFEST_DIR")))
+            .unwrap_or(&path);
+        let relative = relative.to_string_lossy().replace('\\', "/");
+        if ALLOWED_PATHS.iter().any(|allowed| relative == *allowed) {
+            continue;
+        }
+        let contents = match fs::read_to_string(&path) {
+            Ok(contents) => contents,
+            Err(_) => continue,
+        };
+        for (lineno, line) in contents.lines().enumerate() {
+            for func in RAISE_CAPABLE_FUNCTIONS {
+                let callsite = format!("{}(", func);
+                if line.contains(&callsite) {
+                    violations.push(format!("{}:{}: {}", relative, lineno + 1, line.trim()));
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,91 @@
+#![deny(clippy::all)]
+#![deny(clippy::pedantic)]
+
+//! This integration test checks that deep recursion through a Rust-backed
+//! method raises `SystemStackError` instead of overflowing the native
+//! stack and crashing the host process.
+//!
+//! mruby's own call-info stack depth check (`MRB_FUNCALL_DEPTH_MAX`) covers
+//! re-entrant `funcall` and block yield, but not `eval`: a Rust-backed
+//! method that repeatedly calls back into Ruby with
+//! [`Eval::eval`](artichoke_backend::core::Eval::eval) has no native guard
+//! against exhausting the stack. `Artichoke::enter_recursive_call` (wired
+//! into `funcall`, `eval`, and block yield) closes that gap and is
+//! configurable per interpreter with `Artichoke::set_recursion_depth_limit`.
+
+#[macro_use]
+extern crate artichoke_backend;
+
+use artichoke_backend::extn::prelude::*;
+use artichoke_backend::stack_depth::DEFAULT_MAX_DEPTH;
+
+struct Recursive;
+
+unsafe extern "C" fn recursive_eval_recurse(
+    mrb: *mut sys::mrb_state,
+    _slf: sys::mrb_value,
+) -> sys::mrb_value {
+    mrb_get_args!(mrb, none);
+    let mut interp = unwrap_interpreter!(mrb);
+    let mut guard = Guard::new(&mut interp);
+    let result = guard.eval(b"Recursive.eval_recurse");
+    match result {
+        Ok(value) => value.inner(),
+        Err(exception) => exception::raise(guard, exception),
+    }
+}
+
+impl File for Recursive {
+    type Artichoke = Artichoke;
+
+    type Error = Exception;
+
+    fn require(interp: &mut Artichoke) -> Result<(), Self::Error> {
+        let spec = class::Spec::new("Recursive", None, None)?;
+        class::Builder::for_spec(interp, &spec)
+            .add_self_method(
+                "eval_recurse",
+                recursive_eval_recurse,
+                sys::mrb_args_none(),
+            )?
+            .define()?;
+        interp.def_class::<Self>(spec)?;
+        Ok(())
+    }
+}
+
+#[test]
+fn default_recursion_depth_limit_is_documented_default() {
+    let interp = artichoke_backend::interpreter().expect("init");
+    assert_eq!(interp.recursion_depth_limit().unwrap(), DEFAULT_MAX_DEPTH);
+}
+
+#[test]
+fn eval_recursion_through_rust_raises_system_stack_error_instead_of_crashing() {
+    let mut interp = artichoke_backend::interpreter().expect("init");
+    Recursive::require(&mut interp).unwrap();
+    interp.set_recursion_depth_limit(32).unwrap();
+
+    let result = interp
+        .eval(
+            br#"
+            begin
+              Recursive.eval_recurse
+            rescue SystemStackError => e
+              e.class.name
+            end
+            "#,
+        )
+        .unwrap();
+    let classname = result.try_into_mut::<String>(&mut interp).unwrap();
+    assert_eq!(classname, "SystemStackError");
+}
+
+#[test]
+fn recursion_depth_limit_is_configurable_per_interpreter() {
+    let mut interp = artichoke_backend::interpreter().expect("init");
+    assert_eq!(interp.recursion_depth_limit().unwrap(), DEFAULT_MAX_DEPTH);
+
+    interp.set_recursion_depth_limit(64).unwrap();
+    assert_eq!(interp.recursion_depth_limit().unwrap(), 64);
+}
@@ -0,0 +1,50 @@
+#![cfg(target_os = "wasi")]
+#![deny(clippy::all)]
+#![deny(clippy::pedantic)]
+
+//! Smoke test that the `Native` filesystem and `System` `ENV` backends work
+//! unmodified under `wasm32-wasi`.
+//!
+//! Both backends are implemented entirely in terms of `std::fs`/`std::env`
+//! (see `artichoke_backend::fs::native::Native` and
+//! `artichoke_backend::extn::core::env::backend::system::System`), which
+//! WASI's libstd already backs with the `wasi_snapshot_preview1` syscalls, so
+//! there is no Artichoke-specific WASI backend to maintain. This file only
+//! compiles for `wasm32-wasi` targets; run it under a WASI host (e.g.
+//! `wasmtime run --dir .`) that preopens the current directory so
+//! `Native::write_file`/`read_file` have somewhere to write.
+
+use std::env;
+
+use artichoke_backend::extn::core::env::backend::system::System;
+use artichoke_backend::extn::core::env::backend::EnvType;
+use artichoke_backend::fs::native::Native;
+use artichoke_backend::fs::Filesystem;
+
+#[test]
+fn system_env_backend_round_trips_through_wasi_environ_syscalls() {
+    let mut backend = System::new();
+    backend
+        .put(b"ARTICHOKE_WASI_SMOKE_TEST", Some(b"1"))
+        .unwrap();
+    assert_eq!(
+        backend.get(b"ARTICHOKE_WASI_SMOKE_TEST").unwrap().as_deref(),
+        Some(&b"1"[..])
+    );
+    backend.put(b"ARTICHOKE_WASI_SMOKE_TEST", None).unwrap();
+    assert_eq!(backend.get(b"ARTICHOKE_WASI_SMOKE_TEST").unwrap(), None);
+}
+
+#[test]
+fn native_filesystem_backend_round_trips_through_a_wasi_preopened_directory() {
+    let mut backend = Native::new();
+    let path = env::current_dir()
+        .unwrap()
+        .join("artichoke_wasi_smoke_test.txt");
+    backend
+        .write_file(&path, Vec::from(&b"hello from wasi"[..]).into())
+        .unwrap();
+    assert!(backend.is_file(&path));
+    let contents = backend.read_file(&path).unwrap();
+    assert_eq!(&*contents, b"hello from wasi");
+}
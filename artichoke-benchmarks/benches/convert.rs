@@ -0,0 +1,43 @@
+use artichoke::prelude::*;
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+
+fn convert_large_byte_string(c: &mut Criterion) {
+    let mut interp = artichoke::interpreter().unwrap();
+    let bytes = vec![b'a'; 1024 * 1024];
+    c.bench_function("convert 1MiB byte string to Value", |b| {
+        b.iter(|| interp.convert_mut(black_box(bytes.clone())));
+    });
+}
+
+fn convert_large_fixnum_array(c: &mut Criterion) {
+    let mut interp = artichoke::interpreter().unwrap();
+    let values = (0..10_000).map(|i| interp.convert(i)).collect::<Vec<_>>();
+    c.bench_function("convert 10k-element Vec<Value> to Array", |b| {
+        b.iter(|| {
+            interp
+                .try_convert_mut(black_box(values.clone()))
+                .unwrap()
+        });
+    });
+}
+
+fn convert_back_large_array(c: &mut Criterion) {
+    let mut interp = artichoke::interpreter().unwrap();
+    let values = (0..10_000).map(|i| interp.convert(i)).collect::<Vec<_>>();
+    let ary: Value = interp.try_convert_mut(values).unwrap();
+    c.bench_function("convert Array back to Vec<Value>", |b| {
+        b.iter(|| {
+            black_box(ary)
+                .try_into_mut::<Vec<Value>>(&mut interp)
+                .unwrap()
+        });
+    });
+}
+
+criterion_group!(
+    benches,
+    convert_large_byte_string,
+    convert_large_fixnum_array,
+    convert_back_large_array
+);
+criterion_main!(benches);
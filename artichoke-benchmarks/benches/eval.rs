@@ -0,0 +1,43 @@
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+
+fn eval_literal(c: &mut Criterion) {
+    let mut interp = artichoke::interpreter().unwrap();
+    c.bench_function("eval literal fixnum", |b| {
+        b.iter(|| interp.eval(black_box(b"2 + 5")).unwrap());
+    });
+}
+
+fn eval_method_definition_and_call(c: &mut Criterion) {
+    let mut interp = artichoke::interpreter().unwrap();
+    let code = b"
+        def fib(n)
+          return n if n < 2
+
+          fib(n - 1) + fib(n - 2)
+        end
+
+        fib(15)
+    ";
+    c.bench_function("eval method definition and recursive call", |b| {
+        b.iter(|| interp.eval(black_box(code)).unwrap());
+    });
+}
+
+fn eval_string_heavy_source(c: &mut Criterion) {
+    let mut interp = artichoke::interpreter().unwrap();
+    let code = b"
+        parts = (1..1000).map { |i| i.to_s }
+        parts.join(', ')
+    ";
+    c.bench_function("eval string-heavy source", |b| {
+        b.iter(|| interp.eval(black_box(code)).unwrap());
+    });
+}
+
+criterion_group!(
+    benches,
+    eval_literal,
+    eval_method_definition_and_call,
+    eval_string_heavy_source
+);
+criterion_main!(benches);
@@ -0,0 +1,51 @@
+use artichoke::prelude::*;
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+
+fn funcall_no_args(c: &mut Criterion) {
+    let mut interp = artichoke::interpreter().unwrap();
+    let value = interp.convert(17);
+    c.bench_function("funcall round trip, no args", |b| {
+        b.iter(|| {
+            black_box(value)
+                .funcall(&mut interp, "to_s", &[], None)
+                .unwrap()
+        });
+    });
+}
+
+fn funcall_one_arg(c: &mut Criterion) {
+    let mut interp = artichoke::interpreter().unwrap();
+    let value = interp.convert(17);
+    let other = interp.convert(19);
+    c.bench_function("funcall round trip, one arg", |b| {
+        b.iter(|| {
+            black_box(value)
+                .funcall(&mut interp, "+", &[black_box(other)], None)
+                .unwrap()
+        });
+    });
+}
+
+fn funcall_custom_method(c: &mut Criterion) {
+    let mut interp = artichoke::interpreter().unwrap();
+    interp
+        .eval(b"class Greeter; def greet(name); \"Hello, #{name}!\"; end; end")
+        .unwrap();
+    let greeter = interp.eval(b"Greeter.new").unwrap();
+    let name = interp.convert_mut("World");
+    c.bench_function("funcall round trip, user-defined method", |b| {
+        b.iter(|| {
+            black_box(greeter)
+                .funcall(&mut interp, "greet", &[black_box(name)], None)
+                .unwrap()
+        });
+    });
+}
+
+criterion_group!(
+    benches,
+    funcall_no_args,
+    funcall_one_arg,
+    funcall_custom_method
+);
+criterion_main!(benches);
@@ -50,4 +50,21 @@ pub trait Eval {
     /// If `path` does not exist or code cannot be read, an error is returned.
     #[cfg(feature = "std")]
     fn eval_file(&mut self, file: &Path) -> Result<Self::Value, Self::Error>;
+
+    /// Release the retention of the most recent `eval` result.
+    ///
+    /// Some interpreters pin the return value of the most recent `eval` call
+    /// so that REPL-style hosts can chain evals that reference values and
+    /// local variables from prior evals. This retention means the result of
+    /// every `eval` is kept alive until the next `eval` call, which can
+    /// result in unbounded memory growth for hosts that run many evals on one
+    /// long-lived interpreter without ever evaling again.
+    ///
+    /// Calling this method releases that retention, allowing the most recent
+    /// `eval` result to be garbage collected.
+    ///
+    /// # Errors
+    ///
+    /// If an exception is raised on the interpreter, then an error is returned.
+    fn release_last_eval_result(&mut self) -> Result<(), Self::Error>;
 }
@@ -25,4 +25,26 @@ pub trait Io {
         self.print("\n")?;
         Ok(())
     }
+
+    /// Writes the given bytes to the interpreter stderr stream.
+    ///
+    /// # Errors
+    ///
+    /// If the output stream encounters an error, an error is returned.
+    fn write_stderr<T: AsRef<[u8]>>(&mut self, message: T) -> Result<(), Self::Error>;
+
+    /// Reads bytes from the interpreter stdin stream into `buf`, returning
+    /// the number of bytes read.
+    ///
+    /// # Errors
+    ///
+    /// If the input stream encounters an error, an error is returned.
+    fn read_stdin(&mut self, buf: &mut [u8]) -> Result<usize, Self::Error>;
+
+    /// Flushes the interpreter stdout stream.
+    ///
+    /// # Errors
+    ///
+    /// If the output stream encounters an error, an error is returned.
+    fn flush(&mut self) -> Result<(), Self::Error>;
 }
@@ -133,7 +133,7 @@ pub mod prelude {
     pub use crate::regexp::Regexp;
     pub use crate::release_metadata::ReleaseMetadata;
     pub use crate::top_self::TopSelf;
-    pub use crate::types::{Ruby, Rust};
+    pub use crate::types::{Fp, Int, Ruby, Rust};
     pub use crate::value::Value;
     pub use crate::warn::Warn;
 }
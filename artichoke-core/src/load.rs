@@ -1,12 +1,18 @@
 //! Load Ruby and Rust sources into the VM.
 
 use alloc::borrow::Cow;
+use alloc::vec::Vec;
 
 #[cfg(feature = "std")]
 type Path = std::path::Path;
 #[cfg(not(feature = "std"))]
 type Path = str;
 
+#[cfg(feature = "std")]
+type PathBuf = std::path::PathBuf;
+#[cfg(not(feature = "std"))]
+type PathBuf = alloc::string::String;
+
 use crate::file::File;
 
 /// Load Ruby sources and Rust extensions into an interpreter.
@@ -138,4 +144,45 @@ pub trait LoadSources {
     fn read_source_file_contents<P>(&self, path: P) -> Result<Cow<'_, [u8]>, Self::Error>
     where
         P: AsRef<Path>;
+
+    /// Remove a source from the virtual filesystem.
+    ///
+    /// Removes the file contents and extension hook, if any, at `path` and
+    /// clears its required status so a subsequent [`LoadSources::def_rb_source_file`]
+    /// at the same path can be required again.
+    ///
+    /// Returns whether a source existed at `path` prior to removal.
+    ///
+    /// # Errors
+    ///
+    /// If the underlying filesystem is inaccessible, an error is returned.
+    fn undef_source<P>(&mut self, path: P) -> Result<bool, Self::Error>
+    where
+        P: AsRef<Path>;
+
+    /// Replace the contents of a Ruby source already on the virtual
+    /// filesystem and clear its required status.
+    ///
+    /// This is equivalent to calling [`LoadSources::def_rb_source_file`] with
+    /// the new `contents` and then un-marking the source as required so
+    /// `Kernel#require` will re-require the file the next time it is
+    /// referenced.
+    ///
+    /// # Errors
+    ///
+    /// If the underlying filesystem is inaccessible, an error is returned.
+    ///
+    /// If writes to the underlying filesystem fail, an error is returned.
+    fn replace_rb_source_file<P, T>(&mut self, path: P, contents: T) -> Result<(), Self::Error>
+    where
+        P: AsRef<Path>,
+        T: Into<Cow<'static, [u8]>>;
+
+    /// Enumerate all source paths currently defined on the virtual
+    /// filesystem.
+    ///
+    /// # Errors
+    ///
+    /// If the underlying filesystem is inaccessible, an error is returned.
+    fn sources(&self) -> Result<Vec<PathBuf>, Self::Error>;
 }
@@ -64,9 +64,10 @@ pub trait ReleaseMetadata {
     /// This value will populate the `RUBY_RELEASE_DATE` constant.
     fn ruby_release_date(&self) -> &str;
 
-    /// The revision count of the Artichoke git repo used for this build.
+    /// The git commit hash of the Artichoke repo used for this build.
     ///
-    /// This value will populate the `RUBY_REVISION` constant.
+    /// This value will populate the `RUBY_REVISION` constant. As of Ruby 3.0,
+    /// `RUBY_REVISION` is a commit hash rather than an SVN revision number.
     fn ruby_revision(&self) -> &str;
 
     /// The target MRI Ruby version for this build.
@@ -2,6 +2,30 @@
 
 use core::fmt;
 
+/// Artichoke native floating point type.
+///
+/// `Fp` is the backend to the `Float` Ruby class.
+///
+/// The `Fp` type alias is for the `f64` floating point primitive.
+///
+/// This alias is the shared vocabulary type between interpreter frontends
+/// and backends; an interpreter implementation is free to use a different
+/// width internally, but must convert to and from `Fp` at the
+/// [`Convert`](crate::convert::Convert) boundary.
+pub type Fp = f64;
+
+/// Artichoke native integer type.
+///
+/// `Int` is the fixed size (`Fixnum`) backend to the `Integer` Ruby class.
+///
+/// The `Int` type alias is for the `i64` integer primitive.
+///
+/// This alias is the shared vocabulary type between interpreter frontends
+/// and backends; an interpreter implementation is free to use a different
+/// width internally, but must convert to and from `Int` at the
+/// [`Convert`](crate::convert::Convert) boundary.
+pub type Int = i64;
+
 /// Classes of Rust types.
 #[derive(Debug, Clone, Copy, Hash, PartialEq, Eq, PartialOrd, Ord)]
 pub enum Rust {
@@ -0,0 +1,153 @@
+#![warn(clippy::all)]
+#![warn(clippy::pedantic)]
+#![warn(missing_docs, intra_doc_link_resolution_failure)]
+#![warn(missing_debug_implementations)]
+#![warn(rust_2018_idioms)]
+
+//! `artichoke-null-backend` is a minimal implementation of the
+//! [`artichoke-core`] traits that performs no work.
+//!
+//! Every trait method returns [`NotImplemented`]. This crate exists to prove
+//! that a second, non-mruby backend can be written against the
+//! `artichoke-core` trait surface without changing `artichoke-core` or the
+//! public API of existing frontends like `artichoke-backend`.
+
+use std::error;
+use std::fmt;
+
+use artichoke_core::eval::Eval;
+use artichoke_core::parser::Parser;
+use artichoke_core::value::Value as ValueTrait;
+
+/// Error returned by every [`NullInterpreter`] operation.
+///
+/// `NullInterpreter` does not execute Ruby code, so all of its trait methods
+/// are stubs that return this error.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct NotImplemented;
+
+impl fmt::Display for NotImplemented {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("artichoke-null-backend does not implement this operation")
+    }
+}
+
+impl error::Error for NotImplemented {}
+
+/// An interpreter backend that implements the `artichoke-core` trait surface
+/// but performs no work.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct NullInterpreter;
+
+/// A placeholder [`Value`](artichoke_core::value::Value) produced by
+/// [`NullInterpreter`].
+///
+/// `NullValue` cannot be constructed outside of this crate and every
+/// operation on it is a no-op or returns [`NotImplemented`].
+#[derive(Debug, Clone, Copy, Default)]
+pub struct NullValue;
+
+impl ValueTrait for NullValue {
+    type Artichoke = NullInterpreter;
+    type Arg = NullValue;
+    type Value = NullValue;
+    type Block = NullValue;
+    type Error = NotImplemented;
+
+    fn funcall(
+        &self,
+        _interp: &mut Self::Artichoke,
+        _func: &str,
+        _args: &[Self::Arg],
+        _block: Option<Self::Block>,
+    ) -> Result<Self::Value, Self::Error> {
+        Err(NotImplemented)
+    }
+
+    fn freeze(&mut self, _interp: &mut Self::Artichoke) -> Result<(), Self::Error> {
+        Err(NotImplemented)
+    }
+
+    fn is_frozen(&self, _interp: &mut Self::Artichoke) -> bool {
+        false
+    }
+
+    fn is_nil(&self) -> bool {
+        true
+    }
+
+    fn respond_to(&self, _interp: &mut Self::Artichoke, _method: &str) -> Result<bool, Self::Error> {
+        Err(NotImplemented)
+    }
+
+    fn inspect(&self, _interp: &mut Self::Artichoke) -> Vec<u8> {
+        b"nil".to_vec()
+    }
+
+    fn to_s(&self, _interp: &mut Self::Artichoke) -> Vec<u8> {
+        b"".to_vec()
+    }
+}
+
+impl Eval for NullInterpreter {
+    type Value = NullValue;
+    type Error = NotImplemented;
+
+    fn eval(&mut self, _code: &[u8]) -> Result<Self::Value, Self::Error> {
+        Err(NotImplemented)
+    }
+
+    fn eval_os_str(&mut self, _code: &std::ffi::OsStr) -> Result<Self::Value, Self::Error> {
+        Err(NotImplemented)
+    }
+
+    fn eval_file(&mut self, _file: &std::path::Path) -> Result<Self::Value, Self::Error> {
+        Err(NotImplemented)
+    }
+
+    fn release_last_eval_result(&mut self) -> Result<(), Self::Error> {
+        Err(NotImplemented)
+    }
+}
+
+impl Parser for NullInterpreter {
+    type Context = ();
+    type Error = NotImplemented;
+
+    fn reset_parser(&mut self) -> Result<(), Self::Error> {
+        Err(NotImplemented)
+    }
+
+    fn fetch_lineno(&self) -> Result<usize, Self::Error> {
+        Err(NotImplemented)
+    }
+
+    fn add_fetch_lineno(&mut self, _val: usize) -> Result<usize, Self::Error> {
+        Err(NotImplemented)
+    }
+
+    fn push_context(&mut self, _context: Self::Context) -> Result<(), Self::Error> {
+        Err(NotImplemented)
+    }
+
+    fn pop_context(&mut self) -> Result<Option<Self::Context>, Self::Error> {
+        Err(NotImplemented)
+    }
+
+    fn peek_context(&self) -> Result<Option<&Self::Context>, Self::Error> {
+        Err(NotImplemented)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use artichoke_core::eval::Eval;
+
+    use super::NullInterpreter;
+
+    #[test]
+    fn eval_is_not_implemented() {
+        let mut interp = NullInterpreter::default();
+        assert!(interp.eval(b"1 + 1").is_err());
+    }
+}
@@ -53,6 +53,7 @@ pub fn build_release_metadata(target: &Triple) {
     let build_date = Date::from(Utc::now());
     let release_date = build_date;
     let revision_count = revision_count();
+    let revision = git_commit_hash().unwrap_or_else(|| "unknown".to_string());
     let platform = platform(target);
     let copyright = copyright(birth_date, build_date);
     let description = description(
@@ -66,7 +67,9 @@ pub fn build_release_metadata(target: &Triple) {
     emit("RUBY_RELEASE_YEAR", build_date.year());
     emit("RUBY_RELEASE_MONTH", build_date.month());
     emit("RUBY_RELEASE_DAY", build_date.day());
-    emit("RUBY_REVISION", revision_count.unwrap_or(0));
+    // As of Ruby 3.0, `RUBY_REVISION` is the git commit hash of the checkout
+    // the build was made from, rather than an SVN revision number.
+    emit("RUBY_REVISION", revision);
     emit("RUBY_PLATFORM", platform);
     emit("RUBY_COPYRIGHT", copyright);
     emit("RUBY_DESCRIPTION", description);
@@ -109,6 +112,22 @@ fn revision_count() -> Option<usize> {
         .ok()
 }
 
+fn git_commit_hash() -> Option<String> {
+    let cmd = OsString::from("git");
+    let revision = Command::new(cmd)
+        .arg("rev-parse")
+        .arg("HEAD")
+        .output()
+        .ok()?;
+    let revision = String::from_utf8(revision.stdout).ok()?;
+    let revision = revision.trim();
+    if revision.is_empty() {
+        None
+    } else {
+        Some(revision.to_string())
+    }
+}
+
 fn platform(target: &Triple) -> String {
     target.to_string()
 }
@@ -0,0 +1,180 @@
+#![warn(clippy::all)]
+#![warn(clippy::pedantic)]
+#![warn(missing_docs, intra_doc_link_resolution_failure)]
+#![warn(rust_2018_idioms)]
+
+//! `compliance` is a golden-output differential runner for Artichoke.
+//!
+//! `compliance` runs a directory of small, self-contained Ruby programs under
+//! both the `artichoke` CLI and the host `ruby`, and diffs `stdout`,
+//! `stderr`, and exit status between the two. It is a practical compatibility
+//! regression suite distinct from the ruby/spec suites run by `spec-runner`:
+//! ruby/spec exercises individual APIs in isolation, while `compliance`
+//! exercises whole programs the way a user would actually run them.
+//!
+//! Programs that are known to diverge -- for example, because they depend on
+//! a feature Artichoke does not implement yet -- can be listed in an
+//! allowlist file so they are reported separately from unexpected
+//! regressions.
+//!
+//! # Usage
+//!
+//! ```console
+//! $ cargo run -q -p spec-runner --bin compliance -- path/to/programs
+//! $ cargo run -q -p spec-runner --bin compliance -- path/to/programs --allowlist path/to/allowlist.yaml
+//! ```
+
+use serde::{Deserialize, Serialize};
+use std::error::Error;
+use std::ffi::OsStr;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process::{self, Command, Output};
+use structopt::StructOpt;
+
+/// CLI specification for `compliance`.
+#[derive(Default, Debug, Clone, Hash, PartialEq, Eq, PartialOrd, Ord, StructOpt)]
+#[structopt(
+    name = "compliance",
+    about = "Golden-output differential runner against system Ruby."
+)]
+struct Opt {
+    /// Directory of `.rb` programs to run under both interpreters.
+    #[structopt(parse(from_os_str))]
+    directory: PathBuf,
+
+    /// Path to the `artichoke` binary under test.
+    #[structopt(long, parse(from_os_str), default_value = "target/debug/artichoke")]
+    artichoke_bin: PathBuf,
+
+    /// Path to (or name of) the host `ruby` binary to diff against.
+    #[structopt(long, parse(from_os_str), default_value = "ruby")]
+    ruby_bin: PathBuf,
+
+    /// Path to a YAML allowlist of programs with known divergences.
+    #[structopt(long, parse(from_os_str))]
+    allowlist: Option<PathBuf>,
+}
+
+/// Allowlist file format for declaring programs with known divergences.
+#[derive(Default, Debug, Clone, Hash, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+struct Allowlist {
+    /// File names, relative to the program directory, that are known to
+    /// diverge between Artichoke and the host `ruby`.
+    #[serde(default)]
+    known_divergences: Vec<String>,
+}
+
+impl Allowlist {
+    fn load(path: Option<&Path>) -> Result<Self, Box<dyn Error>> {
+        match path {
+            Some(path) => {
+                let contents = fs::read_to_string(path)?;
+                Ok(serde_yaml::from_str(&contents)?)
+            }
+            None => Ok(Self::default()),
+        }
+    }
+
+    fn allows(&self, name: &str) -> bool {
+        self.known_divergences.iter().any(|allowed| allowed == name)
+    }
+}
+
+/// The result of running a single program under one interpreter.
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct Run {
+    stdout: Vec<u8>,
+    stderr: Vec<u8>,
+    status: Option<i32>,
+}
+
+impl Run {
+    fn capture(bin: &Path, program: &Path) -> Result<Self, Box<dyn Error>> {
+        let Output {
+            status,
+            stdout,
+            stderr,
+        } = Command::new(bin).arg(program).output()?;
+        Ok(Self {
+            stdout,
+            stderr,
+            status: status.code(),
+        })
+    }
+}
+
+/// Main entrypoint.
+pub fn main() {
+    let opt = Opt::from_args();
+    match try_main(&opt) {
+        Ok(true) => process::exit(0),
+        Ok(false) => process::exit(1),
+        Err(err) => {
+            eprintln!("{}", err);
+            process::exit(1);
+        }
+    }
+}
+
+/// Result-returning entrypoint.
+///
+/// # Errors
+///
+/// If `opt.directory` cannot be read, or the allowlist at `opt.allowlist`
+/// cannot be read or parsed, an error is returned.
+fn try_main(opt: &Opt) -> Result<bool, Box<dyn Error>> {
+    let allowlist = Allowlist::load(opt.allowlist.as_deref())?;
+
+    let mut programs = fs::read_dir(&opt.directory)?
+        .filter_map(|entry| entry.ok())
+        .map(|entry| entry.path())
+        .filter(|path| path.extension() == Some(OsStr::new("rb")))
+        .collect::<Vec<_>>();
+    programs.sort();
+
+    let mut success = true;
+    for program in &programs {
+        let name = program
+            .file_name()
+            .map_or_else(String::new, |name| name.to_string_lossy().into_owned());
+
+        let artichoke = Run::capture(&opt.artichoke_bin, program)?;
+        let ruby = Run::capture(&opt.ruby_bin, program)?;
+
+        if artichoke == ruby {
+            println!("PASS  {}", name);
+        } else if allowlist.allows(&name) {
+            println!("SKIP  {} (known divergence)", name);
+        } else {
+            success = false;
+            println!("FAIL  {}", name);
+            report_divergence(&artichoke, &ruby);
+        }
+    }
+    Ok(success)
+}
+
+/// Print a human-readable diff of a single divergence between the two runs.
+fn report_divergence(artichoke: &Run, ruby: &Run) {
+    if artichoke.status != ruby.status {
+        println!(
+            "      exit status: artichoke={:?} ruby={:?}",
+            artichoke.status, ruby.status
+        );
+    }
+    if artichoke.stdout != ruby.stdout {
+        println!(
+            "      stdout: artichoke={:?} ruby={:?}",
+            String::from_utf8_lossy(&artichoke.stdout),
+            String::from_utf8_lossy(&ruby.stdout)
+        );
+    }
+    if artichoke.stderr != ruby.stderr {
+        println!(
+            "      stderr: artichoke={:?} ruby={:?}",
+            String::from_utf8_lossy(&artichoke.stderr),
+            String::from_utf8_lossy(&ruby.stderr)
+        );
+    }
+}
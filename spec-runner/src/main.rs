@@ -43,15 +43,32 @@
 //! ruby/spec runner for Artichoke.
 //!
 //! USAGE:
-//!     spec-runner <config>
+//!     spec-runner [FLAGS] <config> [filters]...
 //!
 //! FLAGS:
 //!     -h, --help       Prints help information
+//!         --list       List the embedded spec files selected by `config` and `filters` and exit
 //!     -V, --version    Prints version information
 //!
 //! ARGS:
-//!     <config>    Path to YAML config file
+//!     <config>        Path to YAML config file
+//!     <filters>...    Glob filters, e.g. `core/string/**`, that further restrict which specs from
+//!                     `config` are run
 //! ```
+//!
+//! # Selecting a Subset of Specs
+//!
+//! In addition to the YAML manifest, `spec-runner` accepts positional glob
+//! filters that are intersected with the manifest selection. This is useful
+//! for iterating on a single class or method without editing the manifest or
+//! waiting for the full suite to run:
+//!
+//! ```console
+//! $ cargo run -q -p spec-runner -- spec-runner/enforced-specs.yaml 'core/string/**' 'library/stringio/**'
+//! ```
+//!
+//! Pass `--list` to enumerate the specs a given `config`/filter combination
+//! would run, without running them.
 
 #![doc(html_favicon_url = "https://www.artichokeruby.org/favicon.ico")]
 #![doc(html_logo_url = "https://www.artichokeruby.org/artichoke-logo.svg")]
@@ -60,6 +77,8 @@
 extern crate rust_embed;
 
 use artichoke::prelude::*;
+use glob::Pattern;
+use std::collections::BTreeMap;
 use std::error::Error;
 use std::ffi::OsStr;
 use std::fs;
@@ -67,6 +86,7 @@ use std::io::{self, Write};
 use std::path::{Component, Path, PathBuf};
 use std::process;
 use std::str;
+use std::time::Instant;
 use structopt::StructOpt;
 use termcolor::{ColorChoice, StandardStream, WriteColor};
 
@@ -78,16 +98,33 @@ mod rubyspec;
 #[derive(Default, Debug, Clone, Hash, PartialEq, Eq, PartialOrd, Ord, StructOpt)]
 #[structopt(name = "spec-runner", about = "ruby/spec runner for Artichoke.")]
 struct Opt {
+    /// List the embedded spec files selected by `config` and `filters` and
+    /// exit instead of running them.
+    #[structopt(long)]
+    list: bool,
+
     /// Path to YAML config file.
     #[structopt(parse(from_os_str))]
     config: PathBuf,
+
+    /// Glob filters, e.g. `core/string/**`, that further restrict which
+    /// specs from `config` are run.
+    filters: Vec<String>,
 }
 
 /// Main entrypoint.
 pub fn main() {
     let opt = Opt::from_args();
     let mut stderr = StandardStream::stderr(ColorChoice::Auto);
-    match try_main(&mut stderr, opt.config.as_path()) {
+    let filters = match parse_filters(opt.filters.as_slice()) {
+        Ok(filters) => filters,
+        Err(err) => {
+            let _ = writeln!(&mut stderr, "{}", err);
+            process::exit(1);
+        }
+    };
+    let result = try_main(&mut stderr, opt.config.as_path(), filters.as_slice(), opt.list);
+    match result {
         Ok(true) => process::exit(0),
         Ok(false) => process::exit(1),
         Err(err) => {
@@ -97,6 +134,14 @@ pub fn main() {
     }
 }
 
+/// Parse positional glob filter strings into compiled [`Pattern`]s.
+fn parse_filters(filters: &[String]) -> Result<Vec<Pattern>, Box<dyn Error>> {
+    filters
+        .iter()
+        .map(|filter| Pattern::new(filter).map_err(Into::into))
+        .collect()
+}
+
 /// Result-returning entrypoint.
 ///
 /// Initializes an interpreter, parses the spec manifest, and invokes the
@@ -109,7 +154,12 @@ pub fn main() {
 /// If an Artichoke interpreter cannot be initialized, an error is returned.
 ///
 /// If the `MSpec` runner returns an error, an error is returned.
-pub fn try_main<W>(stderr: W, config: &Path) -> Result<bool, Box<dyn Error>>
+pub fn try_main<W>(
+    stderr: W,
+    config: &Path,
+    filters: &[Pattern],
+    list: bool,
+) -> Result<bool, Box<dyn Error>>
 where
     W: io::Write + WriteColor,
 {
@@ -137,22 +187,83 @@ where
             }
             continue;
         }
-        if is_require_path(&config, &name).is_some() {
+        if is_require_path(&config, &name).is_some() && matches_filters(filters, &name) {
             specs.push(name.into_owned())
         }
     }
-    mspec::init(&mut interp)?;
-    let result = match mspec::run(&mut interp, specs.iter().map(String::as_str)) {
-        Ok(result) => Ok(result),
-        Err(exc) => {
-            artichoke::backtrace::format_cli_trace_into(stderr, &mut interp, &exc)?;
-            Err(exc.into())
+
+    if list {
+        for name in &specs {
+            writeln!(io::stdout(), "{}", name)?;
         }
-    };
+        interp.close();
+        return Ok(true);
+    }
+
+    mspec::init(&mut interp)?;
+    let result = run_families(&mut interp, stderr, specs);
     interp.close();
     result
 }
 
+/// Determine if a spec source path matches at least one glob filter.
+///
+/// An empty filter list matches every spec, so that `spec-runner` runs the
+/// full manifest when no positional filters are given.
+#[must_use]
+fn matches_filters(filters: &[Pattern], name: &str) -> bool {
+    filters.is_empty() || filters.iter().any(|filter| filter.matches(name))
+}
+
+/// Run the selected `specs`, grouped by their top-level family (`core`,
+/// `library`, ...), printing a per-family timing summary.
+///
+/// Running each family as a separate `MSpec` invocation is what makes
+/// per-family timing possible without changing the embedded `MSpec` runner.
+fn run_families<W>(
+    interp: &mut Artichoke,
+    mut stderr: W,
+    specs: Vec<String>,
+) -> Result<bool, Box<dyn Error>>
+where
+    W: io::Write + WriteColor,
+{
+    let mut families: BTreeMap<String, Vec<String>> = BTreeMap::new();
+    for spec in specs {
+        let family = Path::new(&spec)
+            .components()
+            .next()
+            .map_or_else(String::new, |component| {
+                component.as_os_str().to_string_lossy().into_owned()
+            });
+        families.entry(family).or_default().push(spec);
+    }
+
+    let mut success = true;
+    for (family, specs) in &families {
+        let start = Instant::now();
+        let result = mspec::run(interp, specs.iter().map(String::as_str));
+        let elapsed = start.elapsed();
+        match result {
+            Ok(result) => {
+                success &= result;
+                println!(
+                    "{}: {} specs in {:.2}s - {}",
+                    family,
+                    specs.len(),
+                    elapsed.as_secs_f64(),
+                    if result { "PASS" } else { "FAIL" }
+                );
+            }
+            Err(exc) => {
+                artichoke::backtrace::format_cli_trace_into(&mut stderr, interp, &exc)?;
+                return Err(exc.into());
+            }
+        }
+    }
+    Ok(success)
+}
+
 /// Determine if an embedded ruby/spec should be tested.
 ///
 /// This function evaluates a ruby/spec source file against the parsed spec
@@ -37,6 +37,8 @@ where
         "/src/lib/test/spec_runner",
         &include_bytes!("spec_runner.rb")[..],
     )?;
+    let enabled_features = interp.try_convert_mut(enabled_features())?;
+    interp.set_global_variable(&b"$enabled_features"[..], &enabled_features)?;
     interp.eval_file(Path::new("/src/lib/test/spec_runner"))?;
     let specs = interp.try_convert_mut(specs.into_iter().collect::<Vec<_>>())?;
     let result = interp
@@ -45,6 +47,24 @@ where
     interp.try_convert(result)
 }
 
+/// Names of the `MSpec` `with_feature`/`without_feature` guard features
+/// (`vendor/mspec/lib/mspec/guards/feature.rb`) that this build of
+/// `spec-runner` has actually compiled in, derived from this crate's own
+/// Cargo features rather than duplicated by hand in an `.mspec` config.
+///
+/// `MSpec.feature_enabled?` defaults every feature to `false`, so without
+/// this bridge `with_feature`-gated specs would always skip even when the
+/// capability they guard is compiled into the interpreter under test.
+#[must_use]
+fn enabled_features() -> Vec<&'static str> {
+    let mut features = vec![];
+    #[cfg(feature = "core-random")]
+    features.push("random");
+    #[cfg(feature = "core-regexp-oniguruma")]
+    features.push("oniguruma");
+    features
+}
+
 #[cfg(test)]
 mod tests {
     #[test]
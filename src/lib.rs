@@ -67,6 +67,10 @@
 //!   the [`Random` class][core-class-random]. This feature includes additional
 //!   dependencies. When this feature is disabled, Artichoke does not have
 //!   support for generating psuedorandom numbers.
+//! - `interpreter-registry` - *Disabled* by default. This assigns each
+//!   interpreter a process-wide unique id and tracks live interpreters in a
+//!   global registry. Exposes `Artichoke.current_id` in Ruby and
+//!   [`registry::iter`] in Rust.
 //! - `stdlib-securerandom` - **Enabled** by default. This feature includes an
 //!   implementation of a CSPRNG for the
 //!   [`SecureRandom` module][stdlib-mod-securerandom]. This feature includes
@@ -105,6 +109,9 @@ pub mod parser;
 pub mod repl;
 pub mod ruby;
 
+#[cfg(feature = "interpreter-registry")]
+pub use artichoke_backend::registry;
+
 /// A "prelude" for users of the `artichoke-backend` crate.
 ///
 /// This prelude is similar to the standard library's prelude in that you'll
@@ -7,6 +7,8 @@ use std::error;
 use std::ffi::{OsStr, OsString};
 use std::io;
 use std::path::{Path, PathBuf};
+use std::process;
+use std::sync::atomic::{AtomicBool, Ordering};
 use structopt::StructOpt;
 use termcolor::WriteColor;
 
@@ -18,6 +20,51 @@ use crate::prelude::*;
 
 const INLINE_EVAL_SWITCH_FILENAME: &[u8] = b"-e";
 
+/// Exit code used by MRI's `ruby` when a script is terminated by `SIGINT`
+/// (`128 + SIGINT`).
+const SIGINT_EXIT_CODE: i32 = 130;
+
+/// Set by the `SIGINT` handler installed in [`entrypoint`]. Checked at the
+/// natural checkpoints between discrete units of work -- each `-e` command,
+/// and before and after running a program file or script read from stdin --
+/// since the embedded mruby VM has no mechanism for preempting a running
+/// eval.
+static SIGINT_RECEIVED: AtomicBool = AtomicBool::new(false);
+
+/// Install a default `SIGINT` handler that defers to [`SIGINT_RECEIVED`]
+/// instead of immediately terminating the process, so callers have a chance
+/// to raise `Interrupt` on the interpreter and exit with the MRI-compatible
+/// status code.
+///
+/// If a handler cannot be installed (for example, because one is already
+/// registered), the OS default disposition for `SIGINT` -- immediately
+/// terminating the process -- is left in place.
+fn install_sigint_handler() {
+    let _ = ctrlc::set_handler(|| SIGINT_RECEIVED.store(true, Ordering::SeqCst));
+}
+
+/// Returns `true` if a `SIGINT` has arrived since the last call to this
+/// function.
+fn sigint_received() -> bool {
+    SIGINT_RECEIVED.swap(false, Ordering::SeqCst)
+}
+
+/// Raise `Interrupt` on `interp`, report it like any other uncaught
+/// exception, and exit the process with [`SIGINT_EXIT_CODE`].
+///
+/// Callers should invoke this at a checkpoint between discrete units of work
+/// when [`sigint_received`] returns `true`. It is not a preemption point: it
+/// has no effect while `interp` is in the middle of a blocking eval.
+fn raise_sigint_and_exit<W>(interp: &mut Artichoke, error: W) -> !
+where
+    W: io::Write + WriteColor,
+{
+    if let Err(ref exc) = interp.eval(b"raise Interrupt") {
+        let _ = backtrace::format_cli_trace_into(error, interp, exc);
+    }
+    process::exit(SIGINT_EXIT_CODE);
+}
+
 #[cfg(test)]
 mod filename_test {
     #[test]
@@ -59,6 +106,8 @@ where
     R: io::Read,
     W: io::Write + WriteColor,
 {
+    install_sigint_handler();
+
     let opt = Opt::from_args();
     if opt.copyright {
         let mut interp = crate::interpreter()?;
@@ -82,6 +131,9 @@ where
             backtrace::format_cli_trace_into(error, &mut interp, exc)?;
             return Ok(Err(()));
         }
+        if sigint_received() {
+            raise_sigint_and_exit(&mut interp, error);
+        }
         Ok(Ok(()))
     }
 }
@@ -107,6 +159,9 @@ where
         setup_fixture_hack(&mut interp, fixture)?;
     }
     for command in commands {
+        if sigint_received() {
+            raise_sigint_and_exit(&mut interp, error);
+        }
         if let Err(ref exc) = interp.eval_os_str(command.as_os_str()) {
             backtrace::format_cli_trace_into(error, &mut interp, exc)?;
             // short circuit, but don't return an error since we already printed it
@@ -115,6 +170,9 @@ where
         // TODO: Do not suppress this error and implement RubyException for it.
         let _ = interp.add_fetch_lineno(1);
     }
+    if sigint_received() {
+        raise_sigint_and_exit(&mut interp, error);
+    }
     Ok(Ok(()))
 }
 
@@ -134,6 +192,9 @@ where
         backtrace::format_cli_trace_into(error, &mut interp, exc)?;
         return Ok(Err(()));
     }
+    if sigint_received() {
+        raise_sigint_and_exit(&mut interp, error);
+    }
     Ok(Ok(()))
 }
 